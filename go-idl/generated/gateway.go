@@ -17,19 +17,6 @@ var IDLGateway = types.IDL{
 		{
 			Name:          "call",
 			Discriminator: [8]byte{181, 94, 56, 161, 194, 221, 200, 3},
-			Accounts: []types.Account{
-				{
-					Name:     "signer",
-					Writable: true,
-					Signer:   true,
-					Address:  "",
-					PDA:      nil,
-				},
-			},
-		},
-		{
-			Name:          "deposit",
-			Discriminator: [8]byte{242, 35, 198, 137, 82, 225, 242, 182},
 			Accounts: []types.Account{
 				{
 					Name:     "signer",
@@ -40,23 +27,16 @@ var IDLGateway = types.IDL{
 				},
 				{
 					Name:     "pda",
-					Writable: true,
-					Signer:   false,
-					Address:  "",
-					PDA:      nil,
-				},
-				{
-					Name:     "system_program",
 					Writable: false,
 					Signer:   false,
-					Address:  "11111111111111111111111111111111",
+					Address:  "",
 					PDA:      nil,
 				},
 			},
 		},
 		{
-			Name:          "deposit_and_call",
-			Discriminator: [8]byte{65, 33, 186, 198, 114, 223, 133, 57},
+			Name:          "cancel_withdrawal",
+			Discriminator: [8]byte{183, 104, 181, 250, 28, 128, 210, 70},
 			Accounts: []types.Account{
 				{
 					Name:     "signer",
@@ -73,35 +53,29 @@ var IDLGateway = types.IDL{
 					PDA:      nil,
 				},
 				{
-					Name:     "system_program",
+					Name:     "roles",
 					Writable: false,
 					Signer:   false,
-					Address:  "11111111111111111111111111111111",
+					Address:  "",
 					PDA:      nil,
 				},
-			},
-		},
-		{
-			Name:          "deposit_spl_token",
-			Discriminator: [8]byte{86, 172, 212, 121, 63, 233, 96, 144},
-			Accounts: []types.Account{
 				{
-					Name:     "signer",
+					Name:     "rate_limit",
 					Writable: true,
-					Signer:   true,
+					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "pda",
+					Name:     "pending_withdrawal",
 					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "whitelist_entry",
-					Writable: false,
+					Name:     "payer",
+					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
@@ -114,38 +88,31 @@ var IDLGateway = types.IDL{
 					PDA:      nil,
 				},
 				{
-					Name:     "token_program",
-					Writable: false,
-					Signer:   false,
-					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
-					PDA:      nil,
-				},
-				{
-					Name:     "from",
+					Name:     "pending_withdrawal_ata",
 					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "to",
+					Name:     "pda_ata",
 					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "system_program",
+					Name:     "token_program",
 					Writable: false,
 					Signer:   false,
-					Address:  "11111111111111111111111111111111",
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
 					PDA:      nil,
 				},
 			},
 		},
 		{
-			Name:          "deposit_spl_token_and_call",
-			Discriminator: [8]byte{14, 181, 27, 187, 171, 61, 237, 147},
+			Name:          "close_deposit_buffer",
+			Discriminator: [8]byte{178, 198, 161, 97, 42, 56, 28, 115},
 			Accounts: []types.Account{
 				{
 					Name:     "signer",
@@ -155,42 +122,54 @@ var IDLGateway = types.IDL{
 					PDA:      nil,
 				},
 				{
-					Name:     "pda",
+					Name:     "deposit_buffer",
 					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
+			},
+		},
+		{
+			Name:          "close_message_buffer",
+			Discriminator: [8]byte{83, 99, 200, 51, 12, 83, 217, 227},
+			Accounts: []types.Account{
 				{
-					Name:     "whitelist_entry",
-					Writable: false,
-					Signer:   false,
+					Name:     "relayer",
+					Writable: true,
+					Signer:   true,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "mint_account",
-					Writable: false,
+					Name:     "message_buffer",
+					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
+			},
+		},
+		{
+			Name:          "configure_connected_program",
+			Discriminator: [8]byte{216, 110, 69, 84, 123, 87, 169, 71},
+			Accounts: []types.Account{
 				{
-					Name:     "token_program",
-					Writable: false,
-					Signer:   false,
-					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					Name:     "authority",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "from",
+					Name:     "pda",
 					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "to",
+					Name:     "connected_program",
 					Writable: true,
 					Signer:   false,
 					Address:  "",
@@ -206,8 +185,8 @@ var IDLGateway = types.IDL{
 			},
 		},
 		{
-			Name:          "execute",
-			Discriminator: [8]byte{130, 221, 242, 154, 13, 193, 189, 29},
+			Name:          "deposit",
+			Discriminator: [8]byte{242, 35, 198, 137, 82, 225, 242, 182},
 			Accounts: []types.Account{
 				{
 					Name:     "signer",
@@ -224,24 +203,24 @@ var IDLGateway = types.IDL{
 					PDA:      nil,
 				},
 				{
-					Name:     "destination_program",
-					Writable: false,
+					Name:     "rate_limit",
+					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "destination_program_pda",
-					Writable: true,
+					Name:     "system_program",
+					Writable: false,
 					Signer:   false,
-					Address:  "",
+					Address:  "11111111111111111111111111111111",
 					PDA:      nil,
 				},
 			},
 		},
 		{
-			Name:          "execute_spl_token",
-			Discriminator: [8]byte{121, 99, 79, 69, 77, 243, 46, 199},
+			Name:          "deposit_and_call",
+			Discriminator: [8]byte{65, 33, 186, 198, 114, 223, 133, 57},
 			Accounts: []types.Account{
 				{
 					Name:     "signer",
@@ -258,76 +237,81 @@ var IDLGateway = types.IDL{
 					PDA:      nil,
 				},
 				{
-					Name:     "pda_ata",
+					Name:     "rate_limit",
 					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "mint_account",
+					Name:     "system_program",
 					Writable: false,
 					Signer:   false,
-					Address:  "",
+					Address:  "11111111111111111111111111111111",
 					PDA:      nil,
 				},
+			},
+		},
+		{
+			Name:          "deposit_and_call_from_buffer",
+			Discriminator: [8]byte{76, 172, 34, 182, 146, 166, 11, 15},
+			Accounts: []types.Account{
 				{
-					Name:     "destination_program",
+					Name:     "deposit",
 					Writable: false,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "destination_program_pda",
+					Name:     "deposit_buffer",
 					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
+			},
+		},
+		{
+			Name:          "deposit_from_source",
+			Discriminator: [8]byte{154, 147, 119, 150, 144, 166, 216, 206},
+			Accounts: []types.Account{
 				{
-					Name:     "destination_program_pda_ata",
-					Writable: true,
+					Name:     "deposit",
+					Writable: false,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "token_program",
-					Writable: false,
-					Signer:   false,
-					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					Name:     "source",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
 					PDA:      nil,
 				},
+			},
+		},
+		{
+			Name:          "deposit_prefunded",
+			Discriminator: [8]byte{38, 149, 232, 77, 248, 223, 105, 108},
+			Accounts: []types.Account{
 				{
-					Name:     "associated_token_program",
+					Name:     "deposit",
 					Writable: false,
 					Signer:   false,
-					Address:  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "system_program",
+					Name:     "source",
 					Writable: false,
-					Signer:   false,
-					Address:  "11111111111111111111111111111111",
-					PDA:      nil,
-				},
-			},
-		},
-		{
-			Name:          "increment_nonce",
-			Discriminator: [8]byte{84, 149, 209, 233, 228, 66, 195, 237},
-			Accounts: []types.Account{
-				{
-					Name:     "signer",
-					Writable: true,
 					Signer:   true,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "pda",
+					Name:     "escrow",
 					Writable: true,
 					Signer:   false,
 					Address:  "",
@@ -336,8 +320,8 @@ var IDLGateway = types.IDL{
 			},
 		},
 		{
-			Name:          "initialize",
-			Discriminator: [8]byte{175, 175, 109, 31, 13, 152, 155, 237},
+			Name:          "deposit_spl_token",
+			Discriminator: [8]byte{86, 172, 212, 121, 63, 233, 96, 144},
 			Accounts: []types.Account{
 				{
 					Name:     "signer",
@@ -354,71 +338,59 @@ var IDLGateway = types.IDL{
 					PDA:      nil,
 				},
 				{
-					Name:     "system_program",
+					Name:     "whitelist_entry",
 					Writable: false,
 					Signer:   false,
-					Address:  "11111111111111111111111111111111",
+					Address:  "",
 					PDA:      nil,
 				},
-			},
-		},
-		{
-			Name:          "set_deposit_paused",
-			Discriminator: [8]byte{98, 179, 141, 24, 246, 120, 164, 143},
-			Accounts: []types.Account{
 				{
-					Name:     "signer",
-					Writable: true,
-					Signer:   true,
+					Name:     "mint_account",
+					Writable: false,
+					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "pda",
+					Name:     "rate_limit",
 					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
-			},
-		},
-		{
-			Name:          "unwhitelist_spl_mint",
-			Discriminator: [8]byte{73, 142, 63, 191, 233, 238, 170, 104},
-			Accounts: []types.Account{
 				{
-					Name:     "authority",
-					Writable: true,
-					Signer:   true,
-					Address:  "",
+					Name:     "token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
 					PDA:      nil,
 				},
 				{
-					Name:     "pda",
+					Name:     "from",
 					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "whitelist_entry",
+					Name:     "to",
 					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "whitelist_candidate",
+					Name:     "system_program",
 					Writable: false,
 					Signer:   false,
-					Address:  "",
+					Address:  "11111111111111111111111111111111",
 					PDA:      nil,
 				},
 			},
 		},
 		{
-			Name:          "update_authority",
-			Discriminator: [8]byte{32, 46, 64, 28, 149, 75, 243, 88},
+			Name:          "deposit_spl_token_and_call",
+			Discriminator: [8]byte{14, 181, 27, 187, 171, 61, 237, 147},
 			Accounts: []types.Account{
 				{
 					Name:     "signer",
@@ -434,56 +406,44 @@ var IDLGateway = types.IDL{
 					Address:  "",
 					PDA:      nil,
 				},
-			},
-		},
-		{
-			Name:          "update_tss",
-			Discriminator: [8]byte{227, 136, 3, 242, 177, 168, 10, 160},
-			Accounts: []types.Account{
 				{
-					Name:     "signer",
-					Writable: true,
-					Signer:   true,
+					Name:     "whitelist_entry",
+					Writable: false,
+					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "pda",
-					Writable: true,
+					Name:     "mint_account",
+					Writable: false,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
-			},
-		},
-		{
-			Name:          "whitelist_spl_mint",
-			Discriminator: [8]byte{30, 110, 162, 42, 208, 147, 254, 219},
-			Accounts: []types.Account{
 				{
-					Name:     "authority",
+					Name:     "rate_limit",
 					Writable: true,
-					Signer:   true,
+					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "pda",
-					Writable: true,
+					Name:     "token_program",
+					Writable: false,
 					Signer:   false,
-					Address:  "",
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
 					PDA:      nil,
 				},
 				{
-					Name:     "whitelist_entry",
+					Name:     "from",
 					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "whitelist_candidate",
-					Writable: false,
+					Name:     "to",
+					Writable: true,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
@@ -498,41 +458,2135 @@ var IDLGateway = types.IDL{
 			},
 		},
 		{
-			Name:          "withdraw",
-			Discriminator: [8]byte{183, 18, 70, 156, 148, 109, 161, 34},
+			Name:          "deposit_spl_token_with_authority",
+			Discriminator: [8]byte{183, 80, 196, 59, 9, 138, 54, 173},
 			Accounts: []types.Account{
 				{
-					Name:     "signer",
-					Writable: true,
-					Signer:   true,
-					Address:  "",
-					PDA:      nil,
-				},
-				{
-					Name:     "pda",
-					Writable: true,
+					Name:     "deposit",
+					Writable: false,
 					Signer:   false,
 					Address:  "",
 					PDA:      nil,
 				},
 				{
-					Name:     "recipient",
-					Writable: true,
-					Signer:   false,
+					Name:     "authority",
+					Writable: false,
+					Signer:   true,
 					Address:  "",
 					PDA:      nil,
 				},
 			},
 		},
 		{
-			Name:          "withdraw_spl_token",
-			Discriminator: [8]byte{219, 156, 234, 11, 89, 235, 246, 32},
+			Name:          "deposit_wsol",
+			Discriminator: [8]byte{8, 4, 29, 95, 138, 193, 86, 132},
 			Accounts: []types.Account{
 				{
-					Name:     "signer",
-					Writable: true,
-					Signer:   true,
-					Address:  "",
+					Name:     "deposit",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "mint_account",
+					Writable: false,
+					Signer:   false,
+					Address:  "So11111111111111111111111111111111111111112",
+					PDA:      nil,
+				},
+				{
+					Name:     "from",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "unwrap_account",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "deposit_wsol_and_call",
+			Discriminator: [8]byte{223, 181, 70, 255, 51, 86, 214, 130},
+			Accounts: []types.Account{
+				{
+					Name:     "deposit",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "mint_account",
+					Writable: false,
+					Signer:   false,
+					Address:  "So11111111111111111111111111111111111111112",
+					PDA:      nil,
+				},
+				{
+					Name:     "from",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "unwrap_account",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "execute",
+			Discriminator: [8]byte{130, 221, 242, 154, 13, 193, 189, 29},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "connected_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "caller_authority",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "execute_abort",
+			Discriminator: [8]byte{58, 63, 220, 97, 4, 33, 32, 131},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "connected_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "caller_authority",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "execute_arbitrary_call",
+			Discriminator: [8]byte{190, 67, 210, 227, 18, 212, 42, 146},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "connected_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "recipient",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "execute_as_account",
+			Discriminator: [8]byte{33, 65, 180, 36, 119, 185, 112, 199},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "connected_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "interchain_account",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "execute_from_buffer",
+			Discriminator: [8]byte{223, 110, 77, 84, 157, 246, 92, 132},
+			Accounts: []types.Account{
+				{
+					Name:     "execute",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "relayer",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "message_buffer",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "execute_multi",
+			Discriminator: [8]byte{172, 224, 124, 155, 85, 223, 138, 27},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "execute_revert",
+			Discriminator: [8]byte{68, 87, 162, 3, 218, 222, 236, 121},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "connected_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "caller_authority",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "execute_spl_token",
+			Discriminator: [8]byte{121, 99, 79, 69, 77, 243, 46, 199},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "mint_account",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "whitelist_entry",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "connected_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "caller_authority",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					PDA:      nil,
+				},
+				{
+					Name:     "associated_token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "execute_spl_token_abort",
+			Discriminator: [8]byte{3, 154, 130, 252, 92, 39, 73, 30},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "mint_account",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "whitelist_entry",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "connected_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "caller_authority",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					PDA:      nil,
+				},
+				{
+					Name:     "associated_token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "execute_spl_token_revert",
+			Discriminator: [8]byte{240, 160, 44, 253, 131, 37, 159, 196},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "mint_account",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "whitelist_entry",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "connected_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "caller_authority",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					PDA:      nil,
+				},
+				{
+					Name:     "associated_token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "execute_spl_token_revert_v2",
+			Discriminator: [8]byte{176, 140, 102, 49, 44, 73, 62, 81},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "mint_account",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "whitelist_entry",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "connected_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "caller_authority",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					PDA:      nil,
+				},
+				{
+					Name:     "associated_token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "execute_spl_token_v2",
+			Discriminator: [8]byte{48, 2, 24, 57, 55, 80, 110, 241},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "mint_account",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "whitelist_entry",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "connected_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "caller_authority",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					PDA:      nil,
+				},
+				{
+					Name:     "associated_token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "execute_wsol",
+			Discriminator: [8]byte{30, 194, 213, 46, 23, 216, 37, 147},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "mint_account",
+					Writable: false,
+					Signer:   false,
+					Address:  "So11111111111111111111111111111111111111112",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "connected_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "caller_authority",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					PDA:      nil,
+				},
+				{
+					Name:     "associated_token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "execute_wsol_revert",
+			Discriminator: [8]byte{146, 44, 3, 19, 44, 128, 83, 226},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "mint_account",
+					Writable: false,
+					Signer:   false,
+					Address:  "So11111111111111111111111111111111111111112",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "connected_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "caller_authority",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "destination_program_pda_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					PDA:      nil,
+				},
+				{
+					Name:     "associated_token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "finalize_withdrawal",
+			Discriminator: [8]byte{178, 87, 206, 68, 201, 186, 164, 232},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "payer",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "recipient",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "mint_account",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "recipient_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					PDA:      nil,
+				},
+				{
+					Name:     "associated_token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "grant_role",
+			Discriminator: [8]byte{218, 234, 128, 15, 82, 33, 236, 253},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "roles",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "guardian_pause",
+			Discriminator: [8]byte{184, 93, 27, 13, 127, 100, 198, 238},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "roles",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "increment_nonce",
+			Discriminator: [8]byte{84, 149, 209, 233, 228, 66, 195, 237},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "initialize",
+			Discriminator: [8]byte{175, 175, 109, 31, 13, 152, 155, 237},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "migrate_pda",
+			Discriminator: [8]byte{63, 57, 242, 207, 198, 15, 132, 124},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "migrate_whitelist_entry",
+			Discriminator: [8]byte{73, 220, 130, 117, 68, 77, 233, 243},
+			Accounts: []types.Account{
+				{
+					Name:     "authority",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "roles",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "whitelist_entry",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "whitelist_candidate",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "reset_circuit_breaker",
+			Discriminator: [8]byte{225, 48, 84, 136, 90, 146, 26, 149},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "roles",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "reset_nonce",
+			Discriminator: [8]byte{104, 139, 110, 85, 234, 155, 132, 248},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "roles",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "revoke_role",
+			Discriminator: [8]byte{179, 232, 2, 180, 48, 227, 82, 7},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "roles",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "set_circuit_breaker",
+			Discriminator: [8]byte{135, 207, 46, 31, 152, 94, 123, 247},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "set_connected_program",
+			Discriminator: [8]byte{211, 158, 18, 39, 4, 8, 158, 22},
+			Accounts: []types.Account{
+				{
+					Name:     "authority",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "connected_program",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "set_deposit_paused",
+			Discriminator: [8]byte{98, 179, 141, 24, 246, 120, 164, 143},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "roles",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "set_destination_allowlist",
+			Discriminator: [8]byte{96, 36, 214, 87, 93, 175, 207, 31},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "set_rate_limit",
+			Discriminator: [8]byte{42, 212, 44, 91, 198, 58, 60, 239},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "stage_deposit_message",
+			Discriminator: [8]byte{68, 170, 59, 74, 225, 102, 223, 163},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "deposit_buffer",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "stage_message",
+			Discriminator: [8]byte{113, 20, 59, 210, 57, 93, 110, 84},
+			Accounts: []types.Account{
+				{
+					Name:     "relayer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "message_buffer",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "unwhitelist_spl_mint",
+			Discriminator: [8]byte{73, 142, 63, 191, 233, 238, 170, 104},
+			Accounts: []types.Account{
+				{
+					Name:     "authority",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "roles",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "whitelist_entry",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "whitelist_candidate",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "update_authority",
+			Discriminator: [8]byte{32, 46, 64, 28, 149, 75, 243, 88},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "update_guardians",
+			Discriminator: [8]byte{43, 62, 250, 138, 141, 117, 132, 97},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "update_tss",
+			Discriminator: [8]byte{227, 136, 3, 242, 177, 168, 10, 160},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "roles",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "update_whitelist_entry",
+			Discriminator: [8]byte{120, 129, 100, 136, 175, 96, 26, 34},
+			Accounts: []types.Account{
+				{
+					Name:     "authority",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "roles",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "whitelist_entry",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "whitelist_candidate",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "whitelist_spl_mint",
+			Discriminator: [8]byte{30, 110, 162, 42, 208, 147, 254, 219},
+			Accounts: []types.Account{
+				{
+					Name:     "authority",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "roles",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "whitelist_entry",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "whitelist_candidate",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "withdraw",
+			Discriminator: [8]byte{183, 18, 70, 156, 148, 109, 161, 34},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "recipient",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "withdraw_spl_token",
+			Discriminator: [8]byte{219, 156, 234, 11, 89, 235, 246, 32},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "mint_account",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "whitelist_entry",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "recipient",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "recipient_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					PDA:      nil,
+				},
+				{
+					Name:     "associated_token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "withdraw_spl_token_v2",
+			Discriminator: [8]byte{152, 106, 232, 237, 228, 171, 123, 3},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
 					PDA:      nil,
 				},
 				{
@@ -556,6 +2610,103 @@ var IDLGateway = types.IDL{
 					Address:  "",
 					PDA:      nil,
 				},
+				{
+					Name:     "whitelist_entry",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "recipient",
+					Writable: false,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "recipient_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pending_withdrawal_ata",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					PDA:      nil,
+				},
+				{
+					Name:     "associated_token_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+					PDA:      nil,
+				},
+				{
+					Name:     "system_program",
+					Writable: false,
+					Signer:   false,
+					Address:  "11111111111111111111111111111111",
+					PDA:      nil,
+				},
+			},
+		},
+		{
+			Name:          "withdraw_wsol",
+			Discriminator: [8]byte{120, 47, 248, 213, 169, 214, 118, 5},
+			Accounts: []types.Account{
+				{
+					Name:     "signer",
+					Writable: true,
+					Signer:   true,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "pda",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "rate_limit",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
+				{
+					Name:     "mint_account",
+					Writable: false,
+					Signer:   false,
+					Address:  "So11111111111111111111111111111111111111112",
+					PDA:      nil,
+				},
 				{
 					Name:     "recipient",
 					Writable: false,
@@ -584,6 +2735,13 @@ var IDLGateway = types.IDL{
 					Address:  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
 					PDA:      nil,
 				},
+				{
+					Name:     "pending_withdrawal",
+					Writable: true,
+					Signer:   false,
+					Address:  "",
+					PDA:      nil,
+				},
 				{
 					Name:     "system_program",
 					Writable: false,
@@ -595,6 +2753,27 @@ var IDLGateway = types.IDL{
 		},
 	},
 	Accounts: []types.Account{
+		{
+			Name:     "ConnectedProgram",
+			Writable: false,
+			Signer:   false,
+			Address:  "",
+			PDA:      nil,
+		},
+		{
+			Name:     "DepositBuffer",
+			Writable: false,
+			Signer:   false,
+			Address:  "",
+			PDA:      nil,
+		},
+		{
+			Name:     "MessageBuffer",
+			Writable: false,
+			Signer:   false,
+			Address:  "",
+			PDA:      nil,
+		},
 		{
 			Name:     "Pda",
 			Writable: false,
@@ -602,6 +2781,27 @@ var IDLGateway = types.IDL{
 			Address:  "",
 			PDA:      nil,
 		},
+		{
+			Name:     "PendingWithdrawal",
+			Writable: false,
+			Signer:   false,
+			Address:  "",
+			PDA:      nil,
+		},
+		{
+			Name:     "RateLimit",
+			Writable: false,
+			Signer:   false,
+			Address:  "",
+			PDA:      nil,
+		},
+		{
+			Name:     "Roles",
+			Writable: false,
+			Signer:   false,
+			Address:  "",
+			PDA:      nil,
+		},
 		{
 			Name:     "WhitelistEntry",
 			Writable: false,
@@ -621,24 +2821,99 @@ var IDLGateway = types.IDL{
 		{Code: 6007, Name: "SPLAtaAndMintAddressMismatch", Msg: "SPLAtaAndMintAddressMismatch"},
 		{Code: 6008, Name: "EmptyReceiver", Msg: "EmptyReceiver"},
 		{Code: 6009, Name: "InvalidInstructionData", Msg: "InvalidInstructionData"},
+		{Code: 6010, Name: "MintNotWhitelisted", Msg: "MintNotWhitelisted"},
+		{Code: 6011, Name: "DepositBelowMinimum", Msg: "DepositBelowMinimum"},
+		{Code: 6012, Name: "WithdrawPaused", Msg: "WithdrawPaused"},
+		{Code: 6013, Name: "WhitelistEntryAlreadyMigrated", Msg: "WhitelistEntryAlreadyMigrated"},
+		{Code: 6014, Name: "RateLimitExceeded", Msg: "RateLimitExceeded"},
+		{Code: 6015, Name: "InvalidRateLimitConfig", Msg: "InvalidRateLimitConfig"},
+		{Code: 6016, Name: "OutboundPaused", Msg: "OutboundPaused"},
+		{Code: 6017, Name: "CircuitBreakerNotTripped", Msg: "CircuitBreakerNotTripped"},
+		{Code: 6018, Name: "GuardianResetDelayNotElapsed", Msg: "GuardianResetDelayNotElapsed"},
+		{Code: 6019, Name: "TooManyGuardians", Msg: "TooManyGuardians"},
+		{Code: 6020, Name: "PdaAlreadyMigrated", Msg: "PdaAlreadyMigrated"},
+		{Code: 6021, Name: "InvalidCircuitBreakerConfig", Msg: "InvalidCircuitBreakerConfig"},
+		{Code: 6022, Name: "PendingWithdrawalRequired", Msg: "PendingWithdrawalRequired"},
+		{Code: 6023, Name: "WithdrawalDelayNotElapsed", Msg: "WithdrawalDelayNotElapsed"},
+		{Code: 6024, Name: "TooManyRoleMembers", Msg: "TooManyRoleMembers"},
+		{Code: 6025, Name: "DestinationProgramBlocked", Msg: "DestinationProgramBlocked"},
+		{Code: 6026, Name: "DestinationProgramNotAllowed", Msg: "DestinationProgramNotAllowed"},
+		{Code: 6027, Name: "InvalidConnectedProgramConfig", Msg: "InvalidConnectedProgramConfig"},
+		{Code: 6028, Name: "DestinationProgramPdaMismatch", Msg: "DestinationProgramPdaMismatch"},
+		{Code: 6029, Name: "RecipientRequired", Msg: "RecipientRequired"},
+		{Code: 6030, Name: "ConnectedProgramAccountMissing", Msg: "ConnectedProgramAccountMissing"},
+		{Code: 6031, Name: "InvalidExecuteSteps", Msg: "InvalidExecuteSteps"},
+		{Code: 6032, Name: "InvalidMessageBuffer", Msg: "InvalidMessageBuffer"},
+		{Code: 6033, Name: "TooManyRemainingAccounts", Msg: "TooManyRemainingAccounts"},
+		{Code: 6034, Name: "DuplicateRemainingAccount", Msg: "DuplicateRemainingAccount"},
+		{Code: 6035, Name: "CustodyAccountNotAllowed", Msg: "CustodyAccountNotAllowed"},
+		{Code: 6036, Name: "WritableGatewayPda", Msg: "WritableGatewayPda"},
+		{Code: 6037, Name: "MessageExpired", Msg: "MessageExpired"},
+		{Code: 6038, Name: "InvalidRecoveryId", Msg: "InvalidRecoveryId"},
+		{Code: 6039, Name: "MalleableSignature", Msg: "MalleableSignature"},
+		{Code: 6040, Name: "SignatureRecoveryFailed", Msg: "SignatureRecoveryFailed"},
+		{Code: 6041, Name: "ExecuteInProgress", Msg: "ExecuteInProgress"},
+		{Code: 6042, Name: "EmptyDepositEscrow", Msg: "EmptyDepositEscrow"},
+		{Code: 6043, Name: "WithdrawalNotEscrowed", Msg: "WithdrawalNotEscrowed"},
+		{Code: 6044, Name: "RateLimitRequired", Msg: "RateLimitRequired"},
 	},
 }
 
 const (
-	InstructionCall                       = "call"
-	InstructionDeposit                    = "deposit"
-	InstructionDeposit_and_call           = "deposit_and_call"
-	InstructionDeposit_spl_token          = "deposit_spl_token"
-	InstructionDeposit_spl_token_and_call = "deposit_spl_token_and_call"
-	InstructionExecute                    = "execute"
-	InstructionExecute_spl_token          = "execute_spl_token"
-	InstructionIncrement_nonce            = "increment_nonce"
-	InstructionInitialize                 = "initialize"
-	InstructionSet_deposit_paused         = "set_deposit_paused"
-	InstructionUnwhitelist_spl_mint       = "unwhitelist_spl_mint"
-	InstructionUpdate_authority           = "update_authority"
-	InstructionUpdate_tss                 = "update_tss"
-	InstructionWhitelist_spl_mint         = "whitelist_spl_mint"
-	InstructionWithdraw                   = "withdraw"
-	InstructionWithdraw_spl_token         = "withdraw_spl_token"
+	InstructionCall                             = "call"
+	InstructionCancel_withdrawal                = "cancel_withdrawal"
+	InstructionClose_deposit_buffer             = "close_deposit_buffer"
+	InstructionClose_message_buffer             = "close_message_buffer"
+	InstructionConfigure_connected_program      = "configure_connected_program"
+	InstructionDeposit                          = "deposit"
+	InstructionDeposit_and_call                 = "deposit_and_call"
+	InstructionDeposit_and_call_from_buffer     = "deposit_and_call_from_buffer"
+	InstructionDeposit_from_source              = "deposit_from_source"
+	InstructionDeposit_prefunded                = "deposit_prefunded"
+	InstructionDeposit_spl_token                = "deposit_spl_token"
+	InstructionDeposit_spl_token_and_call       = "deposit_spl_token_and_call"
+	InstructionDeposit_spl_token_with_authority = "deposit_spl_token_with_authority"
+	InstructionDeposit_wsol                     = "deposit_wsol"
+	InstructionDeposit_wsol_and_call            = "deposit_wsol_and_call"
+	InstructionExecute                          = "execute"
+	InstructionExecute_abort                    = "execute_abort"
+	InstructionExecute_arbitrary_call           = "execute_arbitrary_call"
+	InstructionExecute_as_account               = "execute_as_account"
+	InstructionExecute_from_buffer              = "execute_from_buffer"
+	InstructionExecute_multi                    = "execute_multi"
+	InstructionExecute_revert                   = "execute_revert"
+	InstructionExecute_spl_token                = "execute_spl_token"
+	InstructionExecute_spl_token_abort          = "execute_spl_token_abort"
+	InstructionExecute_spl_token_revert         = "execute_spl_token_revert"
+	InstructionExecute_spl_token_revert_v2      = "execute_spl_token_revert_v2"
+	InstructionExecute_spl_token_v2             = "execute_spl_token_v2"
+	InstructionExecute_wsol                     = "execute_wsol"
+	InstructionExecute_wsol_revert              = "execute_wsol_revert"
+	InstructionFinalize_withdrawal              = "finalize_withdrawal"
+	InstructionGrant_role                       = "grant_role"
+	InstructionGuardian_pause                   = "guardian_pause"
+	InstructionIncrement_nonce                  = "increment_nonce"
+	InstructionInitialize                       = "initialize"
+	InstructionMigrate_pda                      = "migrate_pda"
+	InstructionMigrate_whitelist_entry          = "migrate_whitelist_entry"
+	InstructionReset_circuit_breaker            = "reset_circuit_breaker"
+	InstructionReset_nonce                      = "reset_nonce"
+	InstructionRevoke_role                      = "revoke_role"
+	InstructionSet_circuit_breaker              = "set_circuit_breaker"
+	InstructionSet_connected_program            = "set_connected_program"
+	InstructionSet_deposit_paused               = "set_deposit_paused"
+	InstructionSet_destination_allowlist        = "set_destination_allowlist"
+	InstructionSet_rate_limit                   = "set_rate_limit"
+	InstructionStage_deposit_message            = "stage_deposit_message"
+	InstructionStage_message                    = "stage_message"
+	InstructionUnwhitelist_spl_mint             = "unwhitelist_spl_mint"
+	InstructionUpdate_authority                 = "update_authority"
+	InstructionUpdate_guardians                 = "update_guardians"
+	InstructionUpdate_tss                       = "update_tss"
+	InstructionUpdate_whitelist_entry           = "update_whitelist_entry"
+	InstructionWhitelist_spl_mint               = "whitelist_spl_mint"
+	InstructionWithdraw                         = "withdraw"
+	InstructionWithdraw_spl_token               = "withdraw_spl_token"
+	InstructionWithdraw_spl_token_v2            = "withdraw_spl_token_v2"
+	InstructionWithdraw_wsol                    = "withdraw_wsol"
 )
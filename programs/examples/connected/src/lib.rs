@@ -1,5 +1,4 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{sysvar, sysvar::instructions::get_instruction_relative};
 use std::mem::size_of;
 
 declare_id!("4xEw862A2SEwMjofPkUyd4NEekmVJKJsdHkK3UkAtDrc");
@@ -13,31 +12,15 @@ pub mod connected {
         Ok(())
     }
 
-    pub fn on_call(
-        ctx: Context<OnCall>,
+    pub fn on_call<'info>(
+        ctx: Context<'_, '_, '_, 'info, OnCall<'info>>,
         amount: u64,
         sender: [u8; 20],
         data: Vec<u8>,
     ) -> Result<()> {
-        // NOTE: this is an illustration how connected programs can check if caller is gateway
-        // it is up to connected programs to configure sysvar account in remaining_accounts
+        // NOTE: caller is checked by requiring gateway caller authority PDA to be a signer (see OnCall)
+        // only the gateway can sign for it, also when the gateway itself is invoked via CPI
         // same approach can be used on on_revert and SPL connected programs
-        let current_ix = get_instruction_relative(
-            0,
-            &ctx.accounts.instruction_sysvar_account.to_account_info(),
-        )
-        .unwrap();
-
-        msg!(
-            "on_call invoked by: {}, gateway is {}",
-            current_ix.program_id,
-            gateway::ID
-        );
-
-        require!(
-            current_ix.program_id == gateway::ID,
-            ErrorCode::InvalidCaller
-        );
 
         let pda = &mut ctx.accounts.pda;
 
@@ -48,6 +31,22 @@ pub mod connected {
         let message = String::from_utf8(data).map_err(|_| ErrorCode::InvalidDataFormat)?;
         pda.last_message = message;
 
+        // Call back into the gateway, which must be rejected while execute is in progress
+        if pda.last_message == "reentrant_call" {
+            let gateway_program = ctx
+                .remaining_accounts
+                .first()
+                .ok_or(ErrorCode::MissingGatewayProgram)?;
+            let cpi_accounts = gateway::cpi::accounts::Call {
+                signer: pda.to_account_info(),
+                pda: ctx.accounts.gateway_pda.to_account_info(),
+            };
+            let signer_seeds: &[&[&[u8]]] = &[&[b"connected", &[ctx.bumps.pda]]];
+            let cpi_ctx =
+                CpiContext::new_with_signer(gateway_program.clone(), cpi_accounts, signer_seeds);
+            gateway::cpi::call(cpi_ctx, sender, b"reentrant".to_vec(), None)?;
+        }
+
         // Split the half equally among all remaining_accounts and pda
         let half = amount / 2;
         let rem_accounts_len = ctx.remaining_accounts.len() as u64;
@@ -114,6 +113,29 @@ pub mod connected {
         Ok(())
     }
 
+    pub fn on_abort(ctx: Context<OnAbort>, abort_context: gateway::AbortContext) -> Result<()> {
+        let message = String::from_utf8(abort_context.revert_message)
+            .map_err(|_| ErrorCode::InvalidDataFormat)?;
+
+        // Check if the message contains "revert" and return an error if so
+        if message.contains("revert") {
+            msg!("Reverting transaction due to message: '{}'", message);
+            return Err(ErrorCode::RevertMessage.into());
+        }
+
+        msg!(
+            "On abort executed with amount {}, asset {}, sender {:?}, outgoing {} and message {}, pda {}",
+            abort_context.amount,
+            abort_context.asset,
+            abort_context.sender,
+            abort_context.outgoing,
+            message,
+            ctx.accounts.pda.key()
+        );
+
+        Ok(())
+    }
+
     pub fn trigger_deposit(
         ctx: Context<TriggerDeposit>,
         amount: u64,
@@ -125,6 +147,7 @@ pub mod connected {
         let cpi_accounts = gateway::cpi::accounts::Deposit {
             signer: ctx.accounts.signer.to_account_info(),
             pda: ctx.accounts.gateway_pda.to_account_info(),
+            rate_limit: ctx.accounts.gateway_rate_limit.to_account_info(),
             system_program: ctx.accounts.system_program.to_account_info(),
         };
 
@@ -135,6 +158,39 @@ pub mod connected {
 
         Ok(())
     }
+
+    pub fn trigger_prefunded_deposit(
+        ctx: Context<TriggerPrefundedDeposit>,
+        amount: u64,
+        receiver: [u8; 20],
+        revert_options: Option<gateway::RevertOptions>,
+    ) -> Result<()> {
+        // The program-owned pda carries data, so it is debited directly into its gateway deposit escrow
+        ctx.accounts.pda.sub_lamports(amount)?;
+        ctx.accounts.gateway_escrow.add_lamports(amount)?;
+
+        let cpi_accounts = gateway::cpi::accounts::DepositPrefunded {
+            deposit: gateway::cpi::accounts::Deposit {
+                signer: ctx.accounts.signer.to_account_info(),
+                pda: ctx.accounts.gateway_pda.to_account_info(),
+                rate_limit: ctx.accounts.gateway_rate_limit.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            source: ctx.accounts.pda.to_account_info(),
+            escrow: ctx.accounts.gateway_escrow.to_account_info(),
+        };
+
+        let signer_seeds: &[&[&[u8]]] = &[&[b"connected", &[ctx.bumps.pda]]];
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.gateway_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+
+        gateway::cpi::deposit_prefunded(cpi_ctx, receiver, revert_options)?;
+
+        Ok(())
+    }
 }
 
 #[derive(Accounts)]
@@ -158,9 +214,13 @@ pub struct OnCall<'info> {
 
     pub system_program: Program<'info, System>,
 
-    /// CHECK: This is test program.
-    #[account(address = sysvar::instructions::id())]
-    instruction_sysvar_account: UncheckedAccount<'info>,
+    /// Gateway caller authority for this program, only the gateway can sign for it.
+    #[account(
+        seeds = [b"caller_authority", crate::ID.as_ref()],
+        bump,
+        seeds::program = gateway::ID
+    )]
+    pub gateway_caller: Signer<'info>,
 }
 
 #[derive(Accounts)]
@@ -174,6 +234,17 @@ pub struct OnRevert<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct OnAbort<'info> {
+    #[account(mut, seeds = [b"connected"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// CHECK: This is test program.
+    pub gateway_pda: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct TriggerDeposit<'info> {
     #[account(mut)]
@@ -183,6 +254,36 @@ pub struct TriggerDeposit<'info> {
     /// CHECK: Validated by the gateway program via seeds
     pub gateway_pda: UncheckedAccount<'info>,
 
+    #[account(mut)]
+    /// CHECK: Validated by the gateway program via seeds
+    pub gateway_rate_limit: UncheckedAccount<'info>,
+
+    /// CHECK: Only used for CPI
+    pub gateway_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TriggerPrefundedDeposit<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [b"connected"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    #[account(mut)]
+    /// CHECK: Validated by the gateway program via seeds
+    pub gateway_escrow: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Validated by the gateway program via seeds
+    pub gateway_pda: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Validated by the gateway program via seeds
+    pub gateway_rate_limit: UncheckedAccount<'info>,
+
     /// CHECK: Only used for CPI
     pub gateway_program: UncheckedAccount<'info>,
 
@@ -207,4 +308,7 @@ pub enum ErrorCode {
 
     #[msg("Caller is not the gateway program.")]
     InvalidCaller,
+
+    #[msg("Gateway program account is missing.")]
+    MissingGatewayProgram,
 }
@@ -48,7 +48,7 @@ pub mod connected_spl {
                     },
                     signer_seeds,
                 );
-                transfer_checked(xfer_ctx, share, 6)?;
+                transfer_checked(xfer_ctx, share, ctx.accounts.mint_account.decimals)?;
             }
         }
 
@@ -1,7 +1,7 @@
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token::{spl_token::native_mint, Mint, Token, TokenAccount};
 use std::mem::size_of;
 
 /// Instruction context for initializing the program.
@@ -30,19 +30,37 @@ pub struct Execute<'info> {
     #[account(mut, seeds = [b"meta"], bump)]
     pub pda: Account<'info, Pda>,
 
+    /// The SOL rate limit account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut, seeds = [b"rate_limit", native_mint::ID.as_ref()], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
     /// The destination program.
     /// CHECK: This is arbitrary program.
     pub destination_program: AccountInfo<'info>,
 
-    // Pda for destination program
+    /// The registry entry of the destination program, may be uninitialized.
     /// CHECK: Validation will occur during instruction processing.
-    #[account(
-        mut,
-        seeds = [b"connected"],
-        bump,
-        seeds::program = destination_program.key()
-    )]
+    #[account(seeds = [b"connected_program", destination_program.key().as_ref()], bump)]
+    pub connected_program: UncheckedAccount<'info>,
+
+    // Pda for destination program
+    /// CHECK: Validated against the custody seed in the connected program registry.
+    #[account(mut)]
     pub destination_program_pda: UncheckedAccount<'info>,
+
+    /// Gateway caller authority for the destination program, signs the call into it.
+    /// CHECK: Only used as a PDA signer.
+    #[account(seeds = [b"caller_authority", destination_program.key().as_ref()], bump)]
+    pub caller_authority: UncheckedAccount<'info>,
+
+    /// The pending withdrawal of the outbound, required above the delay threshold.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut)]
+    pub pending_withdrawal: Option<UncheckedAccount<'info>>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
 }
 
 /// Instruction context for increment nonce.
@@ -68,10 +86,86 @@ pub struct Deposit<'info> {
     #[account(mut, seeds = [b"meta"], bump)]
     pub pda: Account<'info, Pda>,
 
+    /// The SOL rate limit account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut, seeds = [b"rate_limit", native_mint::ID.as_ref()], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction context for staging a chunk of a deposit message.
+#[derive(Accounts)]
+#[instruction(total_len: u32)]
+pub struct StageDepositMessage<'info> {
+    /// The depositor staging the message.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The deposit buffer of the depositor.
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + 32 + 4 + total_len as usize,
+        seeds = [b"deposit_buffer", signer.key().as_ref()],
+        bump
+    )]
+    pub deposit_buffer: Account<'info, DepositBuffer>,
+
     /// The system program.
     pub system_program: Program<'info, System>,
 }
 
+/// Instruction context for closing an unused deposit buffer.
+#[derive(Accounts)]
+pub struct CloseDepositBuffer<'info> {
+    /// The depositor that staged the message.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// The deposit buffer of the depositor.
+    #[account(mut, seeds = [b"deposit_buffer", signer.key().as_ref()], bump, close = signer)]
+    pub deposit_buffer: Account<'info, DepositBuffer>,
+}
+
+/// Instruction context for depositing SOL with the message staged in a deposit buffer.
+#[derive(Accounts)]
+pub struct DepositFromBuffer<'info> {
+    /// The deposit accounts.
+    pub deposit: Deposit<'info>,
+
+    /// The deposit buffer of the depositor, closed after the deposit.
+    #[account(mut, seeds = [b"deposit_buffer", deposit.signer.key().as_ref()], bump)]
+    pub deposit_buffer: Account<'info, DepositBuffer>,
+}
+
+/// Instruction context for depositing SOL taken from a lamport source other than the signer.
+#[derive(Accounts)]
+pub struct DepositFromSource<'info> {
+    /// The deposit accounts, the signer pays the deposit fee.
+    pub deposit: Deposit<'info>,
+
+    /// The system account providing the deposited lamports, e.g. a vault PDA signed by its program.
+    #[account(mut)]
+    pub source: Signer<'info>,
+}
+
+/// Instruction context for depositing SOL credited to the deposit escrow of a source account.
+#[derive(Accounts)]
+pub struct DepositPrefunded<'info> {
+    /// The deposit accounts, the signer pays the deposit fee.
+    pub deposit: Deposit<'info>,
+
+    /// The account that funded the escrow, e.g. a program-owned account signed by its program.
+    pub source: Signer<'info>,
+
+    /// The deposit escrow of the source, emptied into the gateway PDA.
+    /// CHECK: System account holding lamports credited by the source.
+    #[account(mut, seeds = [b"deposit_escrow", source.key().as_ref()], bump)]
+    pub escrow: UncheckedAccount<'info>,
+}
+
 /// Instruction context for depositing SPL tokens.
 #[derive(Accounts)]
 pub struct DepositSplToken<'info> {
@@ -90,6 +184,11 @@ pub struct DepositSplToken<'info> {
     /// The mint account of the SPL token being deposited.
     pub mint_account: Account<'info, Mint>,
 
+    /// The rate limit account for the SPL token, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut, seeds = [b"rate_limit", mint_account.key().as_ref()], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
     /// The token program.
     pub token_program: Program<'info, Token>,
 
@@ -105,16 +204,54 @@ pub struct DepositSplToken<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Instruction context for depositing SPL tokens moved by a token authority other than the signer.
+#[derive(Accounts)]
+pub struct DepositSplTokenWithAuthority<'info> {
+    /// The deposit accounts, the signer pays the deposit fee.
+    pub deposit: DepositSplToken<'info>,
+
+    /// The owner or an approved delegate of the source token account.
+    pub authority: Signer<'info>,
+}
+
+/// Instruction context for depositing wrapped SOL, unwrapped and credited as SOL.
+#[derive(Accounts)]
+pub struct DepositWsol<'info> {
+    /// The deposit accounts, the unwrapped lamports are deposited from the signer.
+    pub deposit: Deposit<'info>,
+
+    /// The native mint.
+    #[account(address = native_mint::ID)]
+    pub mint_account: Account<'info, Mint>,
+
+    /// The source wSOL token account owned by the signer.
+    #[account(mut, constraint = from.mint == mint_account.key())]
+    pub from: Account<'info, TokenAccount>,
+
+    /// Temporary wSOL account of the gateway the deposited amount is unwrapped through.
+    /// CHECK: Created and closed during instruction processing.
+    #[account(mut, seeds = [b"wsol_unwrap", deposit.signer.key().as_ref()], bump)]
+    pub unwrap_account: UncheckedAccount<'info>,
+
+    /// The token program.
+    pub token_program: Program<'info, Token>,
+}
+
 /// Instruction context for call operation.
 #[derive(Accounts)]
 pub struct Call<'info> {
     /// The account of the signer making the call.
     #[account(mut)]
     pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
 }
 
 /// Instruction context for SOL withdrawal operations.
 #[derive(Accounts)]
+#[instruction(amount: u64, signature: [u8; 64], recovery_id: u8, message_hash: [u8; 32], nonce: u64)]
 pub struct Withdraw<'info> {
     /// The account of the signer making the withdrawal.
     #[account(mut)]
@@ -124,10 +261,67 @@ pub struct Withdraw<'info> {
     #[account(mut, seeds = [b"meta"], bump)]
     pub pda: Account<'info, Pda>,
 
+    /// The SOL rate limit account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut, seeds = [b"rate_limit", native_mint::ID.as_ref()], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
     /// The recipient account for the withdrawn SOL.
     /// CHECK: Recipient account is not read; ownership validation is unnecessary.
     #[account(mut)]
     pub recipient: UncheckedAccount<'info>,
+
+    /// The pending withdrawal account, required if the amount is above the delay threshold.
+    /// CHECK: Created during instruction processing.
+    #[account(mut, seeds = [b"pending_withdrawal", nonce.to_le_bytes().as_ref()], bump)]
+    pub pending_withdrawal: Option<UncheckedAccount<'info>>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction context for SOL withdrawals delivered as wrapped SOL.
+#[derive(Accounts)]
+pub struct WithdrawWsol<'info> {
+    /// The account of the signer making the withdrawal.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The SOL rate limit account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut, seeds = [b"rate_limit", native_mint::ID.as_ref()], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// The native mint.
+    #[account(address = native_mint::ID)]
+    pub mint_account: Account<'info, Mint>,
+
+    /// The recipient account for the withdrawn wrapped SOL.
+    /// CHECK: Recipient account is not read; ownership validation is unnecessary.
+    pub recipient: UncheckedAccount<'info>,
+
+    /// The recipient's associated token account for the native mint.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut)]
+    pub recipient_ata: AccountInfo<'info>,
+
+    /// The token program.
+    pub token_program: Program<'info, Token>,
+
+    /// The associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// The pending withdrawal of the outbound, required above the delay threshold.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut)]
+    pub pending_withdrawal: Option<UncheckedAccount<'info>>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
 }
 
 /// Instruction context for SPL token withdrawal operations.
@@ -148,6 +342,16 @@ pub struct WithdrawSPLToken<'info> {
     /// The mint account of the SPL token being withdrawn.
     pub mint_account: Account<'info, Mint>,
 
+    /// The whitelist entry account for the SPL token, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"whitelist", mint_account.key().as_ref()], bump)]
+    pub whitelist_entry: UncheckedAccount<'info>,
+
+    /// The rate limit account for the SPL token, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut, seeds = [b"rate_limit", mint_account.key().as_ref()], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
     /// The recipient account for the withdrawn tokens.
     /// CHECK: Recipient account is not read; ownership validation is unnecessary.
     pub recipient: UncheckedAccount<'info>,
@@ -157,6 +361,17 @@ pub struct WithdrawSPLToken<'info> {
     #[account(mut)]
     pub recipient_ata: AccountInfo<'info>,
 
+    /// The pending withdrawal account, required if the amount is above the delay threshold.
+    /// Derived from the signed nonce, verified during instruction processing.
+    /// CHECK: Created during instruction processing.
+    #[account(mut)]
+    pub pending_withdrawal: Option<UncheckedAccount<'info>>,
+
+    /// The associated token account of the pending withdrawal escrowing the tokens.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut)]
+    pub pending_withdrawal_ata: Option<UncheckedAccount<'info>>,
+
     /// The token program.
     pub token_program: Program<'info, Token>,
 
@@ -167,6 +382,111 @@ pub struct WithdrawSPLToken<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Instruction context for paying out a queued withdrawal.
+#[derive(Accounts)]
+pub struct FinalizeWithdrawal<'info> {
+    /// The account of the signer finalizing the withdrawal, anyone.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The pending withdrawal, closed to the payer once paid out.
+    #[account(
+        mut,
+        seeds = [b"pending_withdrawal", pending_withdrawal.nonce.to_le_bytes().as_ref()],
+        bump,
+        has_one = payer,
+        has_one = recipient,
+        close = payer
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+
+    /// The account that paid the rent for the pending withdrawal.
+    /// CHECK: Validated against the pending withdrawal.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    /// The recipient of the withdrawal.
+    /// CHECK: Validated against the pending withdrawal.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// The mint account of the SPL token, required for SPL token withdrawals.
+    pub mint_account: Option<Account<'info, Mint>>,
+
+    /// The associated token account of the pending withdrawal, required for SPL token withdrawals.
+    #[account(mut)]
+    pub pending_withdrawal_ata: Option<Account<'info, TokenAccount>>,
+
+    /// The recipient's associated token account, required for SPL token withdrawals.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut)]
+    pub recipient_ata: Option<UncheckedAccount<'info>>,
+
+    /// The token program, required for SPL token withdrawals.
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// The associated token program, required for SPL token withdrawals.
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction context for cancelling a queued withdrawal.
+#[derive(Accounts)]
+pub struct CancelWithdrawal<'info> {
+    /// The account of the authority, pauser or guardian cancelling the withdrawal.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The roles account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: UncheckedAccount<'info>,
+
+    /// The rate limit account of the withdrawn asset, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut, seeds = [b"rate_limit", pending_withdrawal.asset.as_ref()], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// The pending withdrawal, closed to the payer once cancelled.
+    #[account(
+        mut,
+        seeds = [b"pending_withdrawal", pending_withdrawal.nonce.to_le_bytes().as_ref()],
+        bump,
+        has_one = payer,
+        close = payer
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+
+    /// The account that paid the rent for the pending withdrawal and its escrow.
+    /// CHECK: Validated against the pending withdrawal.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    /// The mint account of the SPL token, required for SPL token withdrawals.
+    pub mint_account: Option<Account<'info, Mint>>,
+
+    /// The associated token account of the pending withdrawal, required for SPL token withdrawals.
+    #[account(mut)]
+    pub pending_withdrawal_ata: Option<Account<'info, TokenAccount>>,
+
+    /// The associated token account for the Gateway PDA, required for SPL token withdrawals.
+    #[account(mut)]
+    pub pda_ata: Option<Account<'info, TokenAccount>>,
+
+    /// The token program, required for SPL token withdrawals.
+    pub token_program: Option<Program<'info, Token>>,
+}
+
 #[derive(Accounts)]
 pub struct ExecuteSPLToken<'info> {
     /// The account of the signer making the withdrawal.
@@ -184,20 +504,35 @@ pub struct ExecuteSPLToken<'info> {
     /// The mint account of the SPL token being withdrawn.
     pub mint_account: Account<'info, Mint>,
 
+    /// The whitelist entry account for the SPL token, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"whitelist", mint_account.key().as_ref()], bump)]
+    pub whitelist_entry: UncheckedAccount<'info>,
+
+    /// The rate limit account for the SPL token, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut, seeds = [b"rate_limit", mint_account.key().as_ref()], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
     /// The destination program.
     /// CHECK: This is arbitrary program.
     pub destination_program: AccountInfo<'info>,
 
-    // Pda for destination program
+    /// The registry entry of the destination program, may be uninitialized.
     /// CHECK: Validation will occur during instruction processing.
-    #[account(
-        mut,
-        seeds = [b"connected"],
-        bump,
-        seeds::program = destination_program.key()
-    )]
+    #[account(seeds = [b"connected_program", destination_program.key().as_ref()], bump)]
+    pub connected_program: UncheckedAccount<'info>,
+
+    // Pda for destination program
+    /// CHECK: Validated against the custody seed in the connected program registry.
+    #[account(mut)]
     pub destination_program_pda: UncheckedAccount<'info>,
 
+    /// Gateway caller authority for the destination program, signs the call into it.
+    /// CHECK: Only used as a PDA signer.
+    #[account(seeds = [b"caller_authority", destination_program.key().as_ref()], bump)]
+    pub caller_authority: UncheckedAccount<'info>,
+
     /// The destination program associated token account.
     /// CHECK: Validation will occur during instruction processing.
     #[account(mut)]
@@ -209,10 +544,232 @@ pub struct ExecuteSPLToken<'info> {
     /// The associated token program.
     pub associated_token_program: Program<'info, AssociatedToken>,
 
+    /// The pending withdrawal of the outbound, required above the delay threshold.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut)]
+    pub pending_withdrawal: Option<UncheckedAccount<'info>>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction context for executing a call on program with SOL delivered as wrapped SOL.
+#[derive(Accounts)]
+pub struct ExecuteWsol<'info> {
+    /// The account of the signer making the withdrawal.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The SOL rate limit account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut, seeds = [b"rate_limit", native_mint::ID.as_ref()], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// The native mint.
+    #[account(address = native_mint::ID)]
+    pub mint_account: Account<'info, Mint>,
+
+    /// The destination program.
+    /// CHECK: This is arbitrary program.
+    pub destination_program: AccountInfo<'info>,
+
+    /// The registry entry of the destination program, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"connected_program", destination_program.key().as_ref()], bump)]
+    pub connected_program: UncheckedAccount<'info>,
+
+    // Pda for destination program
+    /// CHECK: Validated against the custody seed in the connected program registry.
+    #[account(mut)]
+    pub destination_program_pda: UncheckedAccount<'info>,
+
+    /// Gateway caller authority for the destination program, signs the call into it.
+    /// CHECK: Only used as a PDA signer.
+    #[account(seeds = [b"caller_authority", destination_program.key().as_ref()], bump)]
+    pub caller_authority: UncheckedAccount<'info>,
+
+    /// The destination program associated token account for the native mint.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut)]
+    pub destination_program_pda_ata: AccountInfo<'info>,
+
+    /// The token program.
+    pub token_program: Program<'info, Token>,
+
+    /// The associated token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// The pending withdrawal of the outbound, required above the delay threshold.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut)]
+    pub pending_withdrawal: Option<UncheckedAccount<'info>>,
+
     /// The system program.
     pub system_program: Program<'info, System>,
 }
 
+/// Instruction context for invoking an arbitrary instruction signed by TSS.
+#[derive(Accounts)]
+pub struct ExecuteArbitraryCall<'info> {
+    /// The account of the signer relaying the call.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The SOL rate limit account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut, seeds = [b"rate_limit", native_mint::ID.as_ref()], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// The program invoked by the call.
+    /// CHECK: This is arbitrary program.
+    pub destination_program: AccountInfo<'info>,
+
+    /// The registry entry of the destination program, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"connected_program", destination_program.key().as_ref()], bump)]
+    pub connected_program: UncheckedAccount<'info>,
+
+    /// The account receiving lamports from the gateway, required if amount is not zero.
+    /// CHECK: Included in the signed message.
+    #[account(mut)]
+    pub recipient: Option<UncheckedAccount<'info>>,
+
+    /// The pending withdrawal of the outbound, required above the delay threshold.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut)]
+    pub pending_withdrawal: Option<UncheckedAccount<'info>>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction context for executing a call signed by the interchain account of a ZEVM sender.
+#[derive(Accounts)]
+#[instruction(sender: [u8; 20])]
+pub struct ExecuteAsAccount<'info> {
+    /// The account of the signer relaying the call.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The destination program.
+    /// CHECK: This is arbitrary program.
+    pub destination_program: AccountInfo<'info>,
+
+    /// The registry entry of the destination program, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"connected_program", destination_program.key().as_ref()], bump)]
+    pub connected_program: UncheckedAccount<'info>,
+
+    /// Interchain account of the ZEVM sender, signs the call into the destination program.
+    /// CHECK: Only used as a PDA signer.
+    #[account(mut, seeds = [b"interchain_account", sender.as_ref()], bump)]
+    pub interchain_account: UncheckedAccount<'info>,
+}
+
+/// Instruction context for executing several calls in one outbound.
+#[derive(Accounts)]
+pub struct ExecuteMulti<'info> {
+    /// The account of the signer relaying the calls.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The SOL rate limit account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut, seeds = [b"rate_limit", native_mint::ID.as_ref()], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// The pending withdrawal of the outbound, required above the delay threshold.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut)]
+    pub pending_withdrawal: Option<UncheckedAccount<'info>>,
+
+    /// The token program, moves the tokens of the steps.
+    pub token_program: Program<'info, Token>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction context for staging a chunk of an execute payload.
+#[derive(Accounts)]
+#[instruction(nonce: u64, total_len: u32)]
+pub struct StageMessage<'info> {
+    /// The relayer staging the payload.
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    /// The message buffer of the outbound.
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + 32 + 8 + 4 + total_len as usize,
+        seeds = [b"message_buffer", relayer.key().as_ref(), nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub message_buffer: Account<'info, MessageBuffer>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction context for closing an unused message buffer.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct CloseMessageBuffer<'info> {
+    /// The relayer that staged the payload.
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    /// The message buffer of the outbound.
+    #[account(
+        mut,
+        seeds = [b"message_buffer", relayer.key().as_ref(), nonce.to_le_bytes().as_ref()],
+        bump,
+        has_one = relayer,
+        close = relayer
+    )]
+    pub message_buffer: Account<'info, MessageBuffer>,
+}
+
+/// Instruction context for executing a call with the payload staged in a message buffer.
+#[derive(Accounts)]
+#[instruction(amount: u64, sender: [u8; 20], signature: [u8; 64], recovery_id: u8, message_hash: [u8; 32], nonce: u64)]
+pub struct ExecuteFromBuffer<'info> {
+    /// The execute accounts.
+    pub execute: Execute<'info>,
+
+    /// The relayer that staged the payload, refunded the rent of the message buffer.
+    /// CHECK: Validated against the message buffer.
+    #[account(mut)]
+    pub relayer: UncheckedAccount<'info>,
+
+    /// The message buffer of the outbound.
+    #[account(
+        mut,
+        seeds = [b"message_buffer", relayer.key().as_ref(), nonce.to_le_bytes().as_ref()],
+        bump,
+        has_one = relayer,
+        close = relayer
+    )]
+    pub message_buffer: Account<'info, MessageBuffer>,
+}
+
 /// Instruction context for updating the TSS address.
 #[derive(Accounts)]
 pub struct UpdateTss<'info> {
@@ -223,6 +780,11 @@ pub struct UpdateTss<'info> {
     /// Gateway PDA.
     #[account(mut, seeds = [b"meta"], bump)]
     pub pda: Account<'info, Pda>,
+
+    /// The roles account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: UncheckedAccount<'info>,
 }
 
 /// Instruction context for updating the PDA authority.
@@ -247,6 +809,11 @@ pub struct ResetNonce<'info> {
     /// Gateway PDA.
     #[account(mut, seeds = [b"meta"], bump)]
     pub pda: Account<'info, Pda>,
+
+    /// The roles account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: UncheckedAccount<'info>,
 }
 
 /// Instruction context for pausing or unpausing deposits.
@@ -259,6 +826,28 @@ pub struct UpdatePaused<'info> {
     /// Gateway PDA.
     #[account(mut, seeds = [b"meta"], bump)]
     pub pda: Account<'info, Pda>,
+
+    /// The roles account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: UncheckedAccount<'info>,
+}
+
+/// Instruction context for pausing deposits and outbounds by a guardian.
+#[derive(Accounts)]
+pub struct GuardianPause<'info> {
+    /// The account of the guardian performing the pause.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The roles account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: UncheckedAccount<'info>,
 }
 
 /// Instruction context for whitelisting SPL tokens.
@@ -272,10 +861,15 @@ pub struct Whitelist<'info> {
     #[account(mut, seeds = [b"meta"], bump)]
     pub pda: Account<'info, Pda>,
 
+    /// The roles account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: UncheckedAccount<'info>,
+
     /// The whitelist entry account being initialized.
     #[account(
         init,
-        space = 8,
+        space = size_of::<WhitelistEntry>() + 8,
         payer = authority,
         seeds = [b"whitelist", whitelist_candidate.key().as_ref()],
         bump
@@ -300,6 +894,11 @@ pub struct Unwhitelist<'info> {
     #[account(mut, seeds = [b"meta"], bump)]
     pub pda: Account<'info, Pda>,
 
+    /// The roles account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: UncheckedAccount<'info>,
+
     /// The whitelist entry account being closed.
     #[account(
         mut,
@@ -313,6 +912,230 @@ pub struct Unwhitelist<'info> {
     pub whitelist_candidate: Account<'info, Mint>,
 }
 
+/// Instruction context for updating the policy of a whitelisted SPL token.
+#[derive(Accounts)]
+pub struct UpdateWhitelistEntry<'info> {
+    /// The account of the authority performing the operation.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The roles account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: UncheckedAccount<'info>,
+
+    /// The whitelist entry account being updated.
+    #[account(mut, seeds = [b"whitelist", whitelist_candidate.key().as_ref()], bump)]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+
+    /// The mint account of the whitelisted SPL token.
+    pub whitelist_candidate: Account<'info, Mint>,
+}
+
+/// Instruction context for migrating a legacy whitelist entry to the current layout.
+#[derive(Accounts)]
+pub struct MigrateWhitelistEntry<'info> {
+    /// The account of the authority performing the operation.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The roles account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: UncheckedAccount<'info>,
+
+    /// The legacy whitelist entry account being reallocated.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut, seeds = [b"whitelist", whitelist_candidate.key().as_ref()], bump)]
+    pub whitelist_entry: UncheckedAccount<'info>,
+
+    /// The mint account of the whitelisted SPL token.
+    pub whitelist_candidate: Account<'info, Mint>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction context for configuring the rate limit of an asset.
+#[derive(Accounts)]
+#[instruction(asset: Pubkey)]
+pub struct SetRateLimit<'info> {
+    /// The account of the signer performing the update.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The rate limit account of the asset.
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = size_of::<RateLimit>() + 8,
+        seeds = [b"rate_limit", asset.as_ref()],
+        bump
+    )]
+    pub rate_limit: Account<'info, RateLimit>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction context for configuring the circuit breaker.
+#[derive(Accounts)]
+pub struct UpdateCircuitBreaker<'info> {
+    /// The account of the signer performing the update.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+}
+
+/// Instruction context for updating the guardians.
+#[derive(Accounts)]
+pub struct UpdateGuardians<'info> {
+    /// The account of the signer performing the update.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+}
+
+/// Instruction context for resetting a tripped circuit breaker.
+#[derive(Accounts)]
+pub struct ResetCircuitBreaker<'info> {
+    /// The account of the authority or guardian performing the reset.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The roles account, may be uninitialized.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(seeds = [b"roles"], bump)]
+    pub roles: UncheckedAccount<'info>,
+}
+
+/// Instruction context for migrating the gateway PDA to the current layout.
+#[derive(Accounts)]
+pub struct MigratePda<'info> {
+    /// The account of the authority paying for the reallocation.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA in the legacy layout.
+    /// CHECK: Validation will occur during instruction processing.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: UncheckedAccount<'info>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction context for granting and revoking roles.
+#[derive(Accounts)]
+pub struct UpdateRoles<'info> {
+    /// The account of the signer performing the update.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The roles account.
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = size_of::<Roles>() + 8,
+        seeds = [b"roles"],
+        bump
+    )]
+    pub roles: Account<'info, Roles>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction context for registering a destination program.
+#[derive(Accounts)]
+#[instruction(program_id: Pubkey)]
+pub struct SetConnectedProgram<'info> {
+    /// The account of the authority or TSS relayer performing the update.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The registry entry of the destination program.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = size_of::<ConnectedProgram>() + 8,
+        seeds = [b"connected_program", program_id.as_ref()],
+        bump
+    )]
+    pub connected_program: Account<'info, ConnectedProgram>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction context for configuring the integration of a destination program.
+#[derive(Accounts)]
+#[instruction(program_id: Pubkey)]
+pub struct ConfigureConnectedProgram<'info> {
+    /// The account of the authority or TSS relayer performing the update.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+
+    /// The registry entry of the destination program.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = size_of::<ConnectedProgram>() + 8,
+        seeds = [b"connected_program", program_id.as_ref()],
+        bump
+    )]
+    pub connected_program: Account<'info, ConnectedProgram>,
+
+    /// The system program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction context for enabling or disabling the destination allowlist.
+#[derive(Accounts)]
+pub struct UpdateDestinationAllowlist<'info> {
+    /// The account of the signer performing the update.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// Gateway PDA.
+    #[account(mut, seeds = [b"meta"], bump)]
+    pub pda: Account<'info, Pda>,
+}
+
 /// Instruction context for checking upgrade status
 #[derive(Accounts)]
 pub struct Upgrade<'info> {
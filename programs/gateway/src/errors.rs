@@ -23,4 +23,74 @@ pub enum Errors {
     EmptyReceiver,
     #[msg("InvalidInstructionData")]
     InvalidInstructionData,
+    #[msg("MintNotWhitelisted")]
+    MintNotWhitelisted,
+    #[msg("DepositBelowMinimum")]
+    DepositBelowMinimum,
+    #[msg("WithdrawPaused")]
+    WithdrawPaused,
+    #[msg("WhitelistEntryAlreadyMigrated")]
+    WhitelistEntryAlreadyMigrated,
+    #[msg("RateLimitExceeded")]
+    RateLimitExceeded,
+    #[msg("InvalidRateLimitConfig")]
+    InvalidRateLimitConfig,
+    #[msg("OutboundPaused")]
+    OutboundPaused,
+    #[msg("CircuitBreakerNotTripped")]
+    CircuitBreakerNotTripped,
+    #[msg("GuardianResetDelayNotElapsed")]
+    GuardianResetDelayNotElapsed,
+    #[msg("TooManyGuardians")]
+    TooManyGuardians,
+    #[msg("PdaAlreadyMigrated")]
+    PdaAlreadyMigrated,
+    #[msg("InvalidCircuitBreakerConfig")]
+    InvalidCircuitBreakerConfig,
+    #[msg("PendingWithdrawalRequired")]
+    PendingWithdrawalRequired,
+    #[msg("WithdrawalDelayNotElapsed")]
+    WithdrawalDelayNotElapsed,
+    #[msg("TooManyRoleMembers")]
+    TooManyRoleMembers,
+    #[msg("DestinationProgramBlocked")]
+    DestinationProgramBlocked,
+    #[msg("DestinationProgramNotAllowed")]
+    DestinationProgramNotAllowed,
+    #[msg("InvalidConnectedProgramConfig")]
+    InvalidConnectedProgramConfig,
+    #[msg("DestinationProgramPdaMismatch")]
+    DestinationProgramPdaMismatch,
+    #[msg("RecipientRequired")]
+    RecipientRequired,
+    #[msg("ConnectedProgramAccountMissing")]
+    ConnectedProgramAccountMissing,
+    #[msg("InvalidExecuteSteps")]
+    InvalidExecuteSteps,
+    #[msg("InvalidMessageBuffer")]
+    InvalidMessageBuffer,
+    #[msg("TooManyRemainingAccounts")]
+    TooManyRemainingAccounts,
+    #[msg("DuplicateRemainingAccount")]
+    DuplicateRemainingAccount,
+    #[msg("CustodyAccountNotAllowed")]
+    CustodyAccountNotAllowed,
+    #[msg("WritableGatewayPda")]
+    WritableGatewayPda,
+    #[msg("MessageExpired")]
+    MessageExpired,
+    #[msg("InvalidRecoveryId")]
+    InvalidRecoveryId,
+    #[msg("MalleableSignature")]
+    MalleableSignature,
+    #[msg("SignatureRecoveryFailed")]
+    SignatureRecoveryFailed,
+    #[msg("ExecuteInProgress")]
+    ExecuteInProgress,
+    #[msg("EmptyDepositEscrow")]
+    EmptyDepositEscrow,
+    #[msg("WithdrawalNotEscrowed")]
+    WithdrawalNotEscrowed,
+    #[msg("RateLimitRequired")]
+    RateLimitRequired,
 }
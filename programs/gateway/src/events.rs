@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{FlowDirection, Role};
+
+/// Emitted when the usage of a rate limit crosses its warning threshold.
+#[event]
+pub struct RateLimitWarning {
+    /// The asset being rate limited, the native mint for SOL.
+    pub asset: Pubkey,
+    /// The direction of the tracked flow.
+    pub direction: FlowDirection,
+    /// The amount used in the current window.
+    pub used: u64,
+    /// The limit of the current window.
+    pub limit: u64,
+}
+
+/// Emitted when outflow within a window trips the circuit breaker and pauses outbounds.
+#[event]
+pub struct CircuitBreakerTripped {
+    /// The asset whose outflow tripped the circuit breaker, the native mint for SOL.
+    pub asset: Pubkey,
+    /// The amount withdrawn or executed in the current window.
+    pub outflow: u64,
+    /// The custody of the asset before the outbound that tripped the circuit breaker.
+    pub custody: u64,
+}
+
+/// Emitted when a guardian pauses deposits or outbounds.
+#[event]
+pub struct GuardianPaused {
+    /// The guardian or pauser tripping the pause.
+    pub guardian: Pubkey,
+    /// Whether deposits were paused.
+    pub deposits: bool,
+    /// Whether outbounds were paused.
+    pub outbounds: bool,
+}
+
+/// Emitted when the circuit breaker is reset and outbounds resume.
+#[event]
+pub struct CircuitBreakerReset {
+    /// The authority or guardian resetting the circuit breaker.
+    pub signer: Pubkey,
+}
+
+/// Emitted when a withdrawal above the delay threshold is escrowed for delayed settlement.
+#[event]
+pub struct WithdrawalQueued {
+    /// The nonce of the withdrawal.
+    pub nonce: u64,
+    /// The asset being withdrawn, the native mint for SOL.
+    pub asset: Pubkey,
+    /// The recipient of the withdrawal.
+    pub recipient: Pubkey,
+    /// The amount being withdrawn.
+    pub amount: u64,
+    /// Unix timestamp after which the withdrawal can be finalized.
+    pub executable_at: i64,
+}
+
+/// Emitted when a queued withdrawal is paid out.
+#[event]
+pub struct WithdrawalFinalized {
+    /// The nonce of the withdrawal.
+    pub nonce: u64,
+    /// The asset withdrawn, the native mint for SOL.
+    pub asset: Pubkey,
+    /// The recipient of the withdrawal.
+    pub recipient: Pubkey,
+    /// The amount withdrawn.
+    pub amount: u64,
+}
+
+/// Emitted when a queued withdrawal is cancelled and closed, the outbound is to be reverted on ZetaChain.
+#[event]
+pub struct WithdrawalCancelled {
+    /// The nonce of the withdrawal.
+    pub nonce: u64,
+    /// The asset of the withdrawal, the native mint for SOL.
+    pub asset: Pubkey,
+    /// The recipient of the withdrawal.
+    pub recipient: Pubkey,
+    /// The amount of the withdrawal.
+    pub amount: u64,
+    /// The authority, pauser or guardian cancelling the withdrawal.
+    pub signer: Pubkey,
+}
+
+/// Emitted when a role is granted to a key.
+#[event]
+pub struct RoleGranted {
+    /// The key receiving the role.
+    pub member: Pubkey,
+    /// The role granted.
+    pub role: Role,
+}
+
+/// Emitted when a role is revoked from a key.
+#[event]
+pub struct RoleRevoked {
+    /// The key losing the role.
+    pub member: Pubkey,
+    /// The role revoked.
+    pub role: Role,
+}
+
+/// Emitted when a destination program returns data from an execute call.
+#[event]
+pub struct ExecuteReturnData {
+    /// The destination program that returned the data.
+    pub destination_program: Pubkey,
+    /// The nonce of the outbound.
+    pub nonce: u64,
+    /// The returned data, truncated to the maximum forwarded size.
+    pub data: Vec<u8>,
+}
+
+/// Emitted when a deposit and call consumes the message staged in a deposit buffer.
+#[event]
+pub struct DepositAndCallFromBuffer {
+    /// The depositor.
+    pub sender: Pubkey,
+    /// The receiver on ZetaChain zEVM.
+    pub receiver: [u8; 20],
+    /// The amount deposited.
+    pub amount: u64,
+    /// The deposit buffer the message was staged in.
+    pub buffer: Pubkey,
+    /// The keccak hash of the staged message.
+    pub message_hash: [u8; 32],
+    /// The length of the staged message.
+    pub message_len: u32,
+}
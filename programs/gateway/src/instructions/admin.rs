@@ -1,15 +1,27 @@
 use crate::{
     contexts::{
-        Initialize, ResetNonce, Unwhitelist, UpdateAuthority, UpdatePaused, UpdateTss, Whitelist,
+        ConfigureConnectedProgram, GuardianPause, Initialize, MigratePda, MigrateWhitelistEntry,
+        ResetCircuitBreaker, ResetNonce, SetConnectedProgram, SetRateLimit, Unwhitelist,
+        UpdateAuthority, UpdateCircuitBreaker, UpdateDestinationAllowlist, UpdateGuardians,
+        UpdatePaused, UpdateRoles, UpdateTss, UpdateWhitelistEntry, Whitelist,
+    },
+    errors::Errors,
+    events::{CircuitBreakerReset, GuardianPaused, RoleGranted, RoleRevoked},
+    state::{
+        ConnectedProgramConfig, ConnectedProgramStatus, InstructionId, RateLimitConfig, Role,
+        WhitelistEntry, WhitelistEntryConfig,
     },
-    state::InstructionId,
     utils::{
-        recover_and_verify_eth_address, validate_message_hash, verify_and_update_nonce,
-        verify_authority,
+        has_role, recover_and_verify_eth_address, validate_message_hash, verify_and_update_nonce,
+        verify_authority, verify_role, MAX_CUSTODY_SEED_LEN, MAX_GUARDIANS,
+        MESSAGE_CONTEXT_VERSION,
     },
     Pda,
 };
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use std::mem::size_of;
 
 // Initializes the gateway PDA.
 pub fn initialize(ctx: Context<Initialize>, tss_address: [u8; 20], chain_id: u64) -> Result<()> {
@@ -21,6 +33,14 @@ pub fn initialize(ctx: Context<Initialize>, tss_address: [u8; 20], chain_id: u64
         authority: ctx.accounts.signer.key(),
         chain_id,
         deposit_paused: false,
+        outbound_paused: false,
+        circuit_breaker_bps: 0,
+        guardian_reset_delay: 0,
+        circuit_breaker_tripped_at: 0,
+        circuit_breaker_asset: Pubkey::default(),
+        guardians: [Pubkey::default(); MAX_GUARDIANS],
+        destination_allowlist_enabled: false,
+        execute_locked: false,
     };
 
     msg!(
@@ -33,9 +53,14 @@ pub fn initialize(ctx: Context<Initialize>, tss_address: [u8; 20], chain_id: u64
     Ok(())
 }
 
-// Updates the TSS address. Caller is authority stored in PDA.
+// Updates the TSS address. Caller is authority stored in PDA or a TSS updater.
 pub fn update_tss(ctx: Context<UpdateTss>, tss_address: [u8; 20]) -> Result<()> {
-    verify_authority(&ctx.accounts.signer.key(), &ctx.accounts.pda)?;
+    verify_role(
+        &ctx.accounts.signer.key(),
+        &ctx.accounts.pda,
+        &ctx.accounts.roles,
+        Role::TssUpdater,
+    )?;
     let pda = &mut ctx.accounts.pda;
     pda.tss_address = tss_address;
     pda.nonce = 0;
@@ -68,9 +93,25 @@ pub fn update_authority(
     Ok(())
 }
 
-// Pauses or unpauses deposits. Caller is authority stored in PDA.
+// Pauses or unpauses deposits.
+// Caller is authority stored in PDA, a pauser or a guardian to pause, authority or an unpauser to unpause.
 pub fn set_deposit_paused(ctx: Context<UpdatePaused>, deposit_paused: bool) -> Result<()> {
-    verify_authority(&ctx.accounts.signer.key(), &ctx.accounts.pda)?;
+    let signer = ctx.accounts.signer.key();
+
+    if deposit_paused && ctx.accounts.pda.is_guardian(&signer) {
+        emit!(GuardianPaused {
+            guardian: signer,
+            deposits: true,
+            outbounds: false,
+        });
+    } else {
+        let role = if deposit_paused {
+            Role::Pauser
+        } else {
+            Role::Unpauser
+        };
+        verify_role(&signer, &ctx.accounts.pda, &ctx.accounts.roles, role)?;
+    }
     let pda = &mut ctx.accounts.pda;
 
     pda.deposit_paused = deposit_paused;
@@ -79,6 +120,44 @@ pub fn set_deposit_paused(ctx: Context<UpdatePaused>, deposit_paused: bool) -> R
     Ok(())
 }
 
+// Pauses deposits and/or outbounds, unpausing is left to the authority.
+// Caller is a guardian, a pauser or authority stored in PDA.
+pub fn guardian_pause(ctx: Context<GuardianPause>, deposits: bool, outbounds: bool) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    if !ctx.accounts.pda.is_guardian(&signer) {
+        verify_role(
+            &signer,
+            &ctx.accounts.pda,
+            &ctx.accounts.roles,
+            Role::Pauser,
+        )?;
+    }
+    let pda = &mut ctx.accounts.pda;
+
+    if deposits {
+        pda.deposit_paused = true;
+    }
+    if outbounds {
+        pda.outbound_paused = true;
+        // A manual pause supersedes an automatic trip, guardians can no longer reset it
+        pda.circuit_breaker_tripped_at = 0;
+    }
+
+    msg!(
+        "Guardian pause: guardian = {}, deposits = {}, outbounds = {}",
+        signer,
+        deposits,
+        outbounds
+    );
+    emit!(GuardianPaused {
+        guardian: signer,
+        deposits,
+        outbounds,
+    });
+
+    Ok(())
+}
+
 // Whitelists a new SPL token. Caller is TSS
 pub fn whitelist_spl_mint(
     ctx: Context<Whitelist>,
@@ -100,6 +179,7 @@ pub fn whitelist_spl_mint(
         validate_message_hash(
             InstructionId::WhitelistSplToken,
             pda.chain_id,
+            &pda.key(),
             nonce,
             None, // Skip amount in hash calculation
             &[&whitelist_candidate.key().to_bytes()],
@@ -109,10 +189,19 @@ pub fn whitelist_spl_mint(
         // Verify TSS signature
         recover_and_verify_eth_address(pda, &message_hash, recovery_id, &signature)?;
     } else {
-        // If signature is zero, authority must sign the transaction
-        verify_authority(&authority.key(), &ctx.accounts.pda)?;
+        // If signature is zero, authority or a whitelist manager must sign the transaction
+        verify_role(
+            &authority.key(),
+            &ctx.accounts.pda,
+            &ctx.accounts.roles,
+            Role::WhitelistManager,
+        )?;
     }
 
+    let whitelist_entry = &mut ctx.accounts.whitelist_entry;
+    whitelist_entry.token_program = *whitelist_candidate.to_account_info().owner;
+    whitelist_entry.decimals = whitelist_candidate.decimals;
+
     msg!(
         "SPL token whitelisted: mint = {}, whitelist_entry = {}, authority = {}",
         whitelist_candidate.key(),
@@ -144,6 +233,7 @@ pub fn unwhitelist_spl_mint(
         validate_message_hash(
             InstructionId::UnwhitelistSplToken,
             pda.chain_id,
+            &pda.key(),
             nonce,
             None, // Skip amount in hash calculation
             &[&whitelist_candidate.key().to_bytes()],
@@ -153,8 +243,13 @@ pub fn unwhitelist_spl_mint(
         // Verify TSS signature
         recover_and_verify_eth_address(pda, &message_hash, recovery_id, &signature)?;
     } else {
-        // If signature is zero, authority must sign the transaction
-        verify_authority(&authority.key(), &ctx.accounts.pda)?;
+        // If signature is zero, authority or a whitelist manager must sign the transaction
+        verify_role(
+            &authority.key(),
+            &ctx.accounts.pda,
+            &ctx.accounts.roles,
+            Role::WhitelistManager,
+        )?;
     }
 
     msg!(
@@ -167,9 +262,145 @@ pub fn unwhitelist_spl_mint(
     Ok(())
 }
 
-// Resets the PDA authority. Caller is authority stored in PDA.
+// Updates the policy of a whitelisted SPL token. Caller is authority or TSS.
+pub fn update_whitelist_entry(
+    ctx: Context<UpdateWhitelistEntry>,
+    config: WhitelistEntryConfig,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+) -> Result<()> {
+    let pda = &mut ctx.accounts.pda;
+    let whitelist_candidate = &ctx.accounts.whitelist_candidate;
+    let authority = &ctx.accounts.authority;
+
+    // If signature is not zero, verify the signature is valid and signed by TSS
+    if signature != [0u8; 64] {
+        // Verify and update nonce
+        verify_and_update_nonce(pda, nonce)?;
+
+        // Validate message hash
+        validate_message_hash(
+            InstructionId::UpdateWhitelistEntry,
+            pda.chain_id,
+            &pda.key(),
+            nonce,
+            None, // Skip amount in hash calculation
+            &[
+                &whitelist_candidate.key().to_bytes(),
+                &config.to_message_bytes(),
+            ],
+            &message_hash,
+        )?;
+
+        // Verify TSS signature
+        recover_and_verify_eth_address(pda, &message_hash, recovery_id, &signature)?;
+    } else {
+        // If signature is zero, authority or a whitelist manager must sign the transaction
+        verify_role(
+            &authority.key(),
+            &ctx.accounts.pda,
+            &ctx.accounts.roles,
+            Role::WhitelistManager,
+        )?;
+        // Changing the fee additionally requires the fee manager role
+        if config.fee_override != ctx.accounts.whitelist_entry.fee_override {
+            verify_role(
+                &authority.key(),
+                &ctx.accounts.pda,
+                &ctx.accounts.roles,
+                Role::FeeManager,
+            )?;
+        }
+    }
+
+    let whitelist_entry = &mut ctx.accounts.whitelist_entry;
+    whitelist_entry.token_program = *whitelist_candidate.to_account_info().owner;
+    whitelist_entry.decimals = whitelist_candidate.decimals;
+    whitelist_entry.zrc20 = config.zrc20;
+    whitelist_entry.min_deposit_amount = config.min_deposit_amount;
+    whitelist_entry.deposit_paused = config.deposit_paused;
+    whitelist_entry.withdraw_paused = config.withdraw_paused;
+    whitelist_entry.fee_override = config.fee_override;
+
+    msg!(
+        "Whitelist entry updated: mint = {}, whitelist_entry = {}, config = {:?}",
+        whitelist_candidate.key(),
+        whitelist_entry.key(),
+        config
+    );
+
+    Ok(())
+}
+
+// Reallocates a legacy empty whitelist entry to the current layout.
+// Caller is authority stored in PDA or a whitelist manager.
+pub fn migrate_whitelist_entry(ctx: Context<MigrateWhitelistEntry>) -> Result<()> {
+    verify_role(
+        &ctx.accounts.authority.key(),
+        &ctx.accounts.pda,
+        &ctx.accounts.roles,
+        Role::WhitelistManager,
+    )?;
+
+    let entry_info = ctx.accounts.whitelist_entry.to_account_info();
+    let whitelist_candidate = &ctx.accounts.whitelist_candidate;
+
+    require_keys_eq!(*entry_info.owner, crate::ID, Errors::MintNotWhitelisted);
+    require!(
+        entry_info.try_borrow_data()?[..] == *WhitelistEntry::DISCRIMINATOR,
+        Errors::WhitelistEntryAlreadyMigrated
+    );
+
+    // Top up rent for the new size, paid by the authority
+    let new_len = size_of::<WhitelistEntry>() + 8;
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(entry_info.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: entry_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    entry_info.realloc(new_len, true)?;
+
+    let entry = WhitelistEntry {
+        token_program: *whitelist_candidate.to_account_info().owner,
+        decimals: whitelist_candidate.decimals,
+        zrc20: [0u8; 20],
+        min_deposit_amount: 0,
+        deposit_paused: false,
+        withdraw_paused: false,
+        fee_override: None,
+    };
+    entry.try_serialize(&mut &mut entry_info.try_borrow_mut_data()?[..])?;
+
+    msg!(
+        "Whitelist entry migrated: mint = {}, whitelist_entry = {}, size = {}",
+        whitelist_candidate.key(),
+        entry_info.key(),
+        new_len
+    );
+
+    Ok(())
+}
+
+// Resets the PDA nonce. Caller is authority stored in PDA or a nonce resetter.
 pub fn reset_nonce(ctx: Context<ResetNonce>, new_nonce: u64) -> Result<()> {
-    verify_authority(&ctx.accounts.signer.key(), &ctx.accounts.pda)?;
+    verify_role(
+        &ctx.accounts.signer.key(),
+        &ctx.accounts.pda,
+        &ctx.accounts.roles,
+        Role::NonceResetter,
+    )?;
     let pda = &mut ctx.accounts.pda;
     pda.nonce = new_nonce;
 
@@ -177,3 +408,309 @@ pub fn reset_nonce(ctx: Context<ResetNonce>, new_nonce: u64) -> Result<()> {
 
     Ok(())
 }
+
+// Configures the rate limit of an asset. Caller is authority stored in PDA.
+pub fn set_rate_limit(
+    ctx: Context<SetRateLimit>,
+    asset: Pubkey,
+    config: RateLimitConfig,
+) -> Result<()> {
+    verify_authority(&ctx.accounts.signer.key(), &ctx.accounts.pda)?;
+    require!(
+        config.window_duration > 0
+            && config.warning_threshold_bps <= 10_000
+            && config.settlement_delay >= 0,
+        Errors::InvalidRateLimitConfig
+    );
+
+    let rate_limit = &mut ctx.accounts.rate_limit;
+    if rate_limit.window_duration == 0 {
+        // Newly created rate limit starts its first window now
+        rate_limit.window_start = Clock::get()?.unix_timestamp;
+    }
+    rate_limit.window_duration = config.window_duration;
+    rate_limit.outbound_limit = config.outbound_limit;
+    rate_limit.inbound_limit = config.inbound_limit;
+    rate_limit.warning_threshold_bps = config.warning_threshold_bps;
+    rate_limit.delay_threshold = config.delay_threshold;
+    rate_limit.settlement_delay = config.settlement_delay;
+
+    msg!("Rate limit set: asset = {}, config = {:?}", asset, config);
+
+    Ok(())
+}
+
+// Configures the circuit breaker. Caller is authority stored in PDA.
+pub fn set_circuit_breaker(
+    ctx: Context<UpdateCircuitBreaker>,
+    circuit_breaker_bps: u16,
+    guardian_reset_delay: i64,
+) -> Result<()> {
+    verify_authority(&ctx.accounts.signer.key(), &ctx.accounts.pda)?;
+    require!(
+        circuit_breaker_bps <= 10_000 && guardian_reset_delay >= 0,
+        Errors::InvalidCircuitBreakerConfig
+    );
+
+    let pda = &mut ctx.accounts.pda;
+    pda.circuit_breaker_bps = circuit_breaker_bps;
+    pda.guardian_reset_delay = guardian_reset_delay;
+
+    msg!(
+        "Circuit breaker set: circuit_breaker_bps = {}, guardian_reset_delay = {}",
+        circuit_breaker_bps,
+        guardian_reset_delay
+    );
+
+    Ok(())
+}
+
+// Replaces the guardians. Caller is authority stored in PDA.
+pub fn update_guardians(ctx: Context<UpdateGuardians>, guardians: Vec<Pubkey>) -> Result<()> {
+    verify_authority(&ctx.accounts.signer.key(), &ctx.accounts.pda)?;
+    require!(guardians.len() <= MAX_GUARDIANS, Errors::TooManyGuardians);
+
+    let pda = &mut ctx.accounts.pda;
+    pda.guardians = [Pubkey::default(); MAX_GUARDIANS];
+    pda.guardians[..guardians.len()].copy_from_slice(&guardians);
+
+    msg!("Guardians updated: {:?}", guardians);
+
+    Ok(())
+}
+
+// Resumes outbounds after the circuit breaker tripped.
+// Caller is authority stored in PDA, an unpauser, or a guardian once the reset delay has elapsed.
+pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let is_unpauser = has_role(
+        &signer,
+        &ctx.accounts.pda,
+        &ctx.accounts.roles,
+        Role::Unpauser,
+    )?;
+    let pda = &mut ctx.accounts.pda;
+
+    if !is_unpauser {
+        require!(pda.is_guardian(&signer), Errors::SignerIsNotAuthority);
+        require!(
+            pda.circuit_breaker_tripped_at != 0,
+            Errors::CircuitBreakerNotTripped
+        );
+        require!(
+            Clock::get()?.unix_timestamp
+                >= pda
+                    .circuit_breaker_tripped_at
+                    .saturating_add(pda.guardian_reset_delay),
+            Errors::GuardianResetDelayNotElapsed
+        );
+    }
+
+    pda.outbound_paused = false;
+    pda.circuit_breaker_tripped_at = 0;
+    pda.circuit_breaker_asset = Pubkey::default();
+
+    msg!("Circuit breaker reset: signer = {}", signer);
+    emit!(CircuitBreakerReset { signer });
+
+    Ok(())
+}
+
+// Reallocates the gateway PDA to the current layout, new fields are zero-initialized.
+// Caller is authority stored in PDA.
+pub fn migrate_pda(ctx: Context<MigratePda>) -> Result<()> {
+    let pda_info = ctx.accounts.pda.to_account_info();
+    let new_len = size_of::<Pda>() + 8;
+
+    // Legacy layout is discriminator, nonce, tss_address, authority, ...
+    let authority = {
+        let data = pda_info.try_borrow_data()?;
+        require!(data.len() < new_len, Errors::PdaAlreadyMigrated);
+        Pubkey::try_from(&data[36..68]).map_err(|_| Errors::SignerIsNotAuthority)?
+    };
+    require_keys_eq!(
+        ctx.accounts.signer.key(),
+        authority,
+        Errors::SignerIsNotAuthority
+    );
+
+    // Top up rent for the new size, paid by the authority
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(pda_info.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: pda_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    pda_info.realloc(new_len, true)?;
+
+    msg!("Gateway PDA migrated: size = {}", new_len);
+
+    Ok(())
+}
+
+// Grants a role to a key. Caller is authority stored in PDA.
+pub fn grant_role(ctx: Context<UpdateRoles>, member: Pubkey, role: Role) -> Result<()> {
+    verify_authority(&ctx.accounts.signer.key(), &ctx.accounts.pda)?;
+    ctx.accounts.roles.grant(member, role)?;
+
+    msg!("Role granted: member = {}, role = {:?}", member, role);
+    emit!(RoleGranted { member, role });
+
+    Ok(())
+}
+
+// Revokes a role from a key. Caller is authority stored in PDA.
+pub fn revoke_role(ctx: Context<UpdateRoles>, member: Pubkey, role: Role) -> Result<()> {
+    verify_authority(&ctx.accounts.signer.key(), &ctx.accounts.pda)?;
+    ctx.accounts.roles.revoke(member, role);
+
+    msg!("Role revoked: member = {}, role = {:?}", member, role);
+    emit!(RoleRevoked { member, role });
+
+    Ok(())
+}
+
+// Sets the registry status of a destination program. Caller is TSS or authority stored in PDA.
+pub fn set_connected_program(
+    ctx: Context<SetConnectedProgram>,
+    program_id: Pubkey,
+    status: ConnectedProgramStatus,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+) -> Result<()> {
+    let pda = &mut ctx.accounts.pda;
+    let authority = &ctx.accounts.authority;
+
+    // If signature is not zero, verify the signature is valid and signed by TSS
+    if signature != [0u8; 64] {
+        // Verify and update nonce
+        verify_and_update_nonce(pda, nonce)?;
+
+        // Validate message hash
+        validate_message_hash(
+            InstructionId::SetConnectedProgram,
+            pda.chain_id,
+            &pda.key(),
+            nonce,
+            None, // Skip amount in hash calculation
+            &[&program_id.to_bytes(), &[status as u8]],
+            &message_hash,
+        )?;
+
+        // Verify TSS signature
+        recover_and_verify_eth_address(pda, &message_hash, recovery_id, &signature)?;
+    } else {
+        // If signature is zero, authority must sign the transaction
+        verify_authority(&authority.key(), &ctx.accounts.pda)?;
+    }
+
+    let connected_program = &mut ctx.accounts.connected_program;
+    connected_program.program_id = program_id;
+    connected_program.status = status;
+
+    msg!(
+        "Connected program set: program = {}, status = {:?}, authority = {}",
+        program_id,
+        status,
+        authority.key()
+    );
+
+    Ok(())
+}
+
+// Declares the custody PDA seed and hook prefixes of a destination program.
+// Caller is TSS or authority stored in PDA.
+pub fn configure_connected_program(
+    ctx: Context<ConfigureConnectedProgram>,
+    program_id: Pubkey,
+    config: ConnectedProgramConfig,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+) -> Result<()> {
+    let pda = &mut ctx.accounts.pda;
+    let authority = &ctx.accounts.authority;
+
+    require!(
+        config.custody_seed.len() <= MAX_CUSTODY_SEED_LEN
+            && config.discriminator_len <= 8
+            && config.context_version <= MESSAGE_CONTEXT_VERSION,
+        Errors::InvalidConnectedProgramConfig
+    );
+
+    // If signature is not zero, verify the signature is valid and signed by TSS
+    if signature != [0u8; 64] {
+        // Verify and update nonce
+        verify_and_update_nonce(pda, nonce)?;
+
+        // Validate message hash
+        validate_message_hash(
+            InstructionId::ConfigureConnectedProgram,
+            pda.chain_id,
+            &pda.key(),
+            nonce,
+            None, // Skip amount in hash calculation
+            &[&program_id.to_bytes(), &config.to_message_bytes()],
+            &message_hash,
+        )?;
+
+        // Verify TSS signature
+        recover_and_verify_eth_address(pda, &message_hash, recovery_id, &signature)?;
+    } else {
+        // If signature is zero, authority must sign the transaction
+        verify_authority(&authority.key(), &ctx.accounts.pda)?;
+    }
+
+    let connected_program = &mut ctx.accounts.connected_program;
+    connected_program.program_id = program_id;
+    connected_program.custody_seed = [0u8; MAX_CUSTODY_SEED_LEN];
+    connected_program.custody_seed[..config.custody_seed.len()]
+        .copy_from_slice(&config.custody_seed);
+    connected_program.custody_seed_len = config.custody_seed.len() as u8;
+    connected_program.custody_bump = if config.custody_seed.is_empty() {
+        0
+    } else {
+        Pubkey::find_program_address(&[&config.custody_seed], &program_id).1
+    };
+    connected_program.on_call_discriminator = config.on_call_discriminator;
+    connected_program.on_revert_discriminator = config.on_revert_discriminator;
+    connected_program.discriminator_len = config.discriminator_len;
+    connected_program.context_version = config.context_version;
+    connected_program.on_abort_discriminator = config.on_abort_discriminator;
+
+    msg!(
+        "Connected program configured: program = {}, config = {:?}, authority = {}",
+        program_id,
+        config,
+        authority.key()
+    );
+
+    Ok(())
+}
+
+// Enables or disables restricting executions to allowed destination programs.
+// Caller is authority stored in PDA.
+pub fn set_destination_allowlist(
+    ctx: Context<UpdateDestinationAllowlist>,
+    enabled: bool,
+) -> Result<()> {
+    verify_authority(&ctx.accounts.signer.key(), &ctx.accounts.pda)?;
+    let pda = &mut ctx.accounts.pda;
+    pda.destination_allowlist_enabled = enabled;
+
+    msg!("Set destination allowlist enabled: {:?}", enabled);
+
+    Ok(())
+}
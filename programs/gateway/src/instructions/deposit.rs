@@ -1,14 +1,23 @@
 use crate::{
-    contexts::{Call, Deposit, DepositSplToken},
+    contexts::{
+        Call, CloseDepositBuffer, Deposit, DepositFromBuffer, DepositFromSource, DepositPrefunded,
+        DepositSplToken, DepositSplTokenWithAuthority, DepositWsol, StageDepositMessage,
+    },
     errors::Errors,
-    state::RevertOptions,
-    utils::verify_payload_size,
+    events::DepositAndCallFromBuffer,
+    state::{FlowDirection, RevertOptions},
+    utils::{consume_rate_limit, verify_payload_size, MAX_MESSAGE_BUFFER_SIZE},
 };
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hash;
+use anchor_lang::solana_program::program_pack::Pack;
 use anchor_lang::system_program;
 use anchor_spl::associated_token::get_associated_token_address;
-use anchor_spl::token::transfer;
+use anchor_spl::token::{
+    close_account, initialize_account3, spl_token, spl_token::native_mint, transfer, CloseAccount,
+    InitializeAccount3,
+};
 
 // Deposits SOL into the program and credits the `receiver` on ZetaChain zEVM.
 pub fn handle_sol(
@@ -17,22 +26,130 @@ pub fn handle_sol(
     receiver: [u8; 20],
     revert_options: Option<RevertOptions>,
     deposit_fee: u64,
+) -> Result<()> {
+    let source = ctx.accounts.signer.to_account_info();
+
+    deposit_sol(
+        ctx,
+        source,
+        &[],
+        amount,
+        receiver,
+        revert_options,
+        deposit_fee,
+    )
+}
+
+// Deposits SOL taken from a source account other than the signer, the signer pays the fee.
+pub fn handle_sol_from_source<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositFromSource<'info>>,
+    amount: u64,
+    receiver: [u8; 20],
+    revert_options: Option<RevertOptions>,
+    deposit_fee: u64,
+) -> Result<()> {
+    let source = ctx.accounts.source.to_account_info();
+
+    let deposit_ctx = Context::new(
+        ctx.program_id,
+        &mut ctx.accounts.deposit,
+        ctx.remaining_accounts,
+        ctx.bumps.deposit,
+    );
+    deposit_sol(
+        deposit_ctx,
+        source,
+        &[],
+        amount,
+        receiver,
+        revert_options,
+        deposit_fee,
+    )
+}
+
+// Deposits the lamports credited to the deposit escrow of the source, the signer pays the fee.
+// Programs owning accounts with data debit them directly into the escrow before calling the gateway.
+pub fn handle_sol_prefunded<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositPrefunded<'info>>,
+    receiver: [u8; 20],
+    revert_options: Option<RevertOptions>,
+    deposit_fee: u64,
+) -> Result<()> {
+    let escrow = ctx.accounts.escrow.to_account_info();
+    let amount = escrow.lamports();
+    require!(amount > 0, Errors::EmptyDepositEscrow);
+
+    let source_key = ctx.accounts.source.key();
+    let escrow_seeds: &[&[&[u8]]] =
+        &[&[b"deposit_escrow", source_key.as_ref(), &[ctx.bumps.escrow]]];
+    let deposit_ctx = Context::new(
+        ctx.program_id,
+        &mut ctx.accounts.deposit,
+        ctx.remaining_accounts,
+        ctx.bumps.deposit,
+    );
+    deposit_sol(
+        deposit_ctx,
+        escrow,
+        escrow_seeds,
+        amount,
+        receiver,
+        revert_options,
+        deposit_fee,
+    )
+}
+
+// Common implementation for SOL deposits.
+// The amount is taken from `source`, signed with `source_seeds` if it is a gateway PDA, and the fee from the signer.
+fn deposit_sol<'info>(
+    ctx: Context<'_, '_, '_, '_, Deposit<'info>>,
+    source: AccountInfo<'info>,
+    source_seeds: &[&[&[u8]]],
+    amount: u64,
+    receiver: [u8; 20],
+    revert_options: Option<RevertOptions>,
+    deposit_fee: u64,
 ) -> Result<()> {
     verify_payload_size(None, &revert_options)?;
 
     let pda = &mut ctx.accounts.pda;
     require!(!pda.deposit_paused, Errors::DepositPaused);
+    require!(!pda.execute_locked, Errors::ExecuteInProgress);
     require!(receiver != [0u8; 20], Errors::EmptyReceiver);
 
-    let amount_with_fees = amount + deposit_fee;
-    let cpi_context = CpiContext::new(
-        ctx.accounts.system_program.to_account_info(),
-        system_program::Transfer {
-            from: ctx.accounts.signer.to_account_info().clone(),
-            to: ctx.accounts.pda.to_account_info().clone(),
-        },
-    );
-    system_program::transfer(cpi_context, amount_with_fees)?;
+    consume_rate_limit(
+        &ctx.accounts.rate_limit,
+        native_mint::ID,
+        FlowDirection::Inbound,
+        amount,
+    )?;
+
+    let signer = ctx.accounts.signer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let to = ctx.accounts.pda.to_account_info();
+    if source.key() == signer.key() {
+        let cpi_context = CpiContext::new(
+            system_program,
+            system_program::Transfer { from: signer, to },
+        );
+        system_program::transfer(cpi_context, amount + deposit_fee)?;
+    } else {
+        let fee_context = CpiContext::new(
+            system_program.clone(),
+            system_program::Transfer {
+                from: signer,
+                to: to.clone(),
+            },
+        );
+        system_program::transfer(fee_context, deposit_fee)?;
+
+        let cpi_context = CpiContext::new_with_signer(
+            system_program,
+            system_program::Transfer { from: source, to },
+            source_seeds,
+        );
+        system_program::transfer(cpi_context, amount)?;
+    }
 
     msg!(
         "Deposit executed: amount = {}, fee = {}, receiver = {:?}, pda = {}, revert options = {:?}",
@@ -64,6 +181,226 @@ pub fn handle_sol_with_call(
     Ok(())
 }
 
+// Writes a chunk of a deposit message into the deposit buffer of the signer.
+// Buffer is created by the first chunk with the length of the complete message.
+pub fn stage_message(
+    ctx: Context<StageDepositMessage>,
+    total_len: u32,
+    offset: u32,
+    chunk: Vec<u8>,
+) -> Result<()> {
+    let deposit_buffer = &mut ctx.accounts.deposit_buffer;
+    let signer = ctx.accounts.signer.key();
+
+    require!(
+        total_len <= MAX_MESSAGE_BUFFER_SIZE,
+        Errors::InvalidMessageBuffer
+    );
+
+    if deposit_buffer.owner == Pubkey::default() {
+        deposit_buffer.owner = signer;
+        deposit_buffer.data = vec![0u8; total_len as usize];
+    }
+    require!(
+        deposit_buffer.data.len() == total_len as usize,
+        Errors::InvalidMessageBuffer
+    );
+
+    let start = offset as usize;
+    let target = deposit_buffer
+        .data
+        .get_mut(start..start + chunk.len())
+        .ok_or(Errors::InvalidMessageBuffer)?;
+    target.copy_from_slice(&chunk);
+
+    msg!(
+        "Deposit message staged: offset = {}, len = {}, signer = {}",
+        offset,
+        chunk.len(),
+        signer
+    );
+
+    Ok(())
+}
+
+// Closes a deposit buffer that will not be deposited, refunding rent to the signer.
+pub fn close_buffer(_ctx: Context<CloseDepositBuffer>) -> Result<()> {
+    msg!("Deposit buffer closed");
+
+    Ok(())
+}
+
+// Deposits SOL and calls a contract on ZetaChain zEVM with the message staged in the deposit buffer.
+// The buffer is closed afterwards, refunding rent to the signer.
+pub fn handle_sol_with_buffer<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositFromBuffer<'info>>,
+    amount: u64,
+    receiver: [u8; 20],
+    revert_options: Option<RevertOptions>,
+    deposit_fee: u64,
+) -> Result<()> {
+    let message = &ctx.accounts.deposit_buffer.data;
+    let message_hash = hash(message).to_bytes();
+    let message_len = message.len() as u32;
+
+    let deposit_ctx = Context::new(
+        ctx.program_id,
+        &mut ctx.accounts.deposit,
+        ctx.remaining_accounts,
+        ctx.bumps.deposit,
+    );
+    handle_sol(deposit_ctx, amount, receiver, revert_options, deposit_fee)?;
+
+    let signer = ctx.accounts.deposit.signer.to_account_info();
+    ctx.accounts.deposit_buffer.close(signer)?;
+
+    msg!(
+        "Deposit and call executed with buffer = {}, message hash = {:?}, message len = {}",
+        ctx.accounts.deposit_buffer.key(),
+        message_hash,
+        message_len
+    );
+    emit!(DepositAndCallFromBuffer {
+        sender: ctx.accounts.deposit.signer.key(),
+        receiver,
+        amount,
+        buffer: ctx.accounts.deposit_buffer.key(),
+        message_hash,
+        message_len,
+    });
+
+    Ok(())
+}
+
+// Deposits wrapped SOL and credits the `receiver` on ZetaChain zEVM with SOL.
+// Only the deposited amount is unwrapped, into the signer, and then deposited as SOL.
+pub fn handle_wsol<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositWsol<'info>>,
+    amount: u64,
+    receiver: [u8; 20],
+    revert_options: Option<RevertOptions>,
+    deposit_fee: u64,
+) -> Result<()> {
+    unwrap_wsol(&ctx, amount)?;
+
+    let deposit_ctx = Context::new(
+        ctx.program_id,
+        &mut ctx.accounts.deposit,
+        ctx.remaining_accounts,
+        ctx.bumps.deposit,
+    );
+    handle_sol(deposit_ctx, amount, receiver, revert_options, deposit_fee)
+}
+
+// Deposits wrapped SOL and calls a contract on ZetaChain zEVM.
+pub fn handle_wsol_with_call<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositWsol<'info>>,
+    amount: u64,
+    receiver: [u8; 20],
+    message: Vec<u8>,
+    revert_options: Option<RevertOptions>,
+    deposit_fee: u64,
+) -> Result<()> {
+    verify_payload_size(Some(&message), &revert_options)?;
+
+    handle_wsol(ctx, amount, receiver, revert_options, deposit_fee)?;
+
+    msg!("Deposit and call executed with message = {:?}", message);
+
+    Ok(())
+}
+
+// Moves the deposited wSOL into a temporary native account of the gateway and closes it into the signer.
+// The gateway wSOL custody is left untouched, the account rent is returned to the signer.
+fn unwrap_wsol(ctx: &Context<DepositWsol>, amount: u64) -> Result<()> {
+    let signer = ctx.accounts.deposit.signer.to_account_info();
+    let pda = ctx.accounts.deposit.pda.to_account_info();
+    let system_program = ctx.accounts.deposit.system_program.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let unwrap_account = ctx.accounts.unwrap_account.to_account_info();
+
+    let signer_key = signer.key();
+    let unwrap_seeds: &[&[&[u8]]] = &[&[
+        b"wsol_unwrap",
+        signer_key.as_ref(),
+        &[ctx.bumps.unwrap_account],
+    ]];
+    let space = spl_token::state::Account::LEN;
+
+    // Fund, allocate and assign separately as the address may already hold lamports
+    let rent_due = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(unwrap_account.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: signer.clone(),
+                    to: unwrap_account.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: unwrap_account.clone(),
+            },
+            unwrap_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program,
+            system_program::Assign {
+                account_to_assign: unwrap_account.clone(),
+            },
+            unwrap_seeds,
+        ),
+        &token_program.key(),
+    )?;
+
+    initialize_account3(CpiContext::new(
+        token_program.clone(),
+        InitializeAccount3 {
+            account: unwrap_account.clone(),
+            mint: ctx.accounts.mint_account.to_account_info(),
+            authority: pda.clone(),
+        },
+    ))?;
+
+    transfer(
+        CpiContext::new(
+            token_program.clone(),
+            anchor_spl::token::Transfer {
+                from: ctx.accounts.from.to_account_info(),
+                to: unwrap_account.clone(),
+                authority: signer.clone(),
+            },
+        ),
+        amount,
+    )?;
+
+    let pda_seeds: &[&[&[u8]]] = &[&[b"meta", &[ctx.bumps.deposit.pda]]];
+    close_account(CpiContext::new_with_signer(
+        token_program,
+        CloseAccount {
+            account: unwrap_account,
+            destination: signer,
+            authority: pda,
+        },
+        pda_seeds,
+    ))?;
+
+    msg!("Unwrapped wSOL deposit: amount = {}", amount);
+
+    Ok(())
+}
+
 // Deposits SPL tokens and credits the `receiver` on ZetaChain zEVM.
 pub fn handle_spl(
     ctx: Context<DepositSplToken>,
@@ -71,6 +408,54 @@ pub fn handle_spl(
     receiver: [u8; 20],
     revert_options: Option<RevertOptions>,
     deposit_fee: u64,
+) -> Result<()> {
+    let authority = ctx.accounts.signer.to_account_info();
+
+    deposit_spl(
+        ctx,
+        authority,
+        amount,
+        receiver,
+        revert_options,
+        deposit_fee,
+    )
+}
+
+// Deposits SPL tokens moved by the owner or an approved delegate of the source account,
+// the signer pays the fee.
+pub fn handle_spl_with_authority<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositSplTokenWithAuthority<'info>>,
+    amount: u64,
+    receiver: [u8; 20],
+    revert_options: Option<RevertOptions>,
+    deposit_fee: u64,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.to_account_info();
+
+    let deposit_ctx = Context::new(
+        ctx.program_id,
+        &mut ctx.accounts.deposit,
+        ctx.remaining_accounts,
+        ctx.bumps.deposit,
+    );
+    deposit_spl(
+        deposit_ctx,
+        authority,
+        amount,
+        receiver,
+        revert_options,
+        deposit_fee,
+    )
+}
+
+// Common implementation for SPL token deposits, tokens are moved by `authority`.
+fn deposit_spl<'info>(
+    ctx: Context<'_, '_, '_, '_, DepositSplToken<'info>>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+    receiver: [u8; 20],
+    revert_options: Option<RevertOptions>,
+    deposit_fee: u64,
 ) -> Result<()> {
     verify_payload_size(None, &revert_options)?;
     let token = &ctx.accounts.token_program;
@@ -78,8 +463,25 @@ pub fn handle_spl(
 
     let pda = &mut ctx.accounts.pda;
     require!(!pda.deposit_paused, Errors::DepositPaused);
+    require!(!pda.execute_locked, Errors::ExecuteInProgress);
     require!(receiver != [0u8; 20], Errors::EmptyReceiver);
 
+    // Apply the per-mint policy of the whitelist entry
+    let entry = &ctx.accounts.whitelist_entry;
+    require!(!entry.deposit_paused, Errors::DepositPaused);
+    require!(
+        amount >= entry.min_deposit_amount,
+        Errors::DepositBelowMinimum
+    );
+    let deposit_fee = entry.fee_override.unwrap_or(deposit_fee);
+
+    consume_rate_limit(
+        &ctx.accounts.rate_limit,
+        ctx.accounts.mint_account.key(),
+        FlowDirection::Inbound,
+        amount,
+    )?;
+
     let cpi_context = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
         system_program::Transfer {
@@ -100,7 +502,7 @@ pub fn handle_spl(
         anchor_spl::token::Transfer {
             from: ctx.accounts.from.to_account_info(),
             to: ctx.accounts.to.to_account_info(),
-            authority: ctx.accounts.signer.to_account_info(),
+            authority,
         },
     );
     transfer(xfer_ctx, amount)?;
@@ -138,11 +540,12 @@ pub fn handle_spl_with_call(
 
 // Calls a contract on ZetaChain zEVM.
 pub fn handle_call(
-    _ctx: Context<Call>,
+    ctx: Context<Call>,
     receiver: [u8; 20],
     message: Vec<u8>,
     revert_options: Option<RevertOptions>,
 ) -> Result<()> {
+    require!(!ctx.accounts.pda.execute_locked, Errors::ExecuteInProgress);
     require!(receiver != [0u8; 20], Errors::EmptyReceiver);
     verify_payload_size(Some(&message), &revert_options)?;
 
@@ -1,11 +1,30 @@
 use crate::{
-    contexts::{Execute, ExecuteSPLToken, IncrementNonce},
-    state::CallableInstruction,
-    state::InstructionId,
-    utils::{prepare_account_metas, validate_message, verify_ata_match},
+    contexts::{
+        CloseMessageBuffer, Execute, ExecuteArbitraryCall, ExecuteAsAccount, ExecuteFromBuffer,
+        ExecuteMulti, ExecuteSPLToken, ExecuteWsol, IncrementNonce, StageMessage,
+    },
+    errors::Errors,
+    state::{
+        AbortContext, ArbitraryCallAccount, CallableInstruction, ExecuteStep, InstructionId,
+        MessageContext,
+    },
+    utils::{
+        arbitrary_call_message_bytes, create_ata_if_needed, defer_outbound, find_connected_program,
+        forward_return_data, hash_remaining_accounts, load_connected_program,
+        prepare_account_metas, prepare_arbitrary_call_metas, record_outbound, set_execute_lock,
+        validate_message, validate_outbound_message, verify_ata_match, verify_destination_allowed,
+        verify_destination_pda, verify_withdraw_allowed, wrap_sol, DEFAULT_GAS_COST,
+        MAX_EXECUTE_STEPS, MAX_MESSAGE_BUFFER_SIZE,
+    },
 };
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{instruction::Instruction, program::invoke};
+use anchor_lang::solana_program::{
+    instruction::Instruction,
+    keccak::hash,
+    program::{invoke, invoke_signed},
+};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{spl_token::native_mint, TokenAccount};
 
 // Increments nonce, used by TSS in case outbound fails.
 pub fn increment_nonce(
@@ -16,6 +35,7 @@ pub fn increment_nonce(
     message_hash: [u8; 32],
     nonce: u64,
     failure_reason: String,
+    expiry: Option<i64>,
 ) -> Result<()> {
     let pda = &mut ctx.accounts.pda;
 
@@ -29,6 +49,7 @@ pub fn increment_nonce(
         &message_hash,
         &signature,
         recovery_id,
+        expiry,
     )?;
 
     msg!("Failure reason: {}", failure_reason);
@@ -40,53 +61,118 @@ pub fn increment_nonce(
 fn handle_sol_common(
     ctx: Context<Execute>,
     amount: u64,
-    data: Vec<u8>,
+    message_data: &[&[u8]],
     signature: [u8; 64],
     recovery_id: u8,
     message_hash: [u8; 32],
     nonce: u64,
-    sender: Vec<u8>,
+    expiry: Option<i64>,
     instruction_id: InstructionId,
-    instruction_data: Vec<u8>,
+    instruction: CallableInstruction,
 ) -> Result<()> {
     let pda = &mut ctx.accounts.pda;
 
-    // 1. Validate message
-    validate_message(
+    // 1. Validate message, or release the outbound queued for it
+    let destination_program = ctx.accounts.destination_program.key().to_bytes();
+    let accounts_hash = hash_remaining_accounts(ctx.remaining_accounts);
+    let mut message_parts: Vec<&[u8]> = vec![&destination_program];
+    message_parts.extend_from_slice(message_data);
+    message_parts.push(&accounts_hash);
+    let released = validate_outbound_message(
         pda,
+        ctx.accounts.pending_withdrawal.as_deref(),
+        &ctx.accounts.signer,
         instruction_id,
         nonce,
         amount,
-        &[
-            &ctx.accounts.destination_program.key().to_bytes(),
-            &sender,
-            &data,
-        ],
+        &message_parts,
         &message_hash,
         &signature,
         recovery_id,
+        expiry,
     )?;
 
-    let account_metas = prepare_account_metas(ctx.remaining_accounts, &ctx.accounts.signer, pda)?;
+    // 2. Verify destination program and its PDA against the registry
+    let connected_program = load_connected_program(&ctx.accounts.connected_program)?;
+    verify_destination_allowed(pda, connected_program.as_ref())?;
+    verify_destination_pda(
+        &ctx.accounts.destination_program.key(),
+        &ctx.accounts.destination_program_pda.key(),
+        connected_program.as_ref(),
+    )?;
+
+    // 3. Record outbound against rate limit and circuit breaker, queue it above the delay threshold
+    if !released {
+        let custody = pda.get_lamports();
+        if !record_outbound(
+            pda,
+            &ctx.accounts.rate_limit,
+            native_mint::ID,
+            amount,
+            custody,
+        )? {
+            return Ok(());
+        }
+
+        if defer_outbound(
+            &ctx.accounts.rate_limit,
+            ctx.accounts.pending_withdrawal.as_deref(),
+            &ctx.accounts.signer,
+            &ctx.accounts.system_program,
+            nonce,
+            message_hash,
+            native_mint::ID,
+            ctx.accounts.destination_program_pda.key(),
+            amount,
+            native_mint::DECIMALS,
+        )? {
+            return Ok(());
+        }
+    }
+
+    let account_metas = prepare_account_metas(
+        ctx.remaining_accounts,
+        &ctx.accounts.signer,
+        pda,
+        &ctx.accounts.caller_authority.key(),
+        false,
+        &[],
+    )?;
 
     let ix = Instruction {
         program_id: ctx.accounts.destination_program.key(),
         accounts: account_metas,
-        data: instruction_data,
+        data: instruction
+            .with_context(
+                MessageContext::new(native_mint::ID, native_mint::DECIMALS, nonce, pda.chain_id),
+                connected_program.as_ref(),
+            )
+            .pack_for(connected_program.as_ref()),
     };
 
-    // 2. Transfer SOL to destination program PDA
+    // 4. Transfer SOL to destination program PDA
     pda.sub_lamports(amount)?;
     ctx.accounts.destination_program_pda.add_lamports(amount)?;
 
-    // 3. Invoke destination program's function
-    invoke(&ix, ctx.remaining_accounts)?;
+    // 5. Invoke destination program's function
+    let caller_seeds: &[&[&[u8]]] = &[&[
+        b"caller_authority",
+        ctx.accounts.destination_program.key.as_ref(),
+        &[ctx.bumps.caller_authority],
+    ]];
+    set_execute_lock(pda, true)?;
+    invoke_signed(&ix, ctx.remaining_accounts, caller_seeds)?;
+    set_execute_lock(pda, false)?;
 
-    // 4. Log success
+    // 6. Forward return data of destination program
+    let return_data = forward_return_data(&ctx.accounts.destination_program.key(), nonce);
+
+    // 7. Log success
     msg!(
-        "Execute done: destination contract = {}, amount = {}",
+        "Execute done: destination contract = {}, amount = {}, return data = {:?}",
         ctx.accounts.destination_program.key(),
         amount,
+        return_data,
     );
 
     Ok(())
@@ -102,25 +188,25 @@ pub fn handle_sol(
     recovery_id: u8,
     message_hash: [u8; 32],
     nonce: u64,
+    expiry: Option<i64>,
 ) -> Result<()> {
-    let instruction_data = CallableInstruction::OnCall {
+    let instruction = CallableInstruction::OnCall {
         amount,
         sender,
         data: data.clone(),
-    }
-    .pack();
+    };
 
     handle_sol_common(
         ctx,
         amount,
-        data,
+        &[&sender, &data],
         signature,
         recovery_id,
         message_hash,
         nonce,
-        sender.to_vec(),
+        expiry,
         InstructionId::ExecuteSol,
-        instruction_data,
+        instruction,
     )
 }
 
@@ -134,70 +220,174 @@ pub fn handle_sol_revert(
     recovery_id: u8,
     message_hash: [u8; 32],
     nonce: u64,
+    expiry: Option<i64>,
 ) -> Result<()> {
-    let instruction_data = CallableInstruction::OnRevert {
+    let instruction = CallableInstruction::OnRevert {
         amount,
         sender,
         data: data.clone(),
-    }
-    .pack();
+    };
 
     handle_sol_common(
         ctx,
         amount,
-        data,
+        &[sender.as_ref(), &data],
         signature,
         recovery_id,
         message_hash,
         nonce,
-        sender.to_bytes().to_vec(),
+        expiry,
         InstructionId::ExecuteSolRevert,
-        instruction_data,
+        instruction,
+    )
+}
+
+// Withdraws amount to destination program pda, and calls on_abort on destination program
+pub fn handle_sol_abort(
+    ctx: Context<Execute>,
+    amount: u64,
+    sender: Vec<u8>,
+    revert_message: Vec<u8>,
+    outgoing: bool,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+    expiry: Option<i64>,
+) -> Result<()> {
+    let instruction = CallableInstruction::OnAbort {
+        context: AbortContext {
+            sender: sender.clone(),
+            asset: native_mint::ID,
+            amount,
+            outgoing,
+            revert_message: revert_message.clone(),
+        },
+    };
+
+    handle_sol_common(
+        ctx,
+        amount,
+        &[
+            &[outgoing as u8],
+            &(sender.len() as u32).to_le_bytes(),
+            &sender,
+            &revert_message,
+        ],
+        signature,
+        recovery_id,
+        message_hash,
+        nonce,
+        expiry,
+        InstructionId::ExecuteSolAbort,
+        instruction,
     )
 }
 
 // Common implementation for SPL token withdrawals
+#[allow(clippy::too_many_arguments)]
 fn handle_spl_token_common(
     ctx: Context<ExecuteSPLToken>,
     decimals: u8,
     amount: u64,
-    data: Vec<u8>,
+    message_data: &[&[u8]],
     signature: [u8; 64],
     recovery_id: u8,
     message_hash: [u8; 32],
     nonce: u64,
-    sender: Vec<u8>,
+    expiry: Option<i64>,
     instruction_id: InstructionId,
-    instruction_data: Vec<u8>,
+    instruction: CallableInstruction,
 ) -> Result<()> {
     let pda = &mut ctx.accounts.pda;
 
-    // 1. Validate message
-    validate_message(
+    // 1. Validate message, or release the outbound queued for it
+    let mint = ctx.accounts.mint_account.key().to_bytes();
+    let destination_program_pda_ata = ctx.accounts.destination_program_pda_ata.key().to_bytes();
+    let accounts_hash = hash_remaining_accounts(ctx.remaining_accounts);
+    let mut message_parts: Vec<&[u8]> = vec![&mint, &destination_program_pda_ata];
+    message_parts.extend_from_slice(message_data);
+    message_parts.push(&accounts_hash);
+    let released = validate_outbound_message(
         pda,
+        ctx.accounts.pending_withdrawal.as_deref(),
+        &ctx.accounts.signer,
         instruction_id,
         nonce,
         amount,
-        &[
-            &ctx.accounts.mint_account.key().to_bytes(),
-            &ctx.accounts.destination_program_pda_ata.key().to_bytes(),
-            &sender,
-            &data,
-        ],
+        &message_parts,
         &message_hash,
         &signature,
         recovery_id,
+        expiry,
     )?;
 
-    let account_metas = prepare_account_metas(ctx.remaining_accounts, &ctx.accounts.signer, pda)?;
+    // 2. Verify destination program and its PDA against the registry
+    let connected_program = load_connected_program(&ctx.accounts.connected_program)?;
+    verify_destination_allowed(pda, connected_program.as_ref())?;
+    verify_destination_pda(
+        &ctx.accounts.destination_program.key(),
+        &ctx.accounts.destination_program_pda.key(),
+        connected_program.as_ref(),
+    )?;
+
+    // 3. Record outbound against rate limit and circuit breaker, queue it above the delay threshold
+    if !released {
+        let custody = ctx.accounts.pda_ata.amount;
+        if !record_outbound(
+            pda,
+            &ctx.accounts.rate_limit,
+            ctx.accounts.mint_account.key(),
+            amount,
+            custody,
+        )? {
+            return Ok(());
+        }
+
+        if defer_outbound(
+            &ctx.accounts.rate_limit,
+            ctx.accounts.pending_withdrawal.as_deref(),
+            &ctx.accounts.signer,
+            &ctx.accounts.system_program,
+            nonce,
+            message_hash,
+            ctx.accounts.mint_account.key(),
+            ctx.accounts.destination_program_pda.key(),
+            amount,
+            decimals,
+        )? {
+            return Ok(());
+        }
+    }
+
+    let account_metas = prepare_account_metas(
+        ctx.remaining_accounts,
+        &ctx.accounts.signer,
+        pda,
+        &ctx.accounts.caller_authority.key(),
+        false,
+        &[ctx.accounts.pda_ata.key()],
+    )?;
 
     let ix = Instruction {
         program_id: ctx.accounts.destination_program.key(),
         accounts: account_metas,
-        data: instruction_data,
+        data: instruction
+            .with_context(
+                MessageContext::new(
+                    ctx.accounts.mint_account.key(),
+                    ctx.accounts.mint_account.decimals,
+                    nonce,
+                    pda.chain_id,
+                ),
+                connected_program.as_ref(),
+            )
+            .pack_for(connected_program.as_ref()),
     };
 
-    // 2. Verify token accounts
+    // 4. Verify mint is withdrawable and token accounts
+    verify_withdraw_allowed(&ctx.accounts.whitelist_entry)?;
+
     verify_ata_match(
         &pda.key(),
         &ctx.accounts.mint_account.key(),
@@ -210,7 +400,7 @@ fn handle_spl_token_common(
         &ctx.accounts.destination_program_pda_ata.key(),
     )?;
 
-    // 3. Transfer tokens
+    // 5. Transfer tokens
     let token = &ctx.accounts.token_program;
     let signer_seeds: &[&[&[u8]]] = &[&[b"meta", &[ctx.bumps.pda]]];
 
@@ -227,17 +417,28 @@ fn handle_spl_token_common(
 
     anchor_spl::token::transfer_checked(xfer_ctx, amount, decimals)?;
 
-    // 4. Invoke destination program's function
-    invoke(&ix, ctx.remaining_accounts)?;
+    // 6. Invoke destination program's function
+    let caller_seeds: &[&[&[u8]]] = &[&[
+        b"caller_authority",
+        ctx.accounts.destination_program.key.as_ref(),
+        &[ctx.bumps.caller_authority],
+    ]];
+    set_execute_lock(pda, true)?;
+    invoke_signed(&ix, ctx.remaining_accounts, caller_seeds)?;
+    set_execute_lock(pda, false)?;
 
-    // 5. Log success
+    // 7. Forward return data of destination program
+    let return_data = forward_return_data(&ctx.accounts.destination_program.key(), nonce);
+
+    // 8. Log success
     msg!(
-        "Execute SPL done: amount = {}, decimals = {}, recipient = {}, mint = {}, pda = {}",
+        "Execute SPL done: amount = {}, decimals = {}, recipient = {}, mint = {}, pda = {}, return data = {:?}",
         amount,
         decimals,
         ctx.accounts.destination_program_pda.key(),
         ctx.accounts.mint_account.key(),
-        ctx.accounts.pda.key()
+        ctx.accounts.pda.key(),
+        return_data,
     );
 
     Ok(())
@@ -254,26 +455,26 @@ pub fn handle_spl_token(
     recovery_id: u8,
     message_hash: [u8; 32],
     nonce: u64,
+    expiry: Option<i64>,
 ) -> Result<()> {
-    let instruction_data = CallableInstruction::OnCall {
+    let instruction = CallableInstruction::OnCall {
         amount,
         sender,
         data: data.clone(),
-    }
-    .pack();
+    };
 
     handle_spl_token_common(
         ctx,
         decimals,
         amount,
-        data,
+        &[&sender, &data],
         signature,
         recovery_id,
         message_hash,
         nonce,
-        sender.to_vec(),
+        expiry,
         InstructionId::ExecuteSplToken,
-        instruction_data,
+        instruction,
     )
 }
 
@@ -288,25 +489,891 @@ pub fn handle_spl_token_revert(
     recovery_id: u8,
     message_hash: [u8; 32],
     nonce: u64,
+    expiry: Option<i64>,
 ) -> Result<()> {
-    let instruction_data = CallableInstruction::OnRevert {
+    let instruction = CallableInstruction::OnRevert {
         amount,
         sender,
         data: data.clone(),
-    }
-    .pack();
+    };
 
     handle_spl_token_common(
         ctx,
         decimals,
         amount,
-        data,
+        &[sender.as_ref(), &data],
         signature,
         recovery_id,
         message_hash,
         nonce,
-        sender.to_bytes().to_vec(),
+        expiry,
         InstructionId::ExecuteSplTokenRevert,
-        instruction_data,
+        instruction,
+    )
+}
+
+// Withdraws amount of SPL tokens to destination program pda, and calls on_call on destination program.
+// Decimals are read from the mint and committed to in the message hash.
+pub fn handle_spl_token_v2(
+    ctx: Context<ExecuteSPLToken>,
+    amount: u64,
+    sender: [u8; 20],
+    data: Vec<u8>,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+    expiry: Option<i64>,
+) -> Result<()> {
+    let decimals = ctx.accounts.mint_account.decimals;
+    let instruction = CallableInstruction::OnCall {
+        amount,
+        sender,
+        data: data.clone(),
+    };
+
+    handle_spl_token_common(
+        ctx,
+        decimals,
+        amount,
+        &[&[decimals], &sender, &data],
+        signature,
+        recovery_id,
+        message_hash,
+        nonce,
+        expiry,
+        InstructionId::ExecuteSplTokenV2,
+        instruction,
+    )
+}
+
+// Withdraws amount of SPL tokens to destination program pda, and calls on_revert on destination program.
+// Decimals are read from the mint and committed to in the message hash.
+pub fn handle_spl_token_revert_v2(
+    ctx: Context<ExecuteSPLToken>,
+    amount: u64,
+    sender: Pubkey,
+    data: Vec<u8>,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+    expiry: Option<i64>,
+) -> Result<()> {
+    let decimals = ctx.accounts.mint_account.decimals;
+    let instruction = CallableInstruction::OnRevert {
+        amount,
+        sender,
+        data: data.clone(),
+    };
+
+    handle_spl_token_common(
+        ctx,
+        decimals,
+        amount,
+        &[&[decimals], sender.as_ref(), &data],
+        signature,
+        recovery_id,
+        message_hash,
+        nonce,
+        expiry,
+        InstructionId::ExecuteSplTokenRevertV2,
+        instruction,
+    )
+}
+
+// Withdraws amount of SPL tokens to destination program pda, and calls on_abort on destination program
+#[allow(clippy::too_many_arguments)]
+pub fn handle_spl_token_abort(
+    ctx: Context<ExecuteSPLToken>,
+    decimals: u8,
+    amount: u64,
+    sender: Vec<u8>,
+    revert_message: Vec<u8>,
+    outgoing: bool,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+    expiry: Option<i64>,
+) -> Result<()> {
+    let instruction = CallableInstruction::OnAbort {
+        context: AbortContext {
+            sender: sender.clone(),
+            asset: ctx.accounts.mint_account.key(),
+            amount,
+            outgoing,
+            revert_message: revert_message.clone(),
+        },
+    };
+
+    handle_spl_token_common(
+        ctx,
+        decimals,
+        amount,
+        &[
+            &[outgoing as u8],
+            &(sender.len() as u32).to_le_bytes(),
+            &sender,
+            &revert_message,
+        ],
+        signature,
+        recovery_id,
+        message_hash,
+        nonce,
+        expiry,
+        InstructionId::ExecuteSplTokenAbort,
+        instruction,
+    )
+}
+
+// Withdraws amount as wrapped SOL to destination program pda ATA, and calls on_call on destination program
+pub fn handle_wsol(
+    ctx: Context<ExecuteWsol>,
+    amount: u64,
+    sender: [u8; 20],
+    data: Vec<u8>,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+    expiry: Option<i64>,
+) -> Result<()> {
+    let instruction = CallableInstruction::OnCall {
+        amount,
+        sender,
+        data: data.clone(),
+    };
+
+    handle_wsol_common(
+        ctx,
+        amount,
+        &[&sender, &data],
+        signature,
+        recovery_id,
+        message_hash,
+        nonce,
+        expiry,
+        InstructionId::ExecuteWsol,
+        instruction,
+    )
+}
+
+// Withdraws amount as wrapped SOL to destination program pda ATA, and calls on_revert on destination program
+pub fn handle_wsol_revert(
+    ctx: Context<ExecuteWsol>,
+    amount: u64,
+    sender: Pubkey,
+    data: Vec<u8>,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+    expiry: Option<i64>,
+) -> Result<()> {
+    let instruction = CallableInstruction::OnRevert {
+        amount,
+        sender,
+        data: data.clone(),
+    };
+
+    handle_wsol_common(
+        ctx,
+        amount,
+        &[sender.as_ref(), &data],
+        signature,
+        recovery_id,
+        message_hash,
+        nonce,
+        expiry,
+        InstructionId::ExecuteWsolRevert,
+        instruction,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_wsol_common(
+    ctx: Context<ExecuteWsol>,
+    amount: u64,
+    message_data: &[&[u8]],
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+    expiry: Option<i64>,
+    instruction_id: InstructionId,
+    instruction: CallableInstruction,
+) -> Result<()> {
+    let pda = &mut ctx.accounts.pda;
+
+    let mint = ctx.accounts.mint_account.key().to_bytes();
+    let destination_program_pda_ata = ctx.accounts.destination_program_pda_ata.key().to_bytes();
+    let accounts_hash = hash_remaining_accounts(ctx.remaining_accounts);
+    let mut message_parts: Vec<&[u8]> = vec![&mint, &destination_program_pda_ata];
+    message_parts.extend_from_slice(message_data);
+    message_parts.push(&accounts_hash);
+
+    // 1. Validate message, or release the outbound queued for it
+    let released = validate_outbound_message(
+        pda,
+        ctx.accounts.pending_withdrawal.as_deref(),
+        &ctx.accounts.signer,
+        instruction_id,
+        nonce,
+        amount,
+        &message_parts,
+        &message_hash,
+        &signature,
+        recovery_id,
+        expiry,
+    )?;
+
+    // 2. Verify destination program and its PDA against the registry
+    let connected_program = load_connected_program(&ctx.accounts.connected_program)?;
+    verify_destination_allowed(pda, connected_program.as_ref())?;
+    verify_destination_pda(
+        &ctx.accounts.destination_program.key(),
+        &ctx.accounts.destination_program_pda.key(),
+        connected_program.as_ref(),
+    )?;
+
+    // 3. Record outbound against rate limit and circuit breaker, queue it above the delay threshold
+    if !released {
+        let custody = pda.get_lamports();
+        if !record_outbound(
+            pda,
+            &ctx.accounts.rate_limit,
+            native_mint::ID,
+            amount,
+            custody,
+        )? {
+            return Ok(());
+        }
+
+        if defer_outbound(
+            &ctx.accounts.rate_limit,
+            ctx.accounts.pending_withdrawal.as_deref(),
+            &ctx.accounts.signer,
+            &ctx.accounts.system_program,
+            nonce,
+            message_hash,
+            native_mint::ID,
+            ctx.accounts.destination_program_pda.key(),
+            amount,
+            native_mint::DECIMALS,
+        )? {
+            return Ok(());
+        }
+    }
+
+    let account_metas = prepare_account_metas(
+        ctx.remaining_accounts,
+        &ctx.accounts.signer,
+        pda,
+        &ctx.accounts.caller_authority.key(),
+        false,
+        &[],
+    )?;
+
+    let ix = Instruction {
+        program_id: ctx.accounts.destination_program.key(),
+        accounts: account_metas,
+        data: instruction
+            .with_context(
+                MessageContext::new(native_mint::ID, native_mint::DECIMALS, nonce, pda.chain_id),
+                connected_program.as_ref(),
+            )
+            .pack_for(connected_program.as_ref()),
+    };
+
+    // 4. Verify destination token account, create it if needed
+    verify_ata_match(
+        &ctx.accounts.destination_program_pda.key(),
+        &ctx.accounts.mint_account.key(),
+        &ctx.accounts.destination_program_pda_ata.key(),
+    )?;
+    let cost_ata_create = create_ata_if_needed(
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.destination_program_pda.to_account_info(),
+        &ctx.accounts.mint_account.to_account_info(),
+        &ctx.accounts.destination_program_pda_ata,
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    // 5. Wrap SOL into destination program PDA ATA and reimburse gas costs
+    wrap_sol(
+        pda,
+        &ctx.accounts.destination_program_pda_ata,
+        &ctx.accounts.token_program.to_account_info(),
+        amount,
+    )?;
+
+    let reimbursement = DEFAULT_GAS_COST + cost_ata_create;
+    pda.sub_lamports(reimbursement)?;
+    ctx.accounts.signer.add_lamports(reimbursement)?;
+
+    // 6. Invoke destination program's function
+    let caller_seeds: &[&[&[u8]]] = &[&[
+        b"caller_authority",
+        ctx.accounts.destination_program.key.as_ref(),
+        &[ctx.bumps.caller_authority],
+    ]];
+    set_execute_lock(pda, true)?;
+    invoke_signed(&ix, ctx.remaining_accounts, caller_seeds)?;
+    set_execute_lock(pda, false)?;
+
+    // 7. Forward return data of destination program
+    let return_data = forward_return_data(&ctx.accounts.destination_program.key(), nonce);
+
+    // 8. Log success
+    msg!(
+        "Execute wSOL done: destination contract = {}, amount = {}, recipient = {}, return data = {:?}",
+        ctx.accounts.destination_program.key(),
+        amount,
+        ctx.accounts.destination_program_pda_ata.key(),
+        return_data,
+    );
+
+    Ok(())
+}
+
+// Invokes an instruction signed by TSS verbatim on destination program, without signing for any account.
+// Moves amount to recipient if not zero.
+pub fn handle_arbitrary_call(
+    ctx: Context<ExecuteArbitraryCall>,
+    amount: u64,
+    accounts: Vec<ArbitraryCallAccount>,
+    data: Vec<u8>,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+    expiry: Option<i64>,
+) -> Result<()> {
+    let pda = &mut ctx.accounts.pda;
+    let recipient = ctx.accounts.recipient.as_ref();
+    let recipient_key = recipient
+        .map(|recipient| recipient.key())
+        .unwrap_or_default();
+
+    // 1. Validate message, or release the outbound queued for it
+    let released = validate_outbound_message(
+        pda,
+        ctx.accounts.pending_withdrawal.as_deref(),
+        &ctx.accounts.signer,
+        InstructionId::ExecuteArbitraryCall,
+        nonce,
+        amount,
+        &[
+            &ctx.accounts.destination_program.key().to_bytes(),
+            &recipient_key.to_bytes(),
+            &arbitrary_call_message_bytes(&accounts, &data),
+        ],
+        &message_hash,
+        &signature,
+        recovery_id,
+        expiry,
+    )?;
+
+    // 2. Verify destination program against the registry
+    let connected_program = load_connected_program(&ctx.accounts.connected_program)?;
+    verify_destination_allowed(pda, connected_program.as_ref())?;
+
+    // 3. Record outbound against rate limit and circuit breaker, queue it above the delay threshold
+    if !released {
+        let custody = pda.get_lamports();
+        if !record_outbound(
+            pda,
+            &ctx.accounts.rate_limit,
+            native_mint::ID,
+            amount,
+            custody,
+        )? {
+            return Ok(());
+        }
+
+        if defer_outbound(
+            &ctx.accounts.rate_limit,
+            ctx.accounts.pending_withdrawal.as_deref(),
+            &ctx.accounts.signer,
+            &ctx.accounts.system_program,
+            nonce,
+            message_hash,
+            native_mint::ID,
+            recipient_key,
+            amount,
+            native_mint::DECIMALS,
+        )? {
+            return Ok(());
+        }
+    }
+
+    // 4. Transfer SOL to recipient if specified
+    if amount > 0 {
+        let recipient = recipient.ok_or(Errors::RecipientRequired)?;
+        pda.sub_lamports(amount)?;
+        recipient.add_lamports(amount)?;
+    }
+
+    // 5. Invoke instruction
+    let ix = Instruction {
+        program_id: ctx.accounts.destination_program.key(),
+        accounts: prepare_arbitrary_call_metas(&accounts, &ctx.accounts.signer.key(), &pda.key())?,
+        data,
+    };
+    set_execute_lock(pda, true)?;
+    invoke(&ix, ctx.remaining_accounts)?;
+    set_execute_lock(pda, false)?;
+
+    // 6. Forward return data of destination program
+    let return_data = forward_return_data(&ctx.accounts.destination_program.key(), nonce);
+
+    // 7. Log success
+    msg!(
+        "Arbitrary call done: destination contract = {}, amount = {}, recipient = {}, return data = {:?}",
+        ctx.accounts.destination_program.key(),
+        amount,
+        recipient_key,
+        return_data,
+    );
+
+    Ok(())
+}
+
+// Calls destination program with the interchain account of a ZEVM sender as signer.
+pub fn handle_as_account(
+    ctx: Context<ExecuteAsAccount>,
+    sender: [u8; 20],
+    data: Vec<u8>,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+    expiry: Option<i64>,
+) -> Result<()> {
+    let pda = &mut ctx.accounts.pda;
+    let accounts: Vec<ArbitraryCallAccount> = ctx
+        .remaining_accounts
+        .iter()
+        .map(|account_info| ArbitraryCallAccount {
+            pubkey: account_info.key(),
+            is_writable: account_info.is_writable,
+        })
+        .collect();
+
+    // 1. Validate message
+    validate_message(
+        pda,
+        InstructionId::ExecuteAsAccount,
+        nonce,
+        0, // No amount is moved by the gateway
+        &[
+            &ctx.accounts.destination_program.key().to_bytes(),
+            &sender,
+            &arbitrary_call_message_bytes(&accounts, &data),
+        ],
+        &message_hash,
+        &signature,
+        recovery_id,
+        expiry,
+    )?;
+
+    // 2. Verify outbounds are not paused and destination program against the registry
+    require!(!pda.outbound_paused, Errors::OutboundPaused);
+    let connected_program = load_connected_program(&ctx.accounts.connected_program)?;
+    verify_destination_allowed(pda, connected_program.as_ref())?;
+
+    let account_metas = prepare_account_metas(
+        ctx.remaining_accounts,
+        &ctx.accounts.signer,
+        pda,
+        &ctx.accounts.interchain_account.key(),
+        true,
+        &[],
+    )?;
+
+    let ix = Instruction {
+        program_id: ctx.accounts.destination_program.key(),
+        accounts: account_metas,
+        data,
+    };
+
+    // 3. Invoke destination program's function signed by the interchain account
+    let account_seeds: &[&[&[u8]]] = &[&[
+        b"interchain_account",
+        &sender,
+        &[ctx.bumps.interchain_account],
+    ]];
+    set_execute_lock(pda, true)?;
+    invoke_signed(&ix, ctx.remaining_accounts, account_seeds)?;
+    set_execute_lock(pda, false)?;
+
+    // 4. Forward return data of destination program
+    let return_data = forward_return_data(&ctx.accounts.destination_program.key(), nonce);
+
+    // 5. Log success
+    msg!(
+        "Execute as account done: destination contract = {}, interchain account = {}, sender = {:?}, return data = {:?}",
+        ctx.accounts.destination_program.key(),
+        ctx.accounts.interchain_account.key(),
+        sender,
+        return_data,
+    );
+
+    Ok(())
+}
+
+// Invokes the steps signed by TSS in order, each with its own slice of remaining accounts.
+// Lamports of a step are moved to the first account of the step before its call, tokens to its ATA.
+pub fn handle_multi<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteMulti<'info>>,
+    steps: Vec<ExecuteStep>,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+    expiry: Option<i64>,
+) -> Result<()> {
+    let pda = &mut ctx.accounts.pda;
+
+    require!(
+        !steps.is_empty() && steps.len() <= MAX_EXECUTE_STEPS,
+        Errors::InvalidExecuteSteps
+    );
+    let amount = steps.iter().try_fold(0u64, |total, step| {
+        total
+            .checked_add(step.amount)
+            .ok_or(Errors::InvalidExecuteSteps)
+    })?;
+    let steps_data: Vec<u8> = steps
+        .iter()
+        .flat_map(|step| step.to_message_bytes())
+        .collect();
+
+    // 1. Validate message, or release the outbound queued for it
+    let released = validate_outbound_message(
+        pda,
+        ctx.accounts.pending_withdrawal.as_deref(),
+        &ctx.accounts.signer,
+        InstructionId::ExecuteMulti,
+        nonce,
+        amount,
+        &[
+            &steps_data,
+            &hash_remaining_accounts(ctx.remaining_accounts),
+        ],
+        &message_hash,
+        &signature,
+        recovery_id,
+        expiry,
+    )?;
+
+    // 2. Verify destination programs against the registry and mints of the steps
+    for step in steps.iter() {
+        let connected_program = find_connected_program(ctx.remaining_accounts, &step.program_id)?;
+        verify_destination_allowed(pda, connected_program.as_ref())?;
+    }
+    let step_tokens = find_step_token_accounts(ctx.remaining_accounts, &pda.key(), &steps)?;
+    let custody_accounts: Vec<Pubkey> = step_tokens
+        .iter()
+        .map(|step_token| step_token.pda_ata.key())
+        .collect();
+
+    // 3. Record outbound against rate limit and circuit breaker, queue it above the delay threshold
+    if !released {
+        let custody = pda.get_lamports();
+        if !record_outbound(
+            pda,
+            &ctx.accounts.rate_limit,
+            native_mint::ID,
+            amount,
+            custody,
+        )? {
+            return Ok(());
+        }
+        for step_token in step_tokens.iter() {
+            let custody =
+                TokenAccount::try_deserialize(&mut &step_token.pda_ata.try_borrow_data()?[..])?
+                    .amount;
+            if !record_outbound(
+                pda,
+                step_token.rate_limit,
+                step_token.mint.key(),
+                step_token.amount,
+                custody,
+            )? {
+                return Ok(());
+            }
+        }
+
+        if defer_outbound(
+            &ctx.accounts.rate_limit,
+            ctx.accounts.pending_withdrawal.as_deref(),
+            &ctx.accounts.signer,
+            &ctx.accounts.system_program,
+            nonce,
+            message_hash,
+            native_mint::ID,
+            Pubkey::default(),
+            amount,
+            native_mint::DECIMALS,
+        )? {
+            return Ok(());
+        }
+        for step_token in step_tokens.iter() {
+            if defer_outbound(
+                step_token.rate_limit,
+                ctx.accounts.pending_withdrawal.as_deref(),
+                &ctx.accounts.signer,
+                &ctx.accounts.system_program,
+                nonce,
+                message_hash,
+                step_token.mint.key(),
+                Pubkey::default(),
+                step_token.amount,
+                step_token.decimals,
+            )? {
+                return Ok(());
+            }
+        }
+    }
+
+    // 4. Invoke steps in order
+    // Each step is signed by the caller authority of its own destination program.
+    let signer_seeds: &[&[&[u8]]] = &[&[b"meta", &[ctx.bumps.pda]]];
+    set_execute_lock(pda, true)?;
+    for step in steps.into_iter() {
+        let start = step.accounts_start as usize;
+        let step_accounts = ctx
+            .remaining_accounts
+            .get(start..start + step.accounts_len as usize)
+            .ok_or(Errors::InvalidExecuteSteps)?;
+
+        if step.amount > 0 {
+            let recipient = step_accounts
+                .first()
+                .filter(|account| account.is_writable && account.key() != pda.key())
+                .ok_or(Errors::InvalidExecuteSteps)?;
+            pda.sub_lamports(step.amount)?;
+            recipient.add_lamports(step.amount)?;
+        }
+
+        if let Some(token) = &step.token {
+            let recipient_ata = step_accounts
+                .first()
+                .map(|account| get_associated_token_address(account.key, &token.mint))
+                .ok_or(Errors::InvalidExecuteSteps)?;
+            let recipient = ctx
+                .remaining_accounts
+                .iter()
+                .find(|account| account.key() == recipient_ata)
+                .ok_or(Errors::InvalidExecuteSteps)?;
+            let step_token = step_tokens
+                .iter()
+                .find(|step_token| step_token.mint.key() == token.mint)
+                .ok_or(Errors::InvalidExecuteSteps)?;
+            let xfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::TransferChecked {
+                    from: step_token.pda_ata.clone(),
+                    mint: step_token.mint.clone(),
+                    to: recipient.clone(),
+                    authority: pda.to_account_info(),
+                },
+                signer_seeds,
+            );
+            anchor_spl::token::transfer_checked(xfer_ctx, token.amount, token.decimals)?;
+        }
+
+        let (caller_authority, caller_bump) = Pubkey::find_program_address(
+            &[b"caller_authority", step.program_id.as_ref()],
+            &crate::ID,
+        );
+        let ix = Instruction {
+            program_id: step.program_id,
+            accounts: prepare_account_metas(
+                step_accounts,
+                &ctx.accounts.signer,
+                pda,
+                &caller_authority,
+                false,
+                &custody_accounts,
+            )?,
+            data: step.data,
+        };
+        invoke_signed(
+            &ix,
+            ctx.remaining_accounts,
+            &[&[
+                b"caller_authority",
+                step.program_id.as_ref(),
+                &[caller_bump],
+            ]],
+        )?;
+
+        msg!(
+            "Execute step done: destination contract = {}, amount = {}, token amount = {}",
+            step.program_id,
+            step.amount,
+            step.token.as_ref().map_or(0, |token| token.amount),
+        );
+    }
+    set_execute_lock(pda, false)?;
+
+    // 5. Log success
+    msg!("Execute multi done: amount = {}, nonce = {}", amount, nonce);
+
+    Ok(())
+}
+
+// Accounts of a mint moved by the steps of a multi-step execute.
+struct StepTokenAccounts<'a, 'info> {
+    mint: &'a AccountInfo<'info>,
+    pda_ata: &'a AccountInfo<'info>,
+    rate_limit: &'a AccountInfo<'info>,
+    decimals: u8,
+    amount: u64,
+}
+
+// Finds the accounts of every mint moved by the steps among remaining accounts.
+// Amounts of steps sharing a mint are summed, so the mint is recorded once per outbound.
+fn find_step_token_accounts<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    pda: &Pubkey,
+    steps: &[ExecuteStep],
+) -> Result<Vec<StepTokenAccounts<'a, 'info>>> {
+    let find = |address: Pubkey| {
+        accounts
+            .iter()
+            .find(|account| account.key() == address)
+            .ok_or(Errors::InvalidExecuteSteps)
+    };
+
+    let mut step_tokens: Vec<StepTokenAccounts> = Vec::new();
+    for token in steps.iter().filter_map(|step| step.token.as_ref()) {
+        if let Some(step_token) = step_tokens
+            .iter_mut()
+            .find(|step_token| step_token.mint.key() == token.mint)
+        {
+            require!(
+                step_token.decimals == token.decimals,
+                Errors::InvalidExecuteSteps
+            );
+            step_token.amount = step_token
+                .amount
+                .checked_add(token.amount)
+                .ok_or(Errors::InvalidExecuteSteps)?;
+            continue;
+        }
+
+        let (whitelist_entry, _) =
+            Pubkey::find_program_address(&[b"whitelist", token.mint.as_ref()], &crate::ID);
+        verify_withdraw_allowed(find(whitelist_entry)?)?;
+
+        let (rate_limit, _) =
+            Pubkey::find_program_address(&[b"rate_limit", token.mint.as_ref()], &crate::ID);
+        step_tokens.push(StepTokenAccounts {
+            mint: find(token.mint)?,
+            pda_ata: find(get_associated_token_address(pda, &token.mint))?,
+            rate_limit: find(rate_limit)?,
+            decimals: token.decimals,
+            amount: token.amount,
+        });
+    }
+
+    Ok(step_tokens)
+}
+
+// Writes a chunk of an execute payload into the message buffer of the outbound.
+// Buffer is seeded by the relayer and nonce, so other relayers cannot claim or block it.
+pub fn stage_message(
+    ctx: Context<StageMessage>,
+    nonce: u64,
+    total_len: u32,
+    offset: u32,
+    chunk: Vec<u8>,
+) -> Result<()> {
+    let message_buffer = &mut ctx.accounts.message_buffer;
+    let relayer = ctx.accounts.relayer.key();
+
+    require!(
+        total_len <= MAX_MESSAGE_BUFFER_SIZE,
+        Errors::InvalidMessageBuffer
+    );
+
+    if message_buffer.relayer == Pubkey::default() {
+        message_buffer.relayer = relayer;
+        message_buffer.nonce = nonce;
+        message_buffer.data = vec![0u8; total_len as usize];
+    }
+    require!(
+        message_buffer.relayer == relayer && message_buffer.data.len() == total_len as usize,
+        Errors::InvalidMessageBuffer
+    );
+
+    let start = offset as usize;
+    let target = message_buffer
+        .data
+        .get_mut(start..start + chunk.len())
+        .ok_or(Errors::InvalidMessageBuffer)?;
+    target.copy_from_slice(&chunk);
+
+    msg!(
+        "Message staged: nonce = {}, offset = {}, len = {}, relayer = {}",
+        nonce,
+        offset,
+        chunk.len(),
+        relayer
+    );
+
+    Ok(())
+}
+
+// Closes a message buffer that will not be executed, refunding rent to the relayer.
+pub fn close_message_buffer(_ctx: Context<CloseMessageBuffer>, nonce: u64) -> Result<()> {
+    msg!("Message buffer closed: nonce = {}", nonce);
+
+    Ok(())
+}
+
+// Withdraws amount to destination program pda, and calls on_call with the payload staged in the message buffer.
+// The message buffer is closed afterwards, refunding rent to the relayer.
+pub fn handle_sol_from_buffer<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteFromBuffer<'info>>,
+    amount: u64,
+    sender: [u8; 20],
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+    expiry: Option<i64>,
+) -> Result<()> {
+    let data = ctx.accounts.message_buffer.data.clone();
+    let data_hash = hash(&data).to_bytes();
+    let instruction = CallableInstruction::OnCall {
+        amount,
+        sender,
+        data,
+    };
+
+    let execute_ctx = Context::new(
+        ctx.program_id,
+        &mut ctx.accounts.execute,
+        ctx.remaining_accounts,
+        ctx.bumps.execute,
+    );
+    handle_sol_common(
+        execute_ctx,
+        amount,
+        &[&sender, &data_hash],
+        signature,
+        recovery_id,
+        message_hash,
+        nonce,
+        expiry,
+        InstructionId::ExecuteFromBuffer,
+        instruction,
     )
 }
@@ -1,12 +1,19 @@
 use crate::{
-    contexts::{Withdraw, WithdrawSPLToken},
-    state::InstructionId,
-    utils::{validate_message, verify_ata_match, DEFAULT_GAS_COST},
+    contexts::{CancelWithdrawal, FinalizeWithdrawal, Withdraw, WithdrawSPLToken, WithdrawWsol},
+    errors::Errors,
+    events::{WithdrawalCancelled, WithdrawalFinalized},
+    state::{FlowDirection, InstructionId, PendingWithdrawal, Role},
+    utils::{
+        create_ata_if_needed, defer_outbound, pending_withdrawal_bump, queue_withdrawal,
+        record_outbound, refund_rate_limit, validate_message, validate_outbound_message,
+        verify_ata_match, verify_role, verify_withdraw_allowed, withdrawal_delay, wrap_sol,
+        DEFAULT_GAS_COST,
+    },
 };
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::program::invoke;
-use anchor_spl::token::transfer_checked;
-use spl_associated_token_account::instruction::create_associated_token_account;
+use anchor_spl::token::{
+    close_account, spl_token::native_mint, transfer_checked, CloseAccount, TransferChecked,
+};
 
 // Withdraws SOL. Caller is TSS.
 pub fn handle_sol(
@@ -16,6 +23,7 @@ pub fn handle_sol(
     recovery_id: u8,
     message_hash: [u8; 32],
     nonce: u64,
+    expiry: Option<i64>,
 ) -> Result<()> {
     let pda = &mut ctx.accounts.pda;
 
@@ -29,13 +37,62 @@ pub fn handle_sol(
         &message_hash,
         &signature,
         recovery_id,
+        expiry,
     )?;
 
-    // 2. Transfer SOL
+    // 2. Record outbound against rate limit and circuit breaker
+    let custody = pda.get_lamports();
+    if !record_outbound(
+        pda,
+        &ctx.accounts.rate_limit,
+        native_mint::ID,
+        amount,
+        custody,
+    )? {
+        return Ok(());
+    }
+
+    // 3. Escrow withdrawals above the delay threshold
+    if let Some(delay) = withdrawal_delay(&ctx.accounts.rate_limit, amount)? {
+        let now = Clock::get()?.unix_timestamp;
+        let pending_withdrawal = ctx
+            .accounts
+            .pending_withdrawal
+            .as_ref()
+            .ok_or(Errors::PendingWithdrawalRequired)?;
+
+        queue_withdrawal(
+            &ctx.accounts.signer.to_account_info(),
+            &pending_withdrawal.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.bumps
+                .pending_withdrawal
+                .ok_or(Errors::PendingWithdrawalRequired)?,
+            PendingWithdrawal {
+                nonce,
+                asset: native_mint::ID,
+                recipient: ctx.accounts.recipient.key(),
+                amount,
+                decimals: native_mint::DECIMALS,
+                payer: ctx.accounts.signer.key(),
+                executable_at: now.saturating_add(delay),
+                queued_at: now,
+                message_hash,
+                escrowed: true,
+            },
+        )?;
+
+        pda.sub_lamports(amount)?;
+        pending_withdrawal.add_lamports(amount)?;
+
+        return Ok(());
+    }
+
+    // 4. Transfer SOL
     pda.sub_lamports(amount)?;
     ctx.accounts.recipient.add_lamports(amount)?;
 
-    // 3. Log success
+    // 5. Log success
     msg!(
         "Withdraw executed: amount = {}, recipient = {}, pda = {}",
         amount,
@@ -55,25 +112,97 @@ pub fn handle_spl(
     recovery_id: u8,
     message_hash: [u8; 32],
     nonce: u64,
+    expiry: Option<i64>,
+) -> Result<()> {
+    handle_spl_common(
+        ctx,
+        decimals,
+        amount,
+        &[],
+        signature,
+        recovery_id,
+        message_hash,
+        nonce,
+        expiry,
+        InstructionId::WithdrawSplToken,
+    )
+}
+
+// Withdraws SPL tokens with decimals read from the mint and committed to in the message hash.
+// Caller is TSS
+pub fn handle_spl_v2(
+    ctx: Context<WithdrawSPLToken>,
+    amount: u64,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+    expiry: Option<i64>,
+) -> Result<()> {
+    let decimals = ctx.accounts.mint_account.decimals;
+
+    handle_spl_common(
+        ctx,
+        decimals,
+        amount,
+        &[&[decimals]],
+        signature,
+        recovery_id,
+        message_hash,
+        nonce,
+        expiry,
+        InstructionId::WithdrawSplTokenV2,
+    )
+}
+
+// Common implementation for SPL token withdrawals
+#[allow(clippy::too_many_arguments)]
+fn handle_spl_common(
+    ctx: Context<WithdrawSPLToken>,
+    decimals: u8,
+    amount: u64,
+    message_data: &[&[u8]],
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+    expiry: Option<i64>,
+    instruction_id: InstructionId,
 ) -> Result<()> {
     let pda = &mut ctx.accounts.pda;
 
     // 1. Validate message
+    let mint = ctx.accounts.mint_account.key().to_bytes();
+    let recipient_ata = ctx.accounts.recipient_ata.key().to_bytes();
+    let mut message_parts: Vec<&[u8]> = vec![&mint, &recipient_ata];
+    message_parts.extend_from_slice(message_data);
     validate_message(
         pda,
-        InstructionId::WithdrawSplToken,
+        instruction_id,
         nonce,
         amount,
-        &[
-            &ctx.accounts.mint_account.key().to_bytes(),
-            &ctx.accounts.recipient_ata.key().to_bytes(),
-        ],
+        &message_parts,
         &message_hash,
         &signature,
         recovery_id,
+        expiry,
     )?;
 
-    // 2. Verify token accounts
+    // 2. Record outbound against rate limit and circuit breaker
+    let custody = ctx.accounts.pda_ata.amount;
+    if !record_outbound(
+        pda,
+        &ctx.accounts.rate_limit,
+        ctx.accounts.mint_account.key(),
+        amount,
+        custody,
+    )? {
+        return Ok(());
+    }
+
+    // 3. Verify mint is withdrawable and token accounts
+    verify_withdraw_allowed(&ctx.accounts.whitelist_entry)?;
+
     verify_ata_match(
         &pda.key(),
         &ctx.accounts.mint_account.key(),
@@ -86,46 +215,84 @@ pub fn handle_spl(
         &ctx.accounts.recipient_ata.key(),
     )?;
 
-    // 3. Create recipient ATA if needed and calculate costs
-    let mut cost_ata_create: u64 = 0;
-    let recipient_ata_account = ctx.accounts.recipient_ata.to_account_info();
+    let token = &ctx.accounts.token_program;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"meta", &[ctx.bumps.pda]]];
 
-    if recipient_ata_account.lamports() == 0
-        || *recipient_ata_account.owner == ctx.accounts.system_program.key()
-    {
-        // ATA needs to be created
-        msg!(
-            "Creating associated token account {:?} for recipient {:?}...",
-            recipient_ata_account.key(),
-            ctx.accounts.recipient.key(),
-        );
+    // 4. Escrow withdrawals above the delay threshold
+    if let Some(delay) = withdrawal_delay(&ctx.accounts.rate_limit, amount)? {
+        let now = Clock::get()?.unix_timestamp;
+        let (Some(pending_withdrawal), Some(pending_withdrawal_ata)) = (
+            ctx.accounts.pending_withdrawal.as_ref(),
+            ctx.accounts.pending_withdrawal_ata.as_ref(),
+        ) else {
+            return err!(Errors::PendingWithdrawalRequired);
+        };
+        verify_ata_match(
+            &pending_withdrawal.key(),
+            &ctx.accounts.mint_account.key(),
+            &pending_withdrawal_ata.key(),
+        )?;
 
-        let bal_before = ctx.accounts.signer.lamports();
-        invoke(
-            &create_associated_token_account(
-                ctx.accounts.signer.to_account_info().key,
-                ctx.accounts.recipient.to_account_info().key,
-                ctx.accounts.mint_account.to_account_info().key,
-                ctx.accounts.token_program.key,
-            ),
-            &[
-                ctx.accounts.mint_account.to_account_info().clone(),
-                ctx.accounts.recipient_ata.clone(),
-                ctx.accounts.recipient.to_account_info().clone(),
-                ctx.accounts.signer.to_account_info().clone(),
-                ctx.accounts.system_program.to_account_info().clone(),
-                ctx.accounts.token_program.to_account_info().clone(),
-                ctx.accounts
-                    .associated_token_program
-                    .to_account_info()
-                    .clone(),
-            ],
+        queue_withdrawal(
+            &ctx.accounts.signer.to_account_info(),
+            &pending_withdrawal.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            pending_withdrawal_bump(&pending_withdrawal.key(), nonce)?,
+            PendingWithdrawal {
+                nonce,
+                asset: ctx.accounts.mint_account.key(),
+                recipient: ctx.accounts.recipient.key(),
+                amount,
+                decimals,
+                payer: ctx.accounts.signer.key(),
+                executable_at: now.saturating_add(delay),
+                queued_at: now,
+                message_hash,
+                escrowed: true,
+            },
         )?;
 
-        let bal_after = ctx.accounts.signer.lamports();
-        cost_ata_create = bal_before - bal_after;
+        // Escrow ATA rent is returned to the signer when the withdrawal settles
+        create_ata_if_needed(
+            &ctx.accounts.signer.to_account_info(),
+            &pending_withdrawal.to_account_info(),
+            &ctx.accounts.mint_account.to_account_info(),
+            &pending_withdrawal_ata.to_account_info(),
+            &token.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        let xfer_ctx = CpiContext::new_with_signer(
+            token.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.pda_ata.to_account_info(),
+                mint: ctx.accounts.mint_account.to_account_info(),
+                to: pending_withdrawal_ata.to_account_info(),
+                authority: pda.to_account_info(),
+            },
+            signer_seeds,
+        );
+        transfer_checked(xfer_ctx, amount, decimals)?;
+
+        pda.sub_lamports(DEFAULT_GAS_COST)?;
+        ctx.accounts.signer.add_lamports(DEFAULT_GAS_COST)?;
+
+        return Ok(());
+    }
+
+    // 5. Create recipient ATA if needed and calculate costs
+    let cost_ata_create = create_ata_if_needed(
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.recipient.to_account_info(),
+        &ctx.accounts.mint_account.to_account_info(),
+        &ctx.accounts.recipient_ata,
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
 
-        msg!("Associated token account created!");
+    if cost_ata_create > 0 {
         msg!(
             "Refunding the rent ({:?} lamports) paid by the signer {:?}",
             cost_ata_create,
@@ -133,13 +300,10 @@ pub fn handle_spl(
         );
     }
 
-    // 4. Transfer tokens
-    let token = &ctx.accounts.token_program;
-    let signer_seeds: &[&[&[u8]]] = &[&[b"meta", &[ctx.bumps.pda]]];
-
+    // 6. Transfer tokens
     let xfer_ctx = CpiContext::new_with_signer(
         token.to_account_info(),
-        anchor_spl::token::TransferChecked {
+        TransferChecked {
             from: ctx.accounts.pda_ata.to_account_info(),
             mint: ctx.accounts.mint_account.to_account_info(),
             to: ctx.accounts.recipient_ata.to_account_info(),
@@ -150,12 +314,12 @@ pub fn handle_spl(
 
     transfer_checked(xfer_ctx, amount, decimals)?;
 
-    // 5. Reimburse gas costs
+    // 7. Reimburse gas costs
     let reimbursement = DEFAULT_GAS_COST + cost_ata_create;
     pda.sub_lamports(reimbursement)?;
     ctx.accounts.signer.add_lamports(reimbursement)?;
 
-    // 6. Log success
+    // 8. Log success
     msg!(
         "Withdraw SPL executed: amount = {}, decimals = {}, recipient = {}, mint = {}, pda = {}",
         amount,
@@ -167,3 +331,328 @@ pub fn handle_spl(
 
     Ok(())
 }
+
+// Withdraws SOL delivered as wrapped SOL into the recipient's ATA. Caller is TSS.
+pub fn handle_wsol(
+    ctx: Context<WithdrawWsol>,
+    amount: u64,
+    signature: [u8; 64],
+    recovery_id: u8,
+    message_hash: [u8; 32],
+    nonce: u64,
+    expiry: Option<i64>,
+) -> Result<()> {
+    let pda = &mut ctx.accounts.pda;
+
+    // 1. Validate message, or release the withdrawal queued for it
+    let released = validate_outbound_message(
+        pda,
+        ctx.accounts.pending_withdrawal.as_deref(),
+        &ctx.accounts.signer,
+        InstructionId::WithdrawWsol,
+        nonce,
+        amount,
+        &[
+            &ctx.accounts.mint_account.key().to_bytes(),
+            &ctx.accounts.recipient_ata.key().to_bytes(),
+        ],
+        &message_hash,
+        &signature,
+        recovery_id,
+        expiry,
+    )?;
+
+    // 2. Record outbound against rate limit and circuit breaker, queue it above the delay threshold
+    if !released {
+        let custody = pda.get_lamports();
+        if !record_outbound(
+            pda,
+            &ctx.accounts.rate_limit,
+            native_mint::ID,
+            amount,
+            custody,
+        )? {
+            return Ok(());
+        }
+
+        if defer_outbound(
+            &ctx.accounts.rate_limit,
+            ctx.accounts.pending_withdrawal.as_deref(),
+            &ctx.accounts.signer,
+            &ctx.accounts.system_program,
+            nonce,
+            message_hash,
+            native_mint::ID,
+            ctx.accounts.recipient.key(),
+            amount,
+            native_mint::DECIMALS,
+        )? {
+            return Ok(());
+        }
+    }
+
+    // 3. Verify recipient token account
+    verify_ata_match(
+        &ctx.accounts.recipient.key(),
+        &ctx.accounts.mint_account.key(),
+        &ctx.accounts.recipient_ata.key(),
+    )?;
+
+    // 4. Create recipient ATA if needed and calculate costs
+    let cost_ata_create = create_ata_if_needed(
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.recipient.to_account_info(),
+        &ctx.accounts.mint_account.to_account_info(),
+        &ctx.accounts.recipient_ata,
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    // 5. Wrap SOL into the recipient ATA
+    wrap_sol(
+        pda,
+        &ctx.accounts.recipient_ata,
+        &ctx.accounts.token_program.to_account_info(),
+        amount,
+    )?;
+
+    if cost_ata_create > 0 {
+        msg!(
+            "Refunding the rent ({:?} lamports) paid by the signer {:?}",
+            cost_ata_create,
+            ctx.accounts.signer.to_account_info().key
+        );
+    }
+
+    // 6. Reimburse gas costs
+    let reimbursement = DEFAULT_GAS_COST + cost_ata_create;
+    pda.sub_lamports(reimbursement)?;
+    ctx.accounts.signer.add_lamports(reimbursement)?;
+
+    // 7. Log success
+    msg!(
+        "Withdraw wSOL executed: amount = {}, recipient = {}, recipient_ata = {}, pda = {}",
+        amount,
+        ctx.accounts.recipient.key(),
+        ctx.accounts.recipient_ata.key(),
+        ctx.accounts.pda.key()
+    );
+
+    Ok(())
+}
+
+// Pays out a queued withdrawal once its settlement delay has elapsed. Caller is anyone.
+pub fn finalize(ctx: Context<FinalizeWithdrawal>) -> Result<()> {
+    let pending_withdrawal = &ctx.accounts.pending_withdrawal;
+
+    // 1. Verify the withdrawal can be settled
+    require!(!ctx.accounts.pda.outbound_paused, Errors::OutboundPaused);
+    require!(pending_withdrawal.escrowed, Errors::WithdrawalNotEscrowed);
+    require!(
+        Clock::get()?.unix_timestamp >= pending_withdrawal.executable_at,
+        Errors::WithdrawalDelayNotElapsed
+    );
+
+    // 2. Pay out the escrow, the pending withdrawal is closed to the payer on exit
+    if pending_withdrawal.asset == native_mint::ID {
+        pending_withdrawal.sub_lamports(pending_withdrawal.amount)?;
+        ctx.accounts
+            .recipient
+            .add_lamports(pending_withdrawal.amount)?;
+    } else {
+        let (
+            Some(mint_account),
+            Some(pending_withdrawal_ata),
+            Some(recipient_ata),
+            Some(token_program),
+            Some(associated_token_program),
+        ) = (
+            ctx.accounts.mint_account.as_ref(),
+            ctx.accounts.pending_withdrawal_ata.as_ref(),
+            ctx.accounts.recipient_ata.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+            ctx.accounts.associated_token_program.as_ref(),
+        )
+        else {
+            return err!(Errors::PendingWithdrawalRequired);
+        };
+        require_keys_eq!(
+            mint_account.key(),
+            pending_withdrawal.asset,
+            Errors::SPLAtaAndMintAddressMismatch
+        );
+        verify_ata_match(
+            &pending_withdrawal.key(),
+            &mint_account.key(),
+            &pending_withdrawal_ata.key(),
+        )?;
+        verify_ata_match(
+            &pending_withdrawal.recipient,
+            &mint_account.key(),
+            &recipient_ata.key(),
+        )?;
+
+        // Recipient ATA rent is paid by the signer finalizing the withdrawal
+        create_ata_if_needed(
+            &ctx.accounts.signer.to_account_info(),
+            &ctx.accounts.recipient.to_account_info(),
+            &mint_account.to_account_info(),
+            &recipient_ata.to_account_info(),
+            &token_program.to_account_info(),
+            &associated_token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        let nonce_bytes = pending_withdrawal.nonce.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"pending_withdrawal",
+            &nonce_bytes,
+            &[ctx.bumps.pending_withdrawal],
+        ]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: pending_withdrawal_ata.to_account_info(),
+                    mint: mint_account.to_account_info(),
+                    to: recipient_ata.to_account_info(),
+                    authority: pending_withdrawal.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            pending_withdrawal.amount,
+            pending_withdrawal.decimals,
+        )?;
+
+        close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: pending_withdrawal_ata.to_account_info(),
+                destination: ctx.accounts.payer.to_account_info(),
+                authority: pending_withdrawal.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    // 3. Log success
+    msg!(
+        "Withdrawal finalized: nonce = {}, asset = {}, recipient = {}, amount = {}",
+        pending_withdrawal.nonce,
+        pending_withdrawal.asset,
+        pending_withdrawal.recipient,
+        pending_withdrawal.amount
+    );
+    emit!(WithdrawalFinalized {
+        nonce: pending_withdrawal.nonce,
+        asset: pending_withdrawal.asset,
+        recipient: pending_withdrawal.recipient,
+        amount: pending_withdrawal.amount,
+    });
+
+    Ok(())
+}
+
+// Cancels a queued withdrawal, returns any escrow to custody and its amount to the rate limit.
+// The pending withdrawal is closed to the payer, the outbound is to be reverted.
+// Caller is authority stored in PDA, a pauser or a guardian.
+pub fn cancel(ctx: Context<CancelWithdrawal>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    if !ctx.accounts.pda.is_guardian(&signer) {
+        verify_role(
+            &signer,
+            &ctx.accounts.pda,
+            &ctx.accounts.roles,
+            Role::Pauser,
+        )?;
+    }
+    let pda = &mut ctx.accounts.pda;
+    let pending_withdrawal = &ctx.accounts.pending_withdrawal;
+
+    // 1. Return the amount to the rate limit of the asset
+    refund_rate_limit(
+        &ctx.accounts.rate_limit,
+        FlowDirection::Outbound,
+        pending_withdrawal.amount,
+        pending_withdrawal.queued_at,
+    )?;
+
+    // 2. Return the escrow to custody, outbounds queued without escrow never left it
+    if pending_withdrawal.escrowed && pending_withdrawal.asset == native_mint::ID {
+        pending_withdrawal.sub_lamports(pending_withdrawal.amount)?;
+        pda.add_lamports(pending_withdrawal.amount)?;
+    } else if pending_withdrawal.escrowed {
+        let (Some(mint_account), Some(pending_withdrawal_ata), Some(pda_ata), Some(token_program)) = (
+            ctx.accounts.mint_account.as_ref(),
+            ctx.accounts.pending_withdrawal_ata.as_ref(),
+            ctx.accounts.pda_ata.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(Errors::PendingWithdrawalRequired);
+        };
+        require_keys_eq!(
+            mint_account.key(),
+            pending_withdrawal.asset,
+            Errors::SPLAtaAndMintAddressMismatch
+        );
+        verify_ata_match(
+            &pending_withdrawal.key(),
+            &mint_account.key(),
+            &pending_withdrawal_ata.key(),
+        )?;
+        verify_ata_match(&pda.key(), &mint_account.key(), &pda_ata.key())?;
+
+        let nonce_bytes = pending_withdrawal.nonce.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"pending_withdrawal",
+            &nonce_bytes,
+            &[ctx.bumps.pending_withdrawal],
+        ]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: pending_withdrawal_ata.to_account_info(),
+                    mint: mint_account.to_account_info(),
+                    to: pda_ata.to_account_info(),
+                    authority: pending_withdrawal.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            pending_withdrawal.amount,
+            pending_withdrawal.decimals,
+        )?;
+
+        close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: pending_withdrawal_ata.to_account_info(),
+                destination: ctx.accounts.payer.to_account_info(),
+                authority: pending_withdrawal.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    // 3. Log success
+    msg!(
+        "Withdrawal cancelled: nonce = {}, asset = {}, recipient = {}, amount = {}, signer = {}",
+        pending_withdrawal.nonce,
+        pending_withdrawal.asset,
+        pending_withdrawal.recipient,
+        pending_withdrawal.amount,
+        signer
+    );
+    emit!(WithdrawalCancelled {
+        nonce: pending_withdrawal.nonce,
+        asset: pending_withdrawal.asset,
+        recipient: pending_withdrawal.recipient,
+        amount: pending_withdrawal.amount,
+        signer,
+    });
+
+    Ok(())
+}
@@ -1,13 +1,18 @@
+// Signed outbound instructions carry many arguments, the generated CPI client mirrors them
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
 
 mod contexts;
 mod errors;
+mod events;
 mod instructions;
 mod state;
 mod utils;
 
 pub use contexts::*;
 pub use errors::*;
+pub use events::*;
 pub use state::*;
 pub use utils::DEPOSIT_FEE;
 
@@ -17,6 +22,12 @@ declare_id!("94U5AHQMKkV5txNJ17QPXWoh474PheGou6cNP2FEuL1d");
 #[cfg(not(feature = "dev"))]
 declare_id!("ZETAjseVjuFsxdRxo6MmTCvqFwb3ZHUx56Co3vCmGis");
 
+/// Gateway program of ZetaChain on Solana.
+///
+/// Outbounds are verified against version 2 message hashes only, which commit to the gateway
+/// program id and PDA; version 1 hashes have no compatibility window. Before upgrading a deployed
+/// gateway, TSS stops signing outbounds and every outbound already signed is relayed. Outbounds
+/// still pending after the upgrade are signed again with version 2 hashes for the same nonce.
 #[program]
 pub mod gateway {
     use super::*;
@@ -43,6 +54,7 @@ pub mod gateway {
     /// * `message_hash` - Message hash for signature verification.
     /// * `nonce` - The current nonce value.
     /// * `failure_reason` - The reason for the failure of original outbound.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
     pub fn increment_nonce(
         ctx: Context<IncrementNonce>,
         amount: u64,
@@ -51,6 +63,7 @@ pub mod gateway {
         message_hash: [u8; 32],
         nonce: u64,
         failure_reason: String,
+        expiry: Option<i64>,
     ) -> Result<()> {
         instructions::execute::increment_nonce(
             ctx,
@@ -60,6 +73,7 @@ pub mod gateway {
             message_hash,
             nonce,
             failure_reason,
+            expiry,
         )
     }
 
@@ -73,6 +87,7 @@ pub mod gateway {
     /// * `recovery_id` - Recovery ID of the signature.
     /// * `message_hash` - Hash of the message.
     /// * `nonce` - Nonce of the message.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
     pub fn execute(
         ctx: Context<Execute>,
         amount: u64,
@@ -82,6 +97,7 @@ pub mod gateway {
         recovery_id: u8,
         message_hash: [u8; 32],
         nonce: u64,
+        expiry: Option<i64>,
     ) -> Result<()> {
         instructions::execute::handle_sol(
             ctx,
@@ -92,6 +108,7 @@ pub mod gateway {
             recovery_id,
             message_hash,
             nonce,
+            expiry,
         )
     }
 
@@ -106,6 +123,7 @@ pub mod gateway {
     /// * `recovery_id` - The recovery ID for signature verification.
     /// * `message_hash` - Message hash for signature verification.
     /// * `nonce` - The current nonce value.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
     pub fn execute_revert(
         ctx: Context<Execute>,
         amount: u64,
@@ -115,6 +133,7 @@ pub mod gateway {
         recovery_id: u8,
         message_hash: [u8; 32],
         nonce: u64,
+        expiry: Option<i64>,
     ) -> Result<()> {
         instructions::execute::handle_sol_revert(
             ctx,
@@ -125,6 +144,46 @@ pub mod gateway {
             recovery_id,
             message_hash,
             nonce,
+            expiry,
+        )
+    }
+
+    /// Withdraws amount to destination program pda, and calls on_abort on destination program
+    ///
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of SOL to withdraw.
+    /// * `sender` - Original sender of the aborted transaction.
+    /// * `revert_message` - Revert message of the aborted transaction.
+    /// * `outgoing` - Flag if the aborted transaction was outgoing from ZetaChain.
+    /// * `signature` - The TSS signature.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
+    pub fn execute_abort(
+        ctx: Context<Execute>,
+        amount: u64,
+        sender: Vec<u8>,
+        revert_message: Vec<u8>,
+        outgoing: bool,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+        expiry: Option<i64>,
+    ) -> Result<()> {
+        instructions::execute::handle_sol_abort(
+            ctx,
+            amount,
+            sender,
+            revert_message,
+            outgoing,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+            expiry,
         )
     }
 
@@ -139,6 +198,7 @@ pub mod gateway {
     /// * `recovery_id` - The recovery ID for signature verification.
     /// * `message_hash` - Message hash for signature verification.
     /// * `nonce` - The current nonce value.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
     pub fn execute_spl_token(
         ctx: Context<ExecuteSPLToken>,
         decimals: u8,
@@ -149,6 +209,7 @@ pub mod gateway {
         recovery_id: u8,
         message_hash: [u8; 32],
         nonce: u64,
+        expiry: Option<i64>,
     ) -> Result<()> {
         instructions::execute::handle_spl_token(
             ctx,
@@ -160,6 +221,7 @@ pub mod gateway {
             recovery_id,
             message_hash,
             nonce,
+            expiry,
         )
     }
 
@@ -175,6 +237,7 @@ pub mod gateway {
     /// * `recovery_id` - The recovery ID for signature verification.
     /// * `message_hash` - Message hash for signature verification.
     /// * `nonce` - The current nonce value.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
     pub fn execute_spl_token_revert(
         ctx: Context<ExecuteSPLToken>,
         decimals: u8,
@@ -185,6 +248,7 @@ pub mod gateway {
         recovery_id: u8,
         message_hash: [u8; 32],
         nonce: u64,
+        expiry: Option<i64>,
     ) -> Result<()> {
         instructions::execute::handle_spl_token_revert(
             ctx,
@@ -196,199 +260,900 @@ pub mod gateway {
             recovery_id,
             message_hash,
             nonce,
+            expiry,
         )
     }
 
-    /// Pauses or unpauses deposits. Caller is authority stored in PDA.
-    /// # Arguments
-    /// * `ctx` - The instruction context.
-    /// * `deposit_paused` - Boolean flag to pause or unpause deposits.
-    pub fn set_deposit_paused(ctx: Context<UpdatePaused>, deposit_paused: bool) -> Result<()> {
-        instructions::admin::set_deposit_paused(ctx, deposit_paused)
-    }
-
-    /// Updates the TSS address. Caller is authority stored in PDA.
-    /// # Arguments
-    /// * `ctx` - The instruction context.
-    /// * `tss_address` - The new Ethereum TSS address (20 bytes).
-    pub fn update_tss(ctx: Context<UpdateTss>, tss_address: [u8; 20]) -> Result<()> {
-        instructions::admin::update_tss(ctx, tss_address)
-    }
-
-    /// Updates the PDA authority. Caller is authority stored in PDA.
+    /// Withdraws amount of SPL tokens to destination program pda, and calls on_call on destination program.
+    /// Decimals are read from the mint and committed to in the message hash.
     /// # Arguments
     /// * `ctx` - The instruction context.
-    /// * `new_authority_address` - The new authority's public key.
-    pub fn update_authority(
-        ctx: Context<UpdateAuthority>,
-        new_authority_address: Pubkey,
+    /// * `amount` - The amount of tokens to withdraw.
+    /// * `sender` - Sender from ZEVM.
+    /// * `data` - Data to pass to destination program.
+    /// * `signature` - The TSS signature.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
+    pub fn execute_spl_token_v2(
+        ctx: Context<ExecuteSPLToken>,
+        amount: u64,
+        sender: [u8; 20],
+        data: Vec<u8>,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+        expiry: Option<i64>,
     ) -> Result<()> {
-        instructions::admin::update_authority(ctx, new_authority_address)
+        instructions::execute::handle_spl_token_v2(
+            ctx,
+            amount,
+            sender,
+            data,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+            expiry,
+        )
     }
 
-    /// Resets the PDA nonce. Caller is authority stored in PDA.
+    /// Withdraws SPL token amount to destination program pda, and calls on_revert on destination program.
+    /// Decimals are read from the mint and committed to in the message hash.
     ///
     /// # Arguments
     /// * `ctx` - The instruction context.
-    /// * `new_nonce` - The new nonce.
-    pub fn reset_nonce(ctx: Context<ResetNonce>, new_nonce: u64) -> Result<()> {
-        instructions::admin::reset_nonce(ctx, new_nonce)
-    }
-
-    /// Whitelists a new SPL token. Caller is TSS.
-    /// # Arguments
-    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of tokens to withdraw.
+    /// * `sender` - Sender from ZEVM.
+    /// * `data` - Data to pass to destination program.
     /// * `signature` - The TSS signature.
     /// * `recovery_id` - The recovery ID for signature verification.
     /// * `message_hash` - Message hash for signature verification.
     /// * `nonce` - The current nonce value.
-    pub fn whitelist_spl_mint(
-        ctx: Context<Whitelist>,
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
+    pub fn execute_spl_token_revert_v2(
+        ctx: Context<ExecuteSPLToken>,
+        amount: u64,
+        sender: Pubkey,
+        data: Vec<u8>,
         signature: [u8; 64],
         recovery_id: u8,
         message_hash: [u8; 32],
         nonce: u64,
+        expiry: Option<i64>,
     ) -> Result<()> {
-        instructions::admin::whitelist_spl_mint(ctx, signature, recovery_id, message_hash, nonce)
+        instructions::execute::handle_spl_token_revert_v2(
+            ctx,
+            amount,
+            sender,
+            data,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+            expiry,
+        )
     }
 
-    /// Unwhitelists an SPL token. Caller is TSS.
+    /// Withdraws SPL token amount to destination program pda, and calls on_abort on destination program
+    ///
     /// # Arguments
     /// * `ctx` - The instruction context.
+    /// * `decimals` - Token decimals for precision.
+    /// * `amount` - The amount of tokens to withdraw.
+    /// * `sender` - Original sender of the aborted transaction.
+    /// * `revert_message` - Revert message of the aborted transaction.
+    /// * `outgoing` - Flag if the aborted transaction was outgoing from ZetaChain.
     /// * `signature` - The TSS signature.
     /// * `recovery_id` - The recovery ID for signature verification.
     /// * `message_hash` - Message hash for signature verification.
     /// * `nonce` - The current nonce value.
-    pub fn unwhitelist_spl_mint(
-        ctx: Context<Unwhitelist>,
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute_spl_token_abort(
+        ctx: Context<ExecuteSPLToken>,
+        decimals: u8,
+        amount: u64,
+        sender: Vec<u8>,
+        revert_message: Vec<u8>,
+        outgoing: bool,
         signature: [u8; 64],
         recovery_id: u8,
         message_hash: [u8; 32],
         nonce: u64,
+        expiry: Option<i64>,
     ) -> Result<()> {
-        instructions::admin::unwhitelist_spl_mint(ctx, signature, recovery_id, message_hash, nonce)
+        instructions::execute::handle_spl_token_abort(
+            ctx,
+            decimals,
+            amount,
+            sender,
+            revert_message,
+            outgoing,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+            expiry,
+        )
     }
 
-    /// Deposits SOL into the program and credits the `receiver` on ZetaChain zEVM.
+    /// Invokes an instruction signed by TSS verbatim on destination program.
+    /// No account is passed as signer, amount is moved to recipient only if not zero.
+    ///
     /// # Arguments
     /// * `ctx` - The instruction context.
-    /// * `amount` - The amount of lamports to deposit.
-    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
-    /// * `revert_options` - The revert options created by the caller.
-    pub fn deposit(
-        ctx: Context<Deposit>,
+    /// * `amount` - The amount of SOL to transfer to recipient.
+    /// * `accounts` - Accounts of the invoked instruction with their writable flags.
+    /// * `data` - Data of the invoked instruction.
+    /// * `signature` - The TSS signature.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
+    pub fn execute_arbitrary_call(
+        ctx: Context<ExecuteArbitraryCall>,
         amount: u64,
-        receiver: [u8; 20],
-        revert_options: Option<RevertOptions>,
+        accounts: Vec<ArbitraryCallAccount>,
+        data: Vec<u8>,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+        expiry: Option<i64>,
     ) -> Result<()> {
-        instructions::deposit::handle_sol(ctx, amount, receiver, revert_options, DEPOSIT_FEE)
+        instructions::execute::handle_arbitrary_call(
+            ctx,
+            amount,
+            accounts,
+            data,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+            expiry,
+        )
     }
 
-    /// Deposits SOL and calls a contract on ZetaChain zEVM.
+    /// Calls destination program with the interchain account of a ZEVM sender as signer.
+    /// The interchain account is the gateway PDA derived from the sender address.
+    /// Deposits and calls back to ZEVM are rejected by the execute lock while the destination
+    /// program runs, including those signed by the interchain account; they are sent in a
+    /// separate transaction.
+    ///
     /// # Arguments
     /// * `ctx` - The instruction context.
-    /// * `amount` - The amount of lamports to deposit.
-    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
-    /// * `message` - The message passed to the contract.
-    /// * `revert_options` - The revert options created by the caller.
-    pub fn deposit_and_call(
-        ctx: Context<Deposit>,
-        amount: u64,
-        receiver: [u8; 20],
-        message: Vec<u8>,
-        revert_options: Option<RevertOptions>,
+    /// * `sender` - Sender from ZEVM owning the interchain account.
+    /// * `data` - Data of the invoked instruction.
+    /// * `signature` - The TSS signature.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
+    pub fn execute_as_account(
+        ctx: Context<ExecuteAsAccount>,
+        sender: [u8; 20],
+        data: Vec<u8>,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+        expiry: Option<i64>,
     ) -> Result<()> {
-        instructions::deposit::handle_sol_with_call(
+        instructions::execute::handle_as_account(
             ctx,
-            amount,
-            receiver,
-            message,
-            revert_options,
-            DEPOSIT_FEE,
+            sender,
+            data,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+            expiry,
         )
     }
 
-    /// Deposits SPL tokens and credits the `receiver` on ZetaChain zEVM.
+    /// Calls several destination programs in one outbound, steps are invoked in order
+    /// with their accounts sliced from remaining accounts.
+    /// Steps moving tokens require the mint, its whitelist entry, its rate limit account, the
+    /// gateway ATA and the recipient ATA in remaining accounts.
+    ///
     /// # Arguments
     /// * `ctx` - The instruction context.
-    /// * `amount` - The amount of SPL tokens to deposit.
-    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
-    /// * `revert_options` - The revert options created by the caller.
-    pub fn deposit_spl_token(
-        ctx: Context<DepositSplToken>,
-        amount: u64,
-        receiver: [u8; 20],
-        revert_options: Option<RevertOptions>,
+    /// * `steps` - The ordered steps of the outbound.
+    /// * `signature` - The TSS signature.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
+    pub fn execute_multi<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteMulti<'info>>,
+        steps: Vec<ExecuteStep>,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+        expiry: Option<i64>,
     ) -> Result<()> {
-        instructions::deposit::handle_spl(ctx, amount, receiver, revert_options, DEPOSIT_FEE)
+        instructions::execute::handle_multi(
+            ctx,
+            steps,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+            expiry,
+        )
     }
 
-    /// Deposits SPL tokens and calls a contract on ZetaChain zEVM.
+    /// Writes a chunk of an execute payload into the message buffer of the outbound.
+    ///
     /// # Arguments
     /// * `ctx` - The instruction context.
-    /// * `amount` - The amount of SPL tokens to deposit.
-    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
-    /// * `message` - The message passed to the contract.
-    /// * `revert_options` - The revert options created by the caller.
-    pub fn deposit_spl_token_and_call(
-        ctx: Context<DepositSplToken>,
-        amount: u64,
-        receiver: [u8; 20],
-        message: Vec<u8>,
-        revert_options: Option<RevertOptions>,
+    /// * `nonce` - The nonce of the outbound the payload is staged for.
+    /// * `total_len` - The length of the complete payload.
+    /// * `offset` - The offset of the chunk in the payload.
+    /// * `chunk` - The chunk of the payload.
+    pub fn stage_message(
+        ctx: Context<StageMessage>,
+        nonce: u64,
+        total_len: u32,
+        offset: u32,
+        chunk: Vec<u8>,
     ) -> Result<()> {
-        instructions::deposit::handle_spl_with_call(
-            ctx,
-            amount,
-            receiver,
-            message,
-            revert_options,
-            DEPOSIT_FEE,
-        )
+        instructions::execute::stage_message(ctx, nonce, total_len, offset, chunk)
     }
 
-    /// Calls a contract on ZetaChain zEVM.
+    /// Closes a message buffer that will not be executed, refunding rent to the relayer.
+    ///
     /// # Arguments
-    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
-    /// * `message` - The message passed to the contract.
-    /// * `revert_options` - The revert options created by the caller.
-    pub fn call(
-        ctx: Context<Call>,
-        receiver: [u8; 20],
-        message: Vec<u8>,
-        revert_options: Option<RevertOptions>,
-    ) -> Result<()> {
-        instructions::deposit::handle_call(ctx, receiver, message, revert_options)
+    /// * `ctx` - The instruction context.
+    /// * `nonce` - The nonce of the outbound the payload was staged for.
+    pub fn close_message_buffer(ctx: Context<CloseMessageBuffer>, nonce: u64) -> Result<()> {
+        instructions::execute::close_message_buffer(ctx, nonce)
     }
 
-    /// Withdraws SOL. Caller is TSS.
+    /// Withdraws amount to destination program pda, and calls on_call on destination program
+    /// with the payload staged in the message buffer.
+    ///
     /// # Arguments
     /// * `ctx` - The instruction context.
     /// * `amount` - The amount of SOL to withdraw.
+    /// * `sender` - Sender from ZEVM.
     /// * `signature` - The TSS signature.
     /// * `recovery_id` - The recovery ID for signature verification.
     /// * `message_hash` - Message hash for signature verification.
     /// * `nonce` - The current nonce value.
-    pub fn withdraw(
-        ctx: Context<Withdraw>,
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
+    pub fn execute_from_buffer<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteFromBuffer<'info>>,
         amount: u64,
+        sender: [u8; 20],
         signature: [u8; 64],
         recovery_id: u8,
         message_hash: [u8; 32],
         nonce: u64,
+        expiry: Option<i64>,
     ) -> Result<()> {
-        instructions::withdraw::handle_sol(ctx, amount, signature, recovery_id, message_hash, nonce)
+        instructions::execute::handle_sol_from_buffer(
+            ctx,
+            amount,
+            sender,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+            expiry,
+        )
     }
 
-    /// Withdraws SPL tokens. Caller is TSS.
+    /// Withdraws amount as wrapped SOL to destination program pda ATA, and calls on_call on destination program
     /// # Arguments
     /// * `ctx` - The instruction context.
-    /// * `decimals` - Token decimals for precision.
-    /// * `amount` - The amount of tokens to withdraw.
+    /// * `amount` - The amount of lamports to wrap and transfer.
+    /// * `sender` - Sender from ZEVM.
+    /// * `data` - Data to pass to destination program.
     /// * `signature` - The TSS signature.
     /// * `recovery_id` - The recovery ID for signature verification.
     /// * `message_hash` - Message hash for signature verification.
     /// * `nonce` - The current nonce value.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
+    pub fn execute_wsol(
+        ctx: Context<ExecuteWsol>,
+        amount: u64,
+        sender: [u8; 20],
+        data: Vec<u8>,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+        expiry: Option<i64>,
+    ) -> Result<()> {
+        instructions::execute::handle_wsol(
+            ctx,
+            amount,
+            sender,
+            data,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+            expiry,
+        )
+    }
+
+    /// Withdraws amount as wrapped SOL to destination program pda ATA, and calls on_revert on destination program
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of lamports to wrap and transfer.
+    /// * `sender` - Sender from ZEVM.
+    /// * `data` - Data to pass to destination program.
+    /// * `signature` - The TSS signature.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
+    pub fn execute_wsol_revert(
+        ctx: Context<ExecuteWsol>,
+        amount: u64,
+        sender: Pubkey,
+        data: Vec<u8>,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+        expiry: Option<i64>,
+    ) -> Result<()> {
+        instructions::execute::handle_wsol_revert(
+            ctx,
+            amount,
+            sender,
+            data,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+            expiry,
+        )
+    }
+
+    /// Pauses or unpauses deposits. Caller is authority stored in PDA, a pauser or a guardian
+    /// to pause, authority or an unpauser to unpause.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `deposit_paused` - Boolean flag to pause or unpause deposits.
+    pub fn set_deposit_paused(ctx: Context<UpdatePaused>, deposit_paused: bool) -> Result<()> {
+        instructions::admin::set_deposit_paused(ctx, deposit_paused)
+    }
+
+    /// Pauses deposits and/or outbounds for incident response, unpausing is left to the authority.
+    /// Caller is a guardian, a pauser or authority stored in PDA.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `deposits` - Whether to pause deposits.
+    /// * `outbounds` - Whether to pause withdrawals and executions.
+    pub fn guardian_pause(
+        ctx: Context<GuardianPause>,
+        deposits: bool,
+        outbounds: bool,
+    ) -> Result<()> {
+        instructions::admin::guardian_pause(ctx, deposits, outbounds)
+    }
+
+    /// Updates the TSS address. Caller is authority stored in PDA or a TSS updater.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `tss_address` - The new Ethereum TSS address (20 bytes).
+    pub fn update_tss(ctx: Context<UpdateTss>, tss_address: [u8; 20]) -> Result<()> {
+        instructions::admin::update_tss(ctx, tss_address)
+    }
+
+    /// Updates the PDA authority. Caller is authority stored in PDA.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `new_authority_address` - The new authority's public key.
+    pub fn update_authority(
+        ctx: Context<UpdateAuthority>,
+        new_authority_address: Pubkey,
+    ) -> Result<()> {
+        instructions::admin::update_authority(ctx, new_authority_address)
+    }
+
+    /// Resets the PDA nonce. Caller is authority stored in PDA or a nonce resetter.
+    ///
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `new_nonce` - The new nonce.
+    pub fn reset_nonce(ctx: Context<ResetNonce>, new_nonce: u64) -> Result<()> {
+        instructions::admin::reset_nonce(ctx, new_nonce)
+    }
+
+    /// Grants an administrative role to a key. Caller is authority stored in PDA.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `member` - The key receiving the role.
+    /// * `role` - The role to grant.
+    pub fn grant_role(ctx: Context<UpdateRoles>, member: Pubkey, role: Role) -> Result<()> {
+        instructions::admin::grant_role(ctx, member, role)
+    }
+
+    /// Revokes an administrative role from a key. Caller is authority stored in PDA.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `member` - The key losing the role.
+    /// * `role` - The role to revoke.
+    pub fn revoke_role(ctx: Context<UpdateRoles>, member: Pubkey, role: Role) -> Result<()> {
+        instructions::admin::revoke_role(ctx, member, role)
+    }
+
+    /// Configures the rate limit of an asset. Caller is authority stored in PDA.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `asset` - The mint of the asset, the native mint for SOL.
+    /// * `config` - The rate limit settings.
+    pub fn set_rate_limit(
+        ctx: Context<SetRateLimit>,
+        asset: Pubkey,
+        config: RateLimitConfig,
+    ) -> Result<()> {
+        instructions::admin::set_rate_limit(ctx, asset, config)
+    }
+
+    /// Configures the circuit breaker pausing outbounds when too much custody leaves within
+    /// a rate limit window. Once enabled, outbounds of assets without a rate limit account are rejected.
+    /// Caller is authority stored in PDA.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `circuit_breaker_bps` - Share of custody in basis points, zero disables the circuit breaker.
+    /// * `guardian_reset_delay` - Delay in seconds after a trip before a guardian can reset it.
+    pub fn set_circuit_breaker(
+        ctx: Context<UpdateCircuitBreaker>,
+        circuit_breaker_bps: u16,
+        guardian_reset_delay: i64,
+    ) -> Result<()> {
+        instructions::admin::set_circuit_breaker(ctx, circuit_breaker_bps, guardian_reset_delay)
+    }
+
+    /// Replaces the guardians. Caller is authority stored in PDA.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `guardians` - The guardian keys, at most `MAX_GUARDIANS`.
+    pub fn update_guardians(ctx: Context<UpdateGuardians>, guardians: Vec<Pubkey>) -> Result<()> {
+        instructions::admin::update_guardians(ctx, guardians)
+    }
+
+    /// Resumes outbounds after the circuit breaker tripped.
+    /// Caller is authority stored in PDA, an unpauser, or a guardian once the reset delay has elapsed.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+        instructions::admin::reset_circuit_breaker(ctx)
+    }
+
+    /// Migrates the gateway PDA to the current layout. Caller is authority stored in PDA.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    pub fn migrate_pda(ctx: Context<MigratePda>) -> Result<()> {
+        instructions::admin::migrate_pda(ctx)
+    }
+
+    /// Whitelists a new SPL token. Caller is TSS.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `signature` - The TSS signature.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    pub fn whitelist_spl_mint(
+        ctx: Context<Whitelist>,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+    ) -> Result<()> {
+        instructions::admin::whitelist_spl_mint(ctx, signature, recovery_id, message_hash, nonce)
+    }
+
+    /// Unwhitelists an SPL token. Caller is TSS.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `signature` - The TSS signature.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    pub fn unwhitelist_spl_mint(
+        ctx: Context<Unwhitelist>,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+    ) -> Result<()> {
+        instructions::admin::unwhitelist_spl_mint(ctx, signature, recovery_id, message_hash, nonce)
+    }
+
+    /// Updates the policy of a whitelisted SPL token. Caller is TSS, authority or a whitelist manager.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `config` - The new whitelist entry policy.
+    /// * `signature` - The TSS signature.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    pub fn update_whitelist_entry(
+        ctx: Context<UpdateWhitelistEntry>,
+        config: WhitelistEntryConfig,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+    ) -> Result<()> {
+        instructions::admin::update_whitelist_entry(
+            ctx,
+            config,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+        )
+    }
+
+    /// Sets the registry status of a destination program, allowing or blocking it for executions.
+    /// Caller is TSS or authority stored in PDA.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `program_id` - The destination program.
+    /// * `status` - The registry status of the destination program.
+    /// * `signature` - The TSS signature, zero if signed by authority.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    pub fn set_connected_program(
+        ctx: Context<SetConnectedProgram>,
+        program_id: Pubkey,
+        status: ConnectedProgramStatus,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+    ) -> Result<()> {
+        instructions::admin::set_connected_program(
+            ctx,
+            program_id,
+            status,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+        )
+    }
+
+    /// Declares the custody PDA seed and the hook instruction prefixes invoked on a destination
+    /// program, so programs without the default `connected` PDA and Anchor hooks can be executed.
+    /// Caller is TSS or authority stored in PDA.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `program_id` - The destination program.
+    /// * `config` - The integration settings of the destination program.
+    /// * `signature` - The TSS signature, zero if signed by authority.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    pub fn configure_connected_program(
+        ctx: Context<ConfigureConnectedProgram>,
+        program_id: Pubkey,
+        config: ConnectedProgramConfig,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+    ) -> Result<()> {
+        instructions::admin::configure_connected_program(
+            ctx,
+            program_id,
+            config,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+        )
+    }
+
+    /// Enables or disables restricting executions to destination programs allowed in the registry.
+    /// Caller is authority stored in PDA.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `enabled` - Boolean flag to enable or disable the allowlist.
+    pub fn set_destination_allowlist(
+        ctx: Context<UpdateDestinationAllowlist>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::admin::set_destination_allowlist(ctx, enabled)
+    }
+
+    /// Migrates a legacy whitelist entry to the current layout.
+    /// Caller is authority stored in PDA or a whitelist manager.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    pub fn migrate_whitelist_entry(ctx: Context<MigrateWhitelistEntry>) -> Result<()> {
+        instructions::admin::migrate_whitelist_entry(ctx)
+    }
+
+    /// Deposits SOL into the program and credits the `receiver` on ZetaChain zEVM.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of lamports to deposit.
+    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
+    /// * `revert_options` - The revert options created by the caller.
+    pub fn deposit(
+        ctx: Context<Deposit>,
+        amount: u64,
+        receiver: [u8; 20],
+        revert_options: Option<RevertOptions>,
+    ) -> Result<()> {
+        instructions::deposit::handle_sol(ctx, amount, receiver, revert_options, DEPOSIT_FEE)
+    }
+
+    /// Deposits SOL taken from a source account other than the signer, e.g. a vault PDA signed
+    /// by its program. The signer pays the deposit fee.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of lamports to deposit.
+    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
+    /// * `revert_options` - The revert options created by the caller.
+    pub fn deposit_from_source<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositFromSource<'info>>,
+        amount: u64,
+        receiver: [u8; 20],
+        revert_options: Option<RevertOptions>,
+    ) -> Result<()> {
+        instructions::deposit::handle_sol_from_source(
+            ctx,
+            amount,
+            receiver,
+            revert_options,
+            DEPOSIT_FEE,
+        )
+    }
+
+    /// Deposits the lamports credited to the deposit escrow of the source account.
+    /// Programs owning accounts with data debit them directly into the escrow, then call this
+    /// instruction signing for the source. The signer pays the deposit fee.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
+    /// * `revert_options` - The revert options created by the caller.
+    pub fn deposit_prefunded<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositPrefunded<'info>>,
+        receiver: [u8; 20],
+        revert_options: Option<RevertOptions>,
+    ) -> Result<()> {
+        instructions::deposit::handle_sol_prefunded(ctx, receiver, revert_options, DEPOSIT_FEE)
+    }
+
+    /// Deposits SOL and calls a contract on ZetaChain zEVM.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of lamports to deposit.
+    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
+    /// * `message` - The message passed to the contract.
+    /// * `revert_options` - The revert options created by the caller.
+    pub fn deposit_and_call(
+        ctx: Context<Deposit>,
+        amount: u64,
+        receiver: [u8; 20],
+        message: Vec<u8>,
+        revert_options: Option<RevertOptions>,
+    ) -> Result<()> {
+        instructions::deposit::handle_sol_with_call(
+            ctx,
+            amount,
+            receiver,
+            message,
+            revert_options,
+            DEPOSIT_FEE,
+        )
+    }
+
+    /// Writes a chunk of a deposit message into the deposit buffer of the signer.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `total_len` - The length of the complete message.
+    /// * `offset` - The offset of the chunk in the message.
+    /// * `chunk` - The chunk of the message.
+    pub fn stage_deposit_message(
+        ctx: Context<StageDepositMessage>,
+        total_len: u32,
+        offset: u32,
+        chunk: Vec<u8>,
+    ) -> Result<()> {
+        instructions::deposit::stage_message(ctx, total_len, offset, chunk)
+    }
+
+    /// Closes the deposit buffer of the signer, refunding its rent.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    pub fn close_deposit_buffer(ctx: Context<CloseDepositBuffer>) -> Result<()> {
+        instructions::deposit::close_buffer(ctx)
+    }
+
+    /// Deposits SOL and calls a contract on ZetaChain zEVM with the message staged in the
+    /// deposit buffer of the signer. The buffer is closed after the deposit.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of lamports to deposit.
+    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
+    /// * `revert_options` - The revert options created by the caller.
+    pub fn deposit_and_call_from_buffer<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositFromBuffer<'info>>,
+        amount: u64,
+        receiver: [u8; 20],
+        revert_options: Option<RevertOptions>,
+    ) -> Result<()> {
+        instructions::deposit::handle_sol_with_buffer(
+            ctx,
+            amount,
+            receiver,
+            revert_options,
+            DEPOSIT_FEE,
+        )
+    }
+
+    /// Deposits SPL tokens and credits the `receiver` on ZetaChain zEVM.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of SPL tokens to deposit.
+    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
+    /// * `revert_options` - The revert options created by the caller.
+    pub fn deposit_spl_token(
+        ctx: Context<DepositSplToken>,
+        amount: u64,
+        receiver: [u8; 20],
+        revert_options: Option<RevertOptions>,
+    ) -> Result<()> {
+        instructions::deposit::handle_spl(ctx, amount, receiver, revert_options, DEPOSIT_FEE)
+    }
+
+    /// Deposits SPL tokens and calls a contract on ZetaChain zEVM.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of SPL tokens to deposit.
+    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
+    /// * `message` - The message passed to the contract.
+    /// * `revert_options` - The revert options created by the caller.
+    pub fn deposit_spl_token_and_call(
+        ctx: Context<DepositSplToken>,
+        amount: u64,
+        receiver: [u8; 20],
+        message: Vec<u8>,
+        revert_options: Option<RevertOptions>,
+    ) -> Result<()> {
+        instructions::deposit::handle_spl_with_call(
+            ctx,
+            amount,
+            receiver,
+            message,
+            revert_options,
+            DEPOSIT_FEE,
+        )
+    }
+
+    /// Deposits wrapped SOL and credits the `receiver` on ZetaChain zEVM with SOL.
+    /// Only the deposited amount is unwrapped, the gateway wSOL custody is left untouched.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of wrapped SOL to deposit.
+    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
+    /// * `revert_options` - The revert options created by the caller.
+    pub fn deposit_wsol<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositWsol<'info>>,
+        amount: u64,
+        receiver: [u8; 20],
+        revert_options: Option<RevertOptions>,
+    ) -> Result<()> {
+        instructions::deposit::handle_wsol(ctx, amount, receiver, revert_options, DEPOSIT_FEE)
+    }
+
+    /// Deposits wrapped SOL, credited as SOL, and calls a contract on ZetaChain zEVM.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of wrapped SOL to deposit.
+    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
+    /// * `message` - The message passed to the contract.
+    /// * `revert_options` - The revert options created by the caller.
+    pub fn deposit_wsol_and_call<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositWsol<'info>>,
+        amount: u64,
+        receiver: [u8; 20],
+        message: Vec<u8>,
+        revert_options: Option<RevertOptions>,
+    ) -> Result<()> {
+        instructions::deposit::handle_wsol_with_call(
+            ctx,
+            amount,
+            receiver,
+            message,
+            revert_options,
+            DEPOSIT_FEE,
+        )
+    }
+
+    /// Deposits SPL tokens moved by the owner or an approved delegate of the source token account.
+    /// The signer pays the deposit fee.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of SPL tokens to deposit.
+    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
+    /// * `revert_options` - The revert options created by the caller.
+    pub fn deposit_spl_token_with_authority<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositSplTokenWithAuthority<'info>>,
+        amount: u64,
+        receiver: [u8; 20],
+        revert_options: Option<RevertOptions>,
+    ) -> Result<()> {
+        instructions::deposit::handle_spl_with_authority(
+            ctx,
+            amount,
+            receiver,
+            revert_options,
+            DEPOSIT_FEE,
+        )
+    }
+
+    /// Calls a contract on ZetaChain zEVM.
+    /// Rejected while an execute invokes a destination program.
+    /// # Arguments
+    /// * `receiver` - The Ethereum address of the receiver on ZetaChain zEVM.
+    /// * `message` - The message passed to the contract.
+    /// * `revert_options` - The revert options created by the caller.
+    pub fn call(
+        ctx: Context<Call>,
+        receiver: [u8; 20],
+        message: Vec<u8>,
+        revert_options: Option<RevertOptions>,
+    ) -> Result<()> {
+        instructions::deposit::handle_call(ctx, receiver, message, revert_options)
+    }
+
+    /// Withdraws SOL. Caller is TSS.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of SOL to withdraw.
+    /// * `signature` - The TSS signature.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
+    pub fn withdraw(
+        ctx: Context<Withdraw>,
+        amount: u64,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+        expiry: Option<i64>,
+    ) -> Result<()> {
+        instructions::withdraw::handle_sol(
+            ctx,
+            amount,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+            expiry,
+        )
+    }
+
+    /// Withdraws SPL tokens. Caller is TSS.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `decimals` - Token decimals for precision.
+    /// * `amount` - The amount of tokens to withdraw.
+    /// * `signature` - The TSS signature.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
     pub fn withdraw_spl_token(
         ctx: Context<WithdrawSPLToken>,
         decimals: u8,
@@ -397,6 +1162,7 @@ pub mod gateway {
         recovery_id: u8,
         message_hash: [u8; 32],
         nonce: u64,
+        expiry: Option<i64>,
     ) -> Result<()> {
         instructions::withdraw::handle_spl(
             ctx,
@@ -406,6 +1172,84 @@ pub mod gateway {
             recovery_id,
             message_hash,
             nonce,
+            expiry,
+        )
+    }
+
+    /// Withdraws SPL tokens with decimals read from the mint and committed to in the message hash.
+    /// Caller is TSS.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of tokens to withdraw.
+    /// * `signature` - The TSS signature.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
+    pub fn withdraw_spl_token_v2(
+        ctx: Context<WithdrawSPLToken>,
+        amount: u64,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+        expiry: Option<i64>,
+    ) -> Result<()> {
+        instructions::withdraw::handle_spl_v2(
+            ctx,
+            amount,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+            expiry,
+        )
+    }
+
+    /// Pays out a withdrawal escrowed above the delay threshold once its settlement delay has elapsed.
+    /// Other queued outbounds are carried out by resubmitting their message after the delay.
+    /// Caller is anyone.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    pub fn finalize_withdrawal(ctx: Context<FinalizeWithdrawal>) -> Result<()> {
+        instructions::withdraw::finalize(ctx)
+    }
+
+    /// Cancels a queued withdrawal for revert, returning any escrow to custody and its amount to the
+    /// rate limit. The pending withdrawal is closed to its payer.
+    /// Caller is authority stored in PDA, a pauser or a guardian.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+        instructions::withdraw::cancel(ctx)
+    }
+
+    /// Withdraws SOL as wrapped SOL into the recipient's associated token account. Caller is TSS.
+    /// # Arguments
+    /// * `ctx` - The instruction context.
+    /// * `amount` - The amount of lamports to wrap and withdraw.
+    /// * `signature` - The TSS signature.
+    /// * `recovery_id` - The recovery ID for signature verification.
+    /// * `message_hash` - Message hash for signature verification.
+    /// * `nonce` - The current nonce value.
+    /// * `expiry` - Optional unix timestamp after which the message is rejected.
+    pub fn withdraw_wsol(
+        ctx: Context<WithdrawWsol>,
+        amount: u64,
+        signature: [u8; 64],
+        recovery_id: u8,
+        message_hash: [u8; 32],
+        nonce: u64,
+        expiry: Option<i64>,
+    ) -> Result<()> {
+        instructions::withdraw::handle_wsol(
+            ctx,
+            amount,
+            signature,
+            recovery_id,
+            message_hash,
+            nonce,
+            expiry,
         )
     }
 
@@ -1,5 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::Errors;
+use crate::utils::{
+    MAX_CUSTODY_SEED_LEN, MAX_GUARDIANS, MAX_ROLE_MEMBERS, MESSAGE_CONTEXT_VERSION,
+    MESSAGE_HASH_V2_FLAG, ON_ABORT_DISCRIMINATOR, ON_CALL_DISCRIMINATOR, ON_REVERT_DISCRIMINATOR,
+};
+
 /// PDA account storing program state and settings.
 #[account]
 pub struct Pda {
@@ -13,11 +19,444 @@ pub struct Pda {
     pub chain_id: u64,
     /// Flag to indicate whether deposits are paused.
     pub deposit_paused: bool,
+    /// Flag to indicate whether outbounds are paused.
+    pub outbound_paused: bool,
+    /// Share of custody in basis points that may leave within a rate limit window
+    /// before outbounds are paused, zero disables the circuit breaker.
+    pub circuit_breaker_bps: u16,
+    /// Delay in seconds after a trip before a guardian can reset the circuit breaker.
+    pub guardian_reset_delay: i64,
+    /// Unix timestamp at which the circuit breaker tripped, zero if not tripped.
+    pub circuit_breaker_tripped_at: i64,
+    /// The asset whose outflow tripped the circuit breaker.
+    pub circuit_breaker_asset: Pubkey,
+    /// Guardian keys, unused slots are the default key.
+    pub guardians: [Pubkey; MAX_GUARDIANS],
+    /// Flag to restrict executions to destination programs allowed in the registry.
+    pub destination_allowlist_enabled: bool,
+    /// Flag set while a destination program is invoked by execute, deposits and calls are rejected meanwhile.
+    pub execute_locked: bool,
+}
+
+impl Pda {
+    /// Returns true if `key` is a registered guardian.
+    pub fn is_guardian(&self, key: &Pubkey) -> bool {
+        *key != Pubkey::default() && self.guardians.contains(key)
+    }
 }
 
 /// Whitelist entry account for whitelisted SPL tokens.
 #[account]
-pub struct WhitelistEntry {}
+pub struct WhitelistEntry {
+    /// The token program owning the mint.
+    pub token_program: Pubkey,
+    /// The mint decimals captured when the entry was created or updated.
+    pub decimals: u8,
+    /// The ZRC20 address associated with the mint on ZetaChain.
+    pub zrc20: [u8; 20],
+    /// The minimum amount accepted by deposits.
+    pub min_deposit_amount: u64,
+    /// Flag to indicate whether deposits of the mint are paused.
+    pub deposit_paused: bool,
+    /// Flag to indicate whether withdrawals of the mint are paused.
+    pub withdraw_paused: bool,
+    /// Deposit fee in lamports overriding the default deposit fee.
+    pub fee_override: Option<u64>,
+}
+
+/// Configurable policy of a whitelist entry.
+/// # Arguments
+/// * `zrc20` The ZRC20 address associated with the mint on ZetaChain.
+/// * `min_deposit_amount` The minimum amount accepted by deposits.
+/// * `deposit_paused` Flag to pause deposits of the mint.
+/// * `withdraw_paused` Flag to pause withdrawals of the mint.
+/// * `fee_override` Deposit fee in lamports overriding the default deposit fee.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct WhitelistEntryConfig {
+    pub zrc20: [u8; 20],
+    pub min_deposit_amount: u64,
+    pub deposit_paused: bool,
+    pub withdraw_paused: bool,
+    pub fee_override: Option<u64>,
+}
+
+impl WhitelistEntryConfig {
+    /// Encodes the config for message hashes, integers are big endian.
+    pub fn to_message_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(20 + 8 + 1 + 1 + 9);
+        buf.extend_from_slice(&self.zrc20);
+        buf.extend_from_slice(&self.min_deposit_amount.to_be_bytes());
+        buf.push(self.deposit_paused as u8);
+        buf.push(self.withdraw_paused as u8);
+        match self.fee_override {
+            Some(fee) => {
+                buf.push(1);
+                buf.extend_from_slice(&fee.to_be_bytes());
+            }
+            None => buf.push(0),
+        }
+        buf
+    }
+}
+
+/// Rate limit account tracking value moved through the gateway for an asset.
+/// SOL is tracked under the native mint.
+#[account]
+pub struct RateLimit {
+    /// Length of a window in seconds.
+    pub window_duration: i64,
+    /// Maximum amount withdrawn or executed within a window, zero disables the limit.
+    pub outbound_limit: u64,
+    /// Maximum amount deposited within a window, zero disables the limit.
+    pub inbound_limit: u64,
+    /// Share of a limit in basis points above which a warning event is emitted, zero disables warnings.
+    pub warning_threshold_bps: u16,
+    /// Unix timestamp at which the current window started.
+    pub window_start: i64,
+    /// Amount withdrawn or executed in the current window.
+    pub outbound_used: u64,
+    /// Amount deposited in the current window.
+    pub inbound_used: u64,
+    /// Withdrawals above this amount are queued for delayed settlement, zero disables the queue.
+    pub delay_threshold: u64,
+    /// Delay in seconds before a queued withdrawal can be finalized.
+    pub settlement_delay: i64,
+}
+
+/// Struct containing rate limit settings
+/// # Arguments
+/// * `window_duration` Length of a window in seconds.
+/// * `outbound_limit` Maximum amount withdrawn or executed within a window, zero disables the limit.
+/// * `inbound_limit` Maximum amount deposited within a window, zero disables the limit.
+/// * `warning_threshold_bps` Share of a limit in basis points above which a warning event is emitted.
+/// * `delay_threshold` Withdrawals above this amount are queued for delayed settlement, zero disables the queue.
+/// * `settlement_delay` Delay in seconds before a queued withdrawal can be finalized.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct RateLimitConfig {
+    pub window_duration: i64,
+    pub outbound_limit: u64,
+    pub inbound_limit: u64,
+    pub warning_threshold_bps: u16,
+    pub delay_threshold: u64,
+    pub settlement_delay: i64,
+}
+
+/// Outbound above the delay threshold, held until its settlement delay has elapsed.
+/// Withdrawals are escrowed and paid out by finalize, other outbounds are carried out
+/// by resubmitting their signed message.
+#[account]
+pub struct PendingWithdrawal {
+    /// The nonce of the withdrawal, used as the account seed.
+    pub nonce: u64,
+    /// The asset being withdrawn, the native mint for SOL.
+    pub asset: Pubkey,
+    /// The recipient of the withdrawal, the token account owner for SPL tokens.
+    pub recipient: Pubkey,
+    /// The amount being withdrawn.
+    pub amount: u64,
+    /// The decimals of the asset.
+    pub decimals: u8,
+    /// The account that paid the rent for the pending withdrawal and its escrow.
+    pub payer: Pubkey,
+    /// Unix timestamp after which the withdrawal can be finalized.
+    pub executable_at: i64,
+    /// Unix timestamp at which the withdrawal was queued.
+    pub queued_at: i64,
+    /// The message hash signed by TSS for the outbound.
+    pub message_hash: [u8; 32],
+    /// Flag to indicate the funds are escrowed in the pending withdrawal and paid out by finalize.
+    pub escrowed: bool,
+}
+
+/// Execute payload staged in chunks by a relayer, used for payloads not fitting in a transaction.
+#[account]
+pub struct MessageBuffer {
+    /// The relayer staging the payload, refunded when the buffer is closed.
+    pub relayer: Pubkey,
+    /// The nonce of the outbound, used as the account seed.
+    pub nonce: u64,
+    /// The staged payload.
+    pub data: Vec<u8>,
+}
+
+/// Deposit message staged in chunks by a depositor, used for messages over the deposit payload size.
+#[account]
+pub struct DepositBuffer {
+    /// The depositor staging the message, refunded when the buffer is closed.
+    pub owner: Pubkey,
+    /// The staged message.
+    pub data: Vec<u8>,
+}
+
+/// Registry status of a destination program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectedProgramStatus {
+    /// Treated as if not registered.
+    Unlisted,
+    /// Can be executed when the allowlist is enabled.
+    Allowed,
+    /// Can never be executed.
+    Blocked,
+}
+
+/// Registry entry of a destination program, created by authority or TSS.
+#[account]
+pub struct ConnectedProgram {
+    /// The destination program.
+    pub program_id: Pubkey,
+    /// The registry status of the destination program.
+    pub status: ConnectedProgramStatus,
+    /// Seed of the PDA receiving funds for the destination program, padded with zeros.
+    pub custody_seed: [u8; MAX_CUSTODY_SEED_LEN],
+    /// Length of the custody seed, zero for the default `connected` seed.
+    pub custody_seed_len: u8,
+    /// Bump of the custody PDA derived from the custody seed.
+    pub custody_bump: u8,
+    /// Instruction prefix invoked on calls, padded with zeros.
+    pub on_call_discriminator: [u8; 8],
+    /// Instruction prefix invoked on reverts, padded with zeros.
+    pub on_revert_discriminator: [u8; 8],
+    /// Length of the instruction prefixes, 8 for Anchor discriminators, shorter for raw
+    /// instruction tags, zero for the default `on_call` and `on_revert` hooks.
+    pub discriminator_len: u8,
+    /// Version of the message context appended to hook arguments, zero for the legacy layout.
+    pub context_version: u8,
+    /// Instruction prefix invoked on aborts, padded with zeros.
+    pub on_abort_discriminator: [u8; 8],
+}
+
+impl ConnectedProgram {
+    /// Returns the custody PDA seed, `None` for the default seed.
+    pub fn custody_seed(&self) -> Option<&[u8]> {
+        (self.custody_seed_len > 0).then(|| &self.custody_seed[..self.custody_seed_len as usize])
+    }
+}
+
+/// Struct containing the integration settings of a connected program
+/// # Arguments
+/// * `custody_seed` Seed of the PDA receiving funds, empty for the default `connected` seed.
+/// * `on_call_discriminator` Instruction prefix invoked on calls.
+/// * `on_revert_discriminator` Instruction prefix invoked on reverts.
+/// * `discriminator_len` Length of the instruction prefixes, zero for the default hooks.
+/// * `context_version` Version of the message context passed to the hooks, zero for the legacy layout.
+/// * `on_abort_discriminator` Instruction prefix invoked on aborts.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ConnectedProgramConfig {
+    pub custody_seed: Vec<u8>,
+    pub on_call_discriminator: [u8; 8],
+    pub on_revert_discriminator: [u8; 8],
+    pub discriminator_len: u8,
+    pub context_version: u8,
+    pub on_abort_discriminator: [u8; 8],
+}
+
+impl ConnectedProgramConfig {
+    /// Serializes the config for TSS message hashes.
+    pub fn to_message_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(1 + self.custody_seed.len() + 8 + 8 + 1 + 1 + 8);
+        buf.push(self.custody_seed.len() as u8);
+        buf.extend_from_slice(&self.custody_seed);
+        buf.extend_from_slice(&self.on_call_discriminator);
+        buf.extend_from_slice(&self.on_revert_discriminator);
+        buf.push(self.discriminator_len);
+        buf.push(self.context_version);
+        buf.extend_from_slice(&self.on_abort_discriminator);
+        buf
+    }
+}
+
+/// Administrative roles that can be granted in addition to the PDA authority.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// Can pause deposits.
+    Pauser,
+    /// Can unpause deposits and reset the circuit breaker.
+    Unpauser,
+    /// Can whitelist, unwhitelist and update the policy of SPL tokens.
+    WhitelistManager,
+    /// Can update the fee override of whitelisted SPL tokens.
+    FeeManager,
+    /// Can update the TSS address.
+    TssUpdater,
+    /// Can reset the PDA nonce.
+    NonceResetter,
+}
+
+impl Role {
+    /// Returns the bit of the role in a member's role mask.
+    pub fn mask(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Key holding a set of roles.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct RoleMember {
+    /// The key holding the roles, the default key for unused slots.
+    pub key: Pubkey,
+    /// Bit mask of the roles held by the key.
+    pub roles: u8,
+}
+
+/// Roles account granting administrative roles to keys other than the PDA authority.
+#[account]
+pub struct Roles {
+    /// The keys holding roles.
+    pub members: [RoleMember; MAX_ROLE_MEMBERS],
+}
+
+impl Roles {
+    /// Returns true if `key` holds `role`.
+    pub fn has_role(&self, key: &Pubkey, role: Role) -> bool {
+        *key != Pubkey::default()
+            && self
+                .members
+                .iter()
+                .any(|member| member.key == *key && member.roles & role.mask() != 0)
+    }
+
+    /// Grants `role` to `key`, taking a free slot if the key holds no role yet.
+    pub fn grant(&mut self, key: Pubkey, role: Role) -> Result<()> {
+        if let Some(member) = self.members.iter_mut().find(|member| member.key == key) {
+            member.roles |= role.mask();
+            return Ok(());
+        }
+
+        let member = self
+            .members
+            .iter_mut()
+            .find(|member| member.key == Pubkey::default())
+            .ok_or(Errors::TooManyRoleMembers)?;
+        member.key = key;
+        member.roles = role.mask();
+        Ok(())
+    }
+
+    /// Revokes `role` from `key`, freeing the slot once the key holds no role.
+    pub fn revoke(&mut self, key: Pubkey, role: Role) {
+        if let Some(member) = self.members.iter_mut().find(|member| member.key == key) {
+            member.roles &= !role.mask();
+            if member.roles == 0 {
+                *member = RoleMember::default();
+            }
+        }
+    }
+}
+
+/// Direction of value moved through the gateway.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlowDirection {
+    Inbound,
+    Outbound,
+}
+
+/// Outbound details passed to connected programs after the legacy hook arguments.
+/// # Arguments
+/// * `version` Version of the context layout.
+/// * `asset` Mint of the transferred asset, the native mint for SOL.
+/// * `decimals` Decimals of the transferred asset.
+/// * `nonce` Nonce of the outbound, identifies the cross-chain transaction.
+/// * `chain_id` Chain id of this gateway, the local chain the outbound was signed for and not the
+///   chain the cross-chain transaction originated from.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct MessageContext {
+    pub version: u8,
+    pub asset: Pubkey,
+    pub decimals: u8,
+    pub nonce: u64,
+    pub chain_id: u64,
+}
+
+impl MessageContext {
+    pub fn new(asset: Pubkey, decimals: u8, nonce: u64, chain_id: u64) -> Self {
+        Self {
+            version: MESSAGE_CONTEXT_VERSION,
+            asset,
+            decimals,
+            nonce,
+            chain_id,
+        }
+    }
+}
+
+/// Details of an aborted cross-chain transaction passed to the on_abort hook.
+/// # Arguments
+/// * `sender` Original sender of the aborted transaction.
+/// * `asset` Mint of the transferred asset, the native mint for SOL.
+/// * `amount` Amount of the asset transferred to the abort address.
+/// * `outgoing` Flag if the aborted transaction was outgoing from ZetaChain.
+/// * `revert_message` Revert message of the aborted transaction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct AbortContext {
+    pub sender: Vec<u8>,
+    pub asset: Pubkey,
+    pub amount: u64,
+    pub outgoing: bool,
+    pub revert_message: Vec<u8>,
+}
+
+/// Account of an instruction invoked by an arbitrary call
+/// # Arguments
+/// * `pubkey` The account address.
+/// * `is_writable` Flag if the account is writable.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ArbitraryCallAccount {
+    pub pubkey: Pubkey,
+    pub is_writable: bool,
+}
+
+/// Step of a multi-step execute
+/// # Arguments
+/// * `program_id` The program invoked by the step.
+/// * `accounts_start` Index of the first account of the step in remaining accounts.
+/// * `accounts_len` Number of accounts of the step.
+/// * `data` Data of the invoked instruction.
+/// * `amount` Lamports moved to the first account of the step before the call.
+/// * `token` Tokens moved from the gateway ATA to the ATA of the first account of the step before the call.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ExecuteStep {
+    pub program_id: Pubkey,
+    pub accounts_start: u8,
+    pub accounts_len: u8,
+    pub data: Vec<u8>,
+    pub amount: u64,
+    pub token: Option<ExecuteStepToken>,
+}
+
+/// Tokens moved by a step of a multi-step execute
+/// # Arguments
+/// * `mint` The mint of the tokens.
+/// * `decimals` Decimals of the mint.
+/// * `amount` Amount of tokens moved.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ExecuteStepToken {
+    pub mint: Pubkey,
+    pub decimals: u8,
+    pub amount: u64,
+}
+
+impl ExecuteStep {
+    /// Serializes the step for TSS message hashes.
+    pub fn to_message_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(32 + 1 + 1 + 8 + 42 + 4 + self.data.len());
+        buf.extend_from_slice(self.program_id.as_ref());
+        buf.push(self.accounts_start);
+        buf.push(self.accounts_len);
+        buf.extend_from_slice(&self.amount.to_be_bytes());
+        match &self.token {
+            Some(token) => {
+                buf.push(1);
+                buf.extend_from_slice(token.mint.as_ref());
+                buf.push(token.decimals);
+                buf.extend_from_slice(&token.amount.to_be_bytes());
+            }
+            None => buf.push(0),
+        }
+        buf.extend_from_slice(&(self.data.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&self.data);
+        buf
+    }
+}
 
 #[repr(C)]
 #[derive(Clone, Debug, PartialEq)]
@@ -32,45 +471,140 @@ pub enum CallableInstruction {
         sender: Pubkey,
         data: Vec<u8>,
     },
+    OnCallWithContext {
+        amount: u64,
+        sender: [u8; 20],
+        data: Vec<u8>,
+        context: MessageContext,
+    },
+    OnRevertWithContext {
+        amount: u64,
+        sender: Pubkey,
+        data: Vec<u8>,
+        context: MessageContext,
+    },
+    OnAbort {
+        context: AbortContext,
+    },
 }
 
 impl CallableInstruction {
     pub fn pack(&self) -> Vec<u8> {
+        match self {
+            CallableInstruction::OnCall { .. } | CallableInstruction::OnCallWithContext { .. } => {
+                self.pack_with_discriminator(&ON_CALL_DISCRIMINATOR)
+            }
+            CallableInstruction::OnRevert { .. }
+            | CallableInstruction::OnRevertWithContext { .. } => {
+                self.pack_with_discriminator(&ON_REVERT_DISCRIMINATOR)
+            }
+            CallableInstruction::OnAbort { .. } => {
+                self.pack_with_discriminator(&ON_ABORT_DISCRIMINATOR)
+            }
+        }
+    }
+
+    /// Attaches the message context if the destination program opted into it in its
+    /// registry entry, keeps the legacy layout otherwise.
+    pub fn with_context(
+        self,
+        context: MessageContext,
+        connected_program: Option<&ConnectedProgram>,
+    ) -> Self {
+        if connected_program.map_or(0, |entry| entry.context_version) == 0 {
+            return self;
+        }
         match self {
             CallableInstruction::OnCall {
                 amount,
                 sender,
                 data,
-            } => {
-                let discriminator = [16, 136, 66, 32, 254, 40, 181, 8]; // on_call
-                let data_len = data.len() as u32;
-
-                let mut buf = Vec::with_capacity(8 + 8 + 20 + 4 + data.len());
-                buf.extend_from_slice(&discriminator);
-                buf.extend_from_slice(&amount.to_le_bytes());
-                buf.extend_from_slice(sender);
-                buf.extend_from_slice(&data_len.to_le_bytes());
-                buf.extend_from_slice(data);
-                buf
-            }
+            } => CallableInstruction::OnCallWithContext {
+                amount,
+                sender,
+                data,
+                context,
+            },
             CallableInstruction::OnRevert {
                 amount,
                 sender,
                 data,
-            } => {
-                let discriminator = [226, 44, 101, 52, 224, 214, 41, 9]; // on_revert
-                let data_len = data.len() as u32;
-
-                let mut buf = Vec::with_capacity(8 + 8 + 32 + 4 + data.len());
-                buf.extend_from_slice(&discriminator);
-                buf.extend_from_slice(&amount.to_le_bytes());
-                buf.extend_from_slice(sender.as_ref());
-                buf.extend_from_slice(&data_len.to_le_bytes());
-                buf.extend_from_slice(data);
-                buf
+            } => CallableInstruction::OnRevertWithContext {
+                amount,
+                sender,
+                data,
+                context,
+            },
+            instruction => instruction,
+        }
+    }
+
+    /// Packs the instruction for a destination program, using the hooks declared in its
+    /// registry entry if any.
+    pub fn pack_for(&self, connected_program: Option<&ConnectedProgram>) -> Vec<u8> {
+        let Some(entry) = connected_program.filter(|entry| entry.discriminator_len > 0) else {
+            return self.pack();
+        };
+        let len = entry.discriminator_len as usize;
+        match self {
+            CallableInstruction::OnCall { .. } | CallableInstruction::OnCallWithContext { .. } => {
+                self.pack_with_discriminator(&entry.on_call_discriminator[..len])
+            }
+            CallableInstruction::OnRevert { .. }
+            | CallableInstruction::OnRevertWithContext { .. } => {
+                self.pack_with_discriminator(&entry.on_revert_discriminator[..len])
+            }
+            CallableInstruction::OnAbort { .. } => {
+                self.pack_with_discriminator(&entry.on_abort_discriminator[..len])
             }
         }
     }
+
+    /// Packs the instruction arguments behind the given instruction prefix.
+    pub fn pack_with_discriminator(&self, discriminator: &[u8]) -> Vec<u8> {
+        let (amount, sender, data, context) = match self {
+            CallableInstruction::OnAbort { context } => {
+                let mut buf = discriminator.to_vec();
+                buf.extend_from_slice(&context.try_to_vec().unwrap_or_default());
+                return buf;
+            }
+            CallableInstruction::OnCall {
+                amount,
+                sender,
+                data,
+            } => (amount, &sender[..], data, None),
+            CallableInstruction::OnRevert {
+                amount,
+                sender,
+                data,
+            } => (amount, sender.as_ref(), data, None),
+            CallableInstruction::OnCallWithContext {
+                amount,
+                sender,
+                data,
+                context,
+            } => (amount, &sender[..], data, Some(context)),
+            CallableInstruction::OnRevertWithContext {
+                amount,
+                sender,
+                data,
+                context,
+            } => (amount, sender.as_ref(), data, Some(context)),
+        };
+        let data_len = data.len() as u32;
+
+        let mut buf =
+            Vec::with_capacity(discriminator.len() + 8 + sender.len() + 4 + data.len() + 50);
+        buf.extend_from_slice(discriminator);
+        buf.extend_from_slice(&amount.to_le_bytes());
+        buf.extend_from_slice(sender);
+        buf.extend_from_slice(&data_len.to_le_bytes());
+        buf.extend_from_slice(data);
+        if let Some(context) = context {
+            buf.extend_from_slice(&context.try_to_vec().unwrap_or_default());
+        }
+        buf
+    }
 }
 
 /// Struct containing revert options
@@ -101,4 +635,26 @@ pub enum InstructionId {
     IncrementNonce = 7,
     ExecuteSolRevert = 8,
     ExecuteSplTokenRevert = 9,
+    WithdrawWsol = 10,
+    ExecuteWsol = 11,
+    UpdateWhitelistEntry = 12,
+    SetConnectedProgram = 13,
+    ConfigureConnectedProgram = 14,
+    ExecuteSolAbort = 15,
+    ExecuteSplTokenAbort = 16,
+    ExecuteArbitraryCall = 17,
+    ExecuteAsAccount = 18,
+    ExecuteMulti = 19,
+    ExecuteFromBuffer = 20,
+    WithdrawSplTokenV2 = 21,
+    ExecuteSplTokenV2 = 22,
+    ExecuteSplTokenRevertV2 = 23,
+    ExecuteWsolRevert = 24,
+}
+
+impl InstructionId {
+    /// Returns the identifier of the instruction in version 2 message hashes.
+    pub fn hash_id(self) -> u8 {
+        self as u8 | MESSAGE_HASH_V2_FLAG
+    }
 }
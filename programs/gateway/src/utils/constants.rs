@@ -9,3 +9,43 @@ pub const MAX_DEPOSIT_PAYLOAD_SIZE: usize = 745;
 
 /// Deposit fee used when depositing SOL or SPL tokens.
 pub const DEPOSIT_FEE: u64 = 2_000_000;
+
+/// Maximum number of guardian keys stored in the gateway PDA.
+pub const MAX_GUARDIANS: usize = 5;
+
+/// Maximum length of the custody PDA seed declared by a connected program.
+pub const MAX_CUSTODY_SEED_LEN: usize = 32;
+
+/// Seed of the custody PDA of connected programs without a declared seed.
+pub const DEFAULT_CUSTODY_SEED: &[u8] = b"connected";
+
+/// Discriminator of the Anchor `on_call` hook of connected programs.
+pub const ON_CALL_DISCRIMINATOR: [u8; 8] = [16, 136, 66, 32, 254, 40, 181, 8];
+
+/// Discriminator of the Anchor `on_revert` hook of connected programs.
+pub const ON_REVERT_DISCRIMINATOR: [u8; 8] = [226, 44, 101, 52, 224, 214, 41, 9];
+
+/// Discriminator of the Anchor `on_abort` hook of connected programs.
+pub const ON_ABORT_DISCRIMINATOR: [u8; 8] = [146, 220, 181, 126, 232, 100, 188, 21];
+
+/// Maximum number of keys holding roles in the roles account.
+pub const MAX_ROLE_MEMBERS: usize = 16;
+
+/// Current version of the message context passed to connected programs.
+pub const MESSAGE_CONTEXT_VERSION: u8 = 1;
+
+/// Maximum size of the destination program return data forwarded by execute.
+pub const MAX_EXECUTE_RETURN_DATA_SIZE: usize = 256;
+
+/// Maximum number of steps of a multi-step execute.
+pub const MAX_EXECUTE_STEPS: usize = 8;
+
+/// Maximum size of a payload staged in a message or deposit buffer.
+pub const MAX_MESSAGE_BUFFER_SIZE: u32 = 9_000;
+
+/// Maximum number of remaining accounts passed to a destination program.
+pub const MAX_REMAINING_ACCOUNTS: usize = 96;
+
+/// Flag set on instruction ids in version 2 message hashes, which commit to the
+/// gateway program id and PDA address.
+pub const MESSAGE_HASH_V2_FLAG: u8 = 0x80;
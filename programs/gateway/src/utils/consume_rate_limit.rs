@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::Errors;
+use crate::events::RateLimitWarning;
+use crate::state::{FlowDirection, RateLimit};
+
+/// Records a flow against the rate limit of an asset, reverts if the limit is exceeded.
+/// Assets without an initialized rate limit account are not limited.
+///
+/// # Arguments
+///
+/// * `rate_limit` - The rate limit account of the asset, may be uninitialized
+/// * `asset` - The asset being moved, the native mint for SOL
+/// * `direction` - The direction of the flow
+/// * `amount` - The amount being moved
+///
+/// # Returns
+///
+/// * `Result<Option<u64>>` - The amount moved in the flow direction within the current window,
+///   `None` if the asset has no rate limit account
+pub fn consume_rate_limit(
+    rate_limit: &AccountInfo,
+    asset: Pubkey,
+    direction: FlowDirection,
+    amount: u64,
+) -> Result<Option<u64>> {
+    if *rate_limit.owner != crate::ID {
+        return Ok(None);
+    }
+
+    let mut state = RateLimit::try_deserialize(&mut &rate_limit.try_borrow_data()?[..])?;
+    let now = Clock::get()?.unix_timestamp;
+
+    let crossed_warning = record_flow(&mut state, direction, amount, now)?;
+    let (used, limit) = match direction {
+        FlowDirection::Inbound => (state.inbound_used, state.inbound_limit),
+        FlowDirection::Outbound => (state.outbound_used, state.outbound_limit),
+    };
+    if crossed_warning {
+        emit!(RateLimitWarning {
+            asset,
+            direction,
+            used,
+            limit,
+        });
+    }
+
+    state.try_serialize(&mut &mut rate_limit.try_borrow_mut_data()?[..])?;
+
+    Ok(Some(used))
+}
+
+/// Starts a new window if the current one has elapsed, and adds `amount` to the usage.
+/// Returns true if the usage crossed the warning threshold.
+pub fn record_flow(
+    state: &mut RateLimit,
+    direction: FlowDirection,
+    amount: u64,
+    now: i64,
+) -> Result<bool> {
+    if now >= state.window_start.saturating_add(state.window_duration) {
+        state.window_start = now;
+        state.inbound_used = 0;
+        state.outbound_used = 0;
+    }
+
+    let (used, limit) = match direction {
+        FlowDirection::Inbound => (&mut state.inbound_used, state.inbound_limit),
+        FlowDirection::Outbound => (&mut state.outbound_used, state.outbound_limit),
+    };
+
+    let previous = *used;
+    *used = used.checked_add(amount).ok_or(Errors::RateLimitExceeded)?;
+
+    if limit == 0 {
+        return Ok(false);
+    }
+    require!(*used <= limit, Errors::RateLimitExceeded);
+
+    let warning = (limit as u128) * (state.warning_threshold_bps as u128) / 10_000;
+    Ok(state.warning_threshold_bps > 0
+        && (previous as u128) < warning
+        && (*used as u128) >= warning)
+}
+
+/// Returns a flow recorded at `recorded_at` to the rate limit of an asset.
+/// Flows recorded before the current window no longer count towards the limit and are ignored.
+///
+/// # Arguments
+///
+/// * `rate_limit` - The rate limit account of the asset, may be uninitialized
+/// * `direction` - The direction of the flow
+/// * `amount` - The amount of the flow
+/// * `recorded_at` - The unix timestamp at which the flow was recorded
+pub fn refund_rate_limit(
+    rate_limit: &AccountInfo,
+    direction: FlowDirection,
+    amount: u64,
+    recorded_at: i64,
+) -> Result<()> {
+    if *rate_limit.owner != crate::ID {
+        return Ok(());
+    }
+
+    let mut state = RateLimit::try_deserialize(&mut &rate_limit.try_borrow_data()?[..])?;
+    refund_flow(&mut state, direction, amount, recorded_at);
+    state.try_serialize(&mut &mut rate_limit.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+/// Subtracts `amount` from the usage if the flow was recorded in the current window.
+pub fn refund_flow(state: &mut RateLimit, direction: FlowDirection, amount: u64, recorded_at: i64) {
+    if recorded_at < state.window_start {
+        return;
+    }
+
+    let used = match direction {
+        FlowDirection::Inbound => &mut state.inbound_used,
+        FlowDirection::Outbound => &mut state.outbound_used,
+    };
+    *used = used.saturating_sub(amount);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_limit(outbound_limit: u64, warning_threshold_bps: u16) -> RateLimit {
+        RateLimit {
+            window_duration: 100,
+            outbound_limit,
+            inbound_limit: 0,
+            warning_threshold_bps,
+            window_start: 1_000,
+            outbound_used: 0,
+            inbound_used: 0,
+            delay_threshold: 0,
+            settlement_delay: 0,
+        }
+    }
+
+    #[test]
+    fn test_record_flow_within_limit() {
+        let mut state = rate_limit(1_000, 0);
+
+        assert!(record_flow(&mut state, FlowDirection::Outbound, 600, 1_010).is_ok());
+        assert!(record_flow(&mut state, FlowDirection::Outbound, 400, 1_020).is_ok());
+        assert_eq!(state.outbound_used, 1_000);
+    }
+
+    #[test]
+    fn test_record_flow_exceeds_limit() {
+        let mut state = rate_limit(1_000, 0);
+
+        assert!(record_flow(&mut state, FlowDirection::Outbound, 600, 1_010).is_ok());
+        assert!(record_flow(&mut state, FlowDirection::Outbound, 401, 1_020).is_err());
+    }
+
+    #[test]
+    fn test_record_flow_resets_elapsed_window() {
+        let mut state = rate_limit(1_000, 0);
+
+        assert!(record_flow(&mut state, FlowDirection::Outbound, 1_000, 1_010).is_ok());
+        assert!(record_flow(&mut state, FlowDirection::Outbound, 1_000, 1_100).is_ok());
+        assert_eq!(state.window_start, 1_100);
+        assert_eq!(state.outbound_used, 1_000);
+    }
+
+    #[test]
+    fn test_record_flow_without_limit_tracks_usage() {
+        let mut state = rate_limit(1_000, 0);
+
+        assert!(record_flow(&mut state, FlowDirection::Inbound, u64::MAX, 1_010).is_ok());
+        assert_eq!(state.inbound_used, u64::MAX);
+        assert_eq!(state.outbound_used, 0);
+    }
+
+    #[test]
+    fn test_record_flow_warns_once_when_crossing_threshold() {
+        let mut state = rate_limit(1_000, 8_000);
+
+        assert!(!record_flow(&mut state, FlowDirection::Outbound, 700, 1_010).unwrap());
+        assert!(record_flow(&mut state, FlowDirection::Outbound, 100, 1_020).unwrap());
+        assert!(!record_flow(&mut state, FlowDirection::Outbound, 100, 1_030).unwrap());
+    }
+
+    #[test]
+    fn test_refund_flow_in_current_window() {
+        let mut state = rate_limit(1_000, 0);
+
+        record_flow(&mut state, FlowDirection::Outbound, 600, 1_010).unwrap();
+        refund_flow(&mut state, FlowDirection::Outbound, 600, 1_010);
+        assert_eq!(state.outbound_used, 0);
+        assert!(record_flow(&mut state, FlowDirection::Outbound, 1_000, 1_020).is_ok());
+    }
+
+    #[test]
+    fn test_refund_flow_from_elapsed_window_is_ignored() {
+        let mut state = rate_limit(1_000, 0);
+
+        record_flow(&mut state, FlowDirection::Outbound, 600, 1_010).unwrap();
+        record_flow(&mut state, FlowDirection::Outbound, 300, 1_100).unwrap();
+        refund_flow(&mut state, FlowDirection::Outbound, 600, 1_010);
+        assert_eq!(state.outbound_used, 300);
+    }
+}
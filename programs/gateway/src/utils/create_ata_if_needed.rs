@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use spl_associated_token_account::instruction::create_associated_token_account;
+
+/// Creates the associated token account for `owner` if it does not exist yet.
+///
+/// # Arguments
+///
+/// * `signer` - The account paying the rent for the new token account
+/// * `owner` - The owner of the associated token account
+/// * `mint` - The mint (token) account
+/// * `ata` - The associated token account to create
+/// * `token_program` - The token program
+/// * `associated_token_program` - The associated token program
+/// * `system_program` - The system program
+///
+/// # Returns
+///
+/// * `Result<u64>` - The lamports spent by the signer, zero if the account already existed
+pub fn create_ata_if_needed<'info>(
+    signer: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    ata: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    associated_token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u64> {
+    if ata.lamports() != 0 && *ata.owner != system_program.key() {
+        return Ok(0);
+    }
+
+    // ATA needs to be created
+    msg!(
+        "Creating associated token account {:?} for recipient {:?}...",
+        ata.key(),
+        owner.key(),
+    );
+
+    let bal_before = signer.lamports();
+    invoke(
+        &create_associated_token_account(signer.key, owner.key, mint.key, token_program.key),
+        &[
+            mint.clone(),
+            ata.clone(),
+            owner.clone(),
+            signer.clone(),
+            system_program.clone(),
+            token_program.clone(),
+            associated_token_program.clone(),
+        ],
+    )?;
+    let bal_after = signer.lamports();
+
+    msg!("Associated token account created!");
+
+    Ok(bal_before - bal_after)
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{get_return_data, set_return_data};
+
+use crate::events::ExecuteReturnData;
+use crate::utils::MAX_EXECUTE_RETURN_DATA_SIZE;
+
+/// Reads the return data set by the destination program after a call, emits it and forwards
+/// it as the gateway return data. Data set by other programs is ignored.
+///
+/// # Arguments
+///
+/// * `destination_program` - The program invoked by the gateway
+/// * `nonce` - The nonce of the outbound
+///
+/// # Returns
+///
+/// * `Vec<u8>` - The return data, truncated to `MAX_EXECUTE_RETURN_DATA_SIZE` bytes
+pub fn forward_return_data(destination_program: &Pubkey, nonce: u64) -> Vec<u8> {
+    let Some(return_data) = bounded_return_data(get_return_data(), destination_program) else {
+        return Vec::new();
+    };
+
+    set_return_data(&return_data);
+    emit!(ExecuteReturnData {
+        destination_program: *destination_program,
+        nonce,
+        data: return_data.clone(),
+    });
+
+    return_data
+}
+
+/// Returns the return data if it was set by the destination program, truncated to
+/// `MAX_EXECUTE_RETURN_DATA_SIZE` bytes.
+pub fn bounded_return_data(
+    return_data: Option<(Pubkey, Vec<u8>)>,
+    destination_program: &Pubkey,
+) -> Option<Vec<u8>> {
+    let (program_id, mut data) = return_data?;
+    if program_id != *destination_program || data.is_empty() {
+        return None;
+    }
+    data.truncate(MAX_EXECUTE_RETURN_DATA_SIZE);
+    Some(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_return_data_from_destination_program() {
+        let destination = Pubkey::new_unique();
+
+        assert_eq!(
+            bounded_return_data(Some((destination, vec![1, 2, 3])), &destination),
+            Some(vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_return_data_from_other_program_is_ignored() {
+        let destination = Pubkey::new_unique();
+
+        assert_eq!(
+            bounded_return_data(Some((Pubkey::new_unique(), vec![1])), &destination),
+            None
+        );
+        assert_eq!(bounded_return_data(None, &destination), None);
+        assert_eq!(
+            bounded_return_data(Some((destination, vec![])), &destination),
+            None
+        );
+    }
+
+    #[test]
+    fn test_return_data_is_truncated() {
+        let destination = Pubkey::new_unique();
+        let data = vec![7u8; MAX_EXECUTE_RETURN_DATA_SIZE + 10];
+
+        assert_eq!(
+            bounded_return_data(Some((destination, data)), &destination)
+                .unwrap()
+                .len(),
+            MAX_EXECUTE_RETURN_DATA_SIZE
+        );
+    }
+}
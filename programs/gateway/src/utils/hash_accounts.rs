@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::Hasher;
+
+/// Hashes an account list for TSS message hashes, so relayers cannot add, reorder or
+/// substitute accounts of a signed outbound.
+///
+/// # Arguments
+///
+/// * `accounts` - The account keys with their writable flags, in order
+///
+/// # Returns
+///
+/// * `[u8; 32]` - The keccak hash of the keys each followed by its writable flag
+pub fn hash_accounts<'a>(accounts: impl IntoIterator<Item = (&'a Pubkey, bool)>) -> [u8; 32] {
+    let mut hasher = Hasher::default();
+    for (key, is_writable) in accounts {
+        hasher.hash(key.as_ref());
+        hasher.hash(&[is_writable as u8]);
+    }
+    hasher.result().to_bytes()
+}
+
+/// Hashes the remaining accounts of an instruction, see `hash_accounts`.
+pub fn hash_remaining_accounts(remaining_accounts: &[AccountInfo]) -> [u8; 32] {
+    hash_accounts(
+        remaining_accounts
+            .iter()
+            .map(|account| (account.key, account.is_writable)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::keccak::hash;
+
+    #[test]
+    fn test_hash_matches_concatenated_keys_and_flags() {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+
+        let mut expected = first.to_bytes().to_vec();
+        expected.push(1);
+        expected.extend_from_slice(second.as_ref());
+        expected.push(0);
+
+        assert_eq!(
+            hash_accounts([(&first, true), (&second, false)]),
+            hash(&expected).to_bytes()
+        );
+    }
+
+    #[test]
+    fn test_hash_commits_to_order_and_flags() {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let hashed = hash_accounts([(&first, true), (&second, false)]);
+
+        assert_ne!(hashed, hash_accounts([(&second, false), (&first, true)]));
+        assert_ne!(hashed, hash_accounts([(&first, false), (&second, false)]));
+        assert_ne!(hashed, hash_accounts([(&first, true)]));
+    }
+}
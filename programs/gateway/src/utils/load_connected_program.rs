@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::Errors;
+use crate::state::ConnectedProgram;
+
+/// Loads the registry entry of a destination program.
+///
+/// # Arguments
+///
+/// * `connected_program` - The registry entry of the destination program, may be uninitialized
+///
+/// # Returns
+///
+/// * `Result<Option<ConnectedProgram>>` - The registry entry, `None` if the program is not registered
+pub fn load_connected_program(connected_program: &AccountInfo) -> Result<Option<ConnectedProgram>> {
+    if *connected_program.owner != crate::ID {
+        return Ok(None);
+    }
+
+    Ok(Some(ConnectedProgram::try_deserialize(
+        &mut &connected_program.try_borrow_data()?[..],
+    )?))
+}
+
+/// Finds and loads the registry entry of a destination program among the passed accounts.
+/// The registry account must be passed even if uninitialized, so blocked programs cannot be called
+/// by omitting it.
+///
+/// # Arguments
+///
+/// * `accounts` - The accounts passed to the instruction
+/// * `program_id` - The destination program
+///
+/// # Returns
+///
+/// * `Result<Option<ConnectedProgram>>` - The registry entry, `None` if the program is not registered
+pub fn find_connected_program(
+    accounts: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<Option<ConnectedProgram>> {
+    let (address, _) =
+        Pubkey::find_program_address(&[b"connected_program", program_id.as_ref()], &crate::ID);
+    let connected_program = accounts
+        .iter()
+        .find(|account| account.key() == address)
+        .ok_or(Errors::ConnectedProgramAccountMissing)?;
+
+    load_connected_program(connected_program)
+}
@@ -1,19 +1,49 @@
 pub mod constants;
+pub mod consume_rate_limit;
+pub mod create_ata_if_needed;
+pub mod forward_return_data;
+pub mod hash_accounts;
+pub mod load_connected_program;
 pub mod prepare_account_metas;
+pub mod prepare_arbitrary_call;
+pub mod queue_withdrawal;
+pub mod record_outbound;
 pub mod recover_and_verify_eth_address;
+pub mod set_execute_lock;
 pub mod validate_message;
 pub mod validate_message_hash;
 pub mod verify_and_update_nonce;
 pub mod verify_ata_match;
 pub mod verify_authority;
+pub mod verify_destination_allowed;
+pub mod verify_destination_pda;
+pub mod verify_message_expiry;
 pub mod verify_payload_size;
+pub mod verify_role;
+pub mod verify_withdraw_allowed;
+pub mod wrap_sol;
 
 pub use constants::*;
+pub use consume_rate_limit::*;
+pub use create_ata_if_needed::*;
+pub use forward_return_data::*;
+pub use hash_accounts::*;
+pub use load_connected_program::*;
 pub use prepare_account_metas::*;
+pub use prepare_arbitrary_call::*;
+pub use queue_withdrawal::*;
+pub use record_outbound::*;
 pub use recover_and_verify_eth_address::*;
+pub use set_execute_lock::*;
 pub use validate_message::*;
 pub use validate_message_hash::*;
 pub use verify_and_update_nonce::*;
 pub use verify_ata_match::*;
 pub use verify_authority::*;
+pub use verify_destination_allowed::*;
+pub use verify_destination_pda::*;
+pub use verify_message_expiry::*;
 pub use verify_payload_size::*;
+pub use verify_role::*;
+pub use verify_withdraw_allowed::*;
+pub use wrap_sol::*;
@@ -1,16 +1,48 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::AccountMeta;
 
+use super::constants::MAX_REMAINING_ACCOUNTS;
 use crate::errors::Errors;
 use crate::state::Pda;
 
-/// Prepares account metas for withdraw and call, revert if unallowed account is passed
+/// Prepares account metas for withdraw and call, revert if unallowed account is passed.
+/// The accounts themselves are committed to by the TSS message, see `hash_remaining_accounts`.
+/// The PDA the gateway signs for (the caller authority, or the interchain account of a ZEVM sender)
+/// is passed as a signer, and as writable only if `signer_pda_writable` is set.
+///
+/// # Arguments
+///
+/// * `remaining_accounts` - The accounts passed to the destination program
+/// * `signer` - The relayer, never passed to the destination program
+/// * `pda` - The gateway PDA, can only be passed as not writable
+/// * `signer_pda` - The PDA signed for by the gateway
+/// * `signer_pda_writable` - Whether the signed PDA can be passed as writable
+/// * `custody_accounts` - Token accounts of the gateway, never passed to the destination program
 pub fn prepare_account_metas(
     remaining_accounts: &[AccountInfo],
     signer: &Signer,
     pda: &Account<Pda>,
+    signer_pda: &Pubkey,
+    signer_pda_writable: bool,
+    custody_accounts: &[Pubkey],
 ) -> Result<Vec<AccountMeta>> {
-    let mut account_metas = Vec::new();
+    require!(
+        remaining_accounts.len() <= MAX_REMAINING_ACCOUNTS,
+        Errors::TooManyRemainingAccounts
+    );
+
+    // Prevent duplicates from being included
+    let mut keys: Vec<&Pubkey> = remaining_accounts
+        .iter()
+        .map(|account| account.key)
+        .collect();
+    keys.sort_unstable();
+    require!(
+        keys.windows(2).all(|pair| pair[0] != pair[1]),
+        Errors::DuplicateRemainingAccount
+    );
+
+    let mut account_metas = Vec::with_capacity(remaining_accounts.len());
 
     for account_info in remaining_accounts.iter() {
         let account_key = account_info.key;
@@ -18,9 +50,23 @@ pub fn prepare_account_metas(
         // Prevent signer from being included
         require!(account_key != signer.key, Errors::InvalidInstructionData);
 
+        // Prevent gateway token accounts from being included
+        require!(
+            !custody_accounts.contains(account_key),
+            Errors::CustodyAccountNotAllowed
+        );
+
         // Gateway pda can be added as not writable
         if *account_key == pda.key() {
+            require!(!account_info.is_writable, Errors::WritableGatewayPda);
             account_metas.push(AccountMeta::new_readonly(*account_key, false));
+        } else if account_key == signer_pda {
+            // Signed for by the gateway
+            account_metas.push(AccountMeta {
+                pubkey: *account_key,
+                is_signer: true,
+                is_writable: signer_pda_writable && account_info.is_writable,
+            });
         } else if account_info.is_writable {
             account_metas.push(AccountMeta::new(*account_key, false));
         } else {
@@ -29,3 +75,122 @@ pub fn prepare_account_metas(
     }
     Ok(account_metas)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::MAX_GUARDIANS;
+
+    // Prepares account metas with a fresh signer and a gateway PDA at `pda_key`.
+    fn prepare(
+        pda_key: Pubkey,
+        remaining_accounts: &[AccountInfo],
+        custody_accounts: &[Pubkey],
+    ) -> Result<Vec<AccountMeta>> {
+        let pda = Pda {
+            nonce: 0,
+            tss_address: [0u8; 20],
+            authority: Pubkey::new_unique(),
+            chain_id: 0,
+            deposit_paused: false,
+            outbound_paused: false,
+            circuit_breaker_bps: 0,
+            guardian_reset_delay: 0,
+            circuit_breaker_tripped_at: 0,
+            circuit_breaker_asset: Pubkey::default(),
+            guardians: [Pubkey::default(); MAX_GUARDIANS],
+            destination_allowlist_enabled: false,
+            execute_locked: false,
+        };
+        let mut pda_data = Vec::new();
+        pda.try_serialize(&mut pda_data)?;
+        let signer_key = Pubkey::new_unique();
+        let (mut signer_lamports, mut pda_lamports) = (0, 0);
+        let mut signer_data = vec![];
+        let signer_info = AccountInfo::new(
+            &signer_key,
+            true,
+            true,
+            &mut signer_lamports,
+            &mut signer_data,
+            &signer_key,
+            false,
+            0,
+        );
+        let pda_info = AccountInfo::new(
+            &pda_key,
+            false,
+            true,
+            &mut pda_lamports,
+            &mut pda_data,
+            &crate::ID,
+            false,
+            0,
+        );
+
+        prepare_account_metas(
+            remaining_accounts,
+            &Signer::try_from(&signer_info)?,
+            &Account::try_from(&pda_info)?,
+            &Pubkey::new_unique(),
+            false,
+            custody_accounts,
+        )
+    }
+
+    #[test]
+    fn test_rejects_duplicate_account() {
+        let key = Pubkey::new_unique();
+        let (mut lamports, mut data) = (0, vec![]);
+        let info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &key, false, 0);
+
+        let result = prepare(Pubkey::new_unique(), &[info.clone(), info], &[]);
+        assert_eq!(
+            result.unwrap_err(),
+            Errors::DuplicateRemainingAccount.into()
+        );
+    }
+
+    #[test]
+    fn test_rejects_too_many_remaining_accounts() {
+        let key = Pubkey::new_unique();
+        let (mut lamports, mut data) = (0, vec![]);
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &key, false, 0);
+
+        let result = prepare(
+            Pubkey::new_unique(),
+            &vec![info; MAX_REMAINING_ACCOUNTS + 1],
+            &[],
+        );
+        assert_eq!(result.unwrap_err(), Errors::TooManyRemainingAccounts.into());
+    }
+
+    #[test]
+    fn test_rejects_custody_account() {
+        let key = Pubkey::new_unique();
+        let (mut lamports, mut data) = (0, vec![]);
+        let info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &key, false, 0);
+
+        let result = prepare(Pubkey::new_unique(), &[info], &[key]);
+        assert_eq!(result.unwrap_err(), Errors::CustodyAccountNotAllowed.into());
+    }
+
+    #[test]
+    fn test_rejects_writable_gateway_pda() {
+        let pda_key = Pubkey::new_unique();
+        let (mut lamports, mut data) = (0, vec![]);
+        let info = AccountInfo::new(
+            &pda_key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &crate::ID,
+            false,
+            0,
+        );
+
+        let result = prepare(pda_key, &[info], &[]);
+        assert_eq!(result.unwrap_err(), Errors::WritableGatewayPda.into());
+    }
+}
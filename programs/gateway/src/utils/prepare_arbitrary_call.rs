@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+
+use crate::errors::Errors;
+use crate::state::ArbitraryCallAccount;
+
+/// Prepares account metas of an arbitrary call, revert if unallowed account is passed.
+/// No account is passed as signer, the gateway PDA can only be passed as not writable.
+pub fn prepare_arbitrary_call_metas(
+    accounts: &[ArbitraryCallAccount],
+    signer: &Pubkey,
+    pda: &Pubkey,
+) -> Result<Vec<AccountMeta>> {
+    accounts
+        .iter()
+        .map(|account| {
+            // Prevent signer from being included
+            require!(account.pubkey != *signer, Errors::InvalidInstructionData);
+
+            if account.is_writable && account.pubkey != *pda {
+                Ok(AccountMeta::new(account.pubkey, false))
+            } else {
+                Ok(AccountMeta::new_readonly(account.pubkey, false))
+            }
+        })
+        .collect()
+}
+
+/// Serializes the accounts and data of an arbitrary call for TSS message hashes.
+pub fn arbitrary_call_message_bytes(accounts: &[ArbitraryCallAccount], data: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(4 + accounts.len() * 33 + data.len());
+    buf.extend_from_slice(&(accounts.len() as u32).to_le_bytes());
+    for account in accounts {
+        buf.extend_from_slice(account.pubkey.as_ref());
+        buf.push(account.is_writable as u8);
+    }
+    buf.extend_from_slice(data);
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(pubkey: Pubkey, is_writable: bool) -> ArbitraryCallAccount {
+        ArbitraryCallAccount {
+            pubkey,
+            is_writable,
+        }
+    }
+
+    #[test]
+    fn test_metas_keep_writable_flags_without_signers() {
+        let writable = Pubkey::new_unique();
+        let readonly = Pubkey::new_unique();
+        let metas = prepare_arbitrary_call_metas(
+            &[account(writable, true), account(readonly, false)],
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            metas,
+            vec![
+                AccountMeta::new(writable, false),
+                AccountMeta::new_readonly(readonly, false)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gateway_pda_is_never_writable() {
+        let pda = Pubkey::new_unique();
+        let metas =
+            prepare_arbitrary_call_metas(&[account(pda, true)], &Pubkey::new_unique(), &pda)
+                .unwrap();
+
+        assert_eq!(metas, vec![AccountMeta::new_readonly(pda, false)]);
+    }
+
+    #[test]
+    fn test_signer_is_rejected() {
+        let signer = Pubkey::new_unique();
+
+        assert!(prepare_arbitrary_call_metas(
+            &[account(signer, false)],
+            &signer,
+            &Pubkey::new_unique()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_message_bytes_layout() {
+        let pubkey = Pubkey::new_unique();
+        let bytes = arbitrary_call_message_bytes(&[account(pubkey, true)], &[9, 9]);
+
+        assert_eq!(&bytes[..4], &1u32.to_le_bytes());
+        assert_eq!(&bytes[4..36], pubkey.as_ref());
+        assert_eq!(bytes[36], 1);
+        assert_eq!(&bytes[37..], &[9, 9]);
+    }
+}
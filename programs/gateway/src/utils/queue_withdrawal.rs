@@ -0,0 +1,221 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use std::mem::size_of;
+
+use crate::errors::Errors;
+use crate::events::WithdrawalQueued;
+use crate::state::{PendingWithdrawal, RateLimit};
+
+/// Returns the settlement delay of a withdrawal if it is above the delay threshold of the asset.
+///
+/// # Arguments
+///
+/// * `rate_limit` - The rate limit account of the asset, may be uninitialized
+/// * `amount` - The amount being withdrawn
+pub fn withdrawal_delay(rate_limit: &AccountInfo, amount: u64) -> Result<Option<i64>> {
+    if *rate_limit.owner != crate::ID {
+        return Ok(None);
+    }
+
+    let state = RateLimit::try_deserialize(&mut &rate_limit.try_borrow_data()?[..])?;
+    if state.delay_threshold == 0 || amount <= state.delay_threshold {
+        return Ok(None);
+    }
+
+    Ok(Some(state.settlement_delay))
+}
+
+/// Creates the pending withdrawal account and stores the withdrawal in it.
+/// Escrowing the withdrawn funds is left to the caller.
+///
+/// # Arguments
+///
+/// * `payer` - The account paying the rent for the pending withdrawal
+/// * `pending_withdrawal` - The pending withdrawal PDA to create
+/// * `system_program` - The system program
+/// * `bump` - The bump of the pending withdrawal PDA
+/// * `withdrawal` - The withdrawal to store
+pub fn queue_withdrawal<'info>(
+    payer: &AccountInfo<'info>,
+    pending_withdrawal: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    bump: u8,
+    withdrawal: PendingWithdrawal,
+) -> Result<()> {
+    let nonce_bytes = withdrawal.nonce.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"pending_withdrawal", &nonce_bytes, &[bump]]];
+    let space = size_of::<PendingWithdrawal>() + 8;
+
+    // Fund, allocate and assign separately as the address may already hold lamports
+    let rent_due = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(pending_withdrawal.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: pending_withdrawal.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: pending_withdrawal.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign {
+                account_to_assign: pending_withdrawal.clone(),
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )?;
+
+    withdrawal.try_serialize(&mut &mut pending_withdrawal.try_borrow_mut_data()?[..])?;
+
+    msg!(
+        "Withdrawal queued: nonce = {}, asset = {}, recipient = {}, amount = {}, executable_at = {}",
+        withdrawal.nonce,
+        withdrawal.asset,
+        withdrawal.recipient,
+        withdrawal.amount,
+        withdrawal.executable_at
+    );
+    emit!(WithdrawalQueued {
+        nonce: withdrawal.nonce,
+        asset: withdrawal.asset,
+        recipient: withdrawal.recipient,
+        amount: withdrawal.amount,
+        executable_at: withdrawal.executable_at,
+    });
+
+    Ok(())
+}
+
+/// Queues an outbound above the delay threshold of its asset without escrowing its funds.
+/// The outbound is carried out by resubmitting its message once the settlement delay has elapsed.
+/// Returns true if the outbound was queued and must not be carried out yet.
+///
+/// # Arguments
+///
+/// * `rate_limit` - The rate limit account of the asset, may be uninitialized
+/// * `pending_withdrawal` - The pending withdrawal PDA of the nonce, required above the delay threshold
+/// * `payer` - The account paying the rent for the pending withdrawal
+/// * `system_program` - The system program
+/// * `nonce` - The nonce of the outbound
+/// * `message_hash` - The message hash signed by TSS for the outbound
+/// * `asset` - The asset being moved, the native mint for SOL
+/// * `recipient` - The account receiving the funds of the outbound
+/// * `amount` - The amount being moved
+/// * `decimals` - The decimals of the asset
+#[allow(clippy::too_many_arguments)]
+pub fn defer_outbound<'info>(
+    rate_limit: &AccountInfo<'info>,
+    pending_withdrawal: Option<&AccountInfo<'info>>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    nonce: u64,
+    message_hash: [u8; 32],
+    asset: Pubkey,
+    recipient: Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> Result<bool> {
+    let Some(delay) = withdrawal_delay(rate_limit, amount)? else {
+        return Ok(false);
+    };
+    let pending_withdrawal = pending_withdrawal.ok_or(Errors::PendingWithdrawalRequired)?;
+    let bump = pending_withdrawal_bump(pending_withdrawal.key, nonce)?;
+    let now = Clock::get()?.unix_timestamp;
+
+    queue_withdrawal(
+        payer,
+        pending_withdrawal,
+        system_program,
+        bump,
+        PendingWithdrawal {
+            nonce,
+            asset,
+            recipient,
+            amount,
+            decimals,
+            payer: payer.key(),
+            executable_at: now.saturating_add(delay),
+            queued_at: now,
+            message_hash,
+            escrowed: false,
+        },
+    )?;
+
+    Ok(true)
+}
+
+/// Releases an outbound queued without escrow once its settlement delay has elapsed.
+/// Any signer can release it as the message hash binds its content, the pending withdrawal
+/// is closed to the signer carrying out the outbound.
+/// Returns false if no outbound is queued for the nonce.
+///
+/// # Arguments
+///
+/// * `pending_withdrawal` - The pending withdrawal PDA of the nonce, may be omitted or uninitialized
+/// * `signer` - The signer resubmitting the outbound, receives the pending withdrawal rent
+/// * `nonce` - The nonce of the outbound
+/// * `message_hash` - The message hash of the resubmitted outbound
+pub fn release_queued_outbound<'info>(
+    pending_withdrawal: Option<&AccountInfo<'info>>,
+    signer: &AccountInfo<'info>,
+    nonce: u64,
+    message_hash: &[u8; 32],
+) -> Result<bool> {
+    let Some(pending_withdrawal) = pending_withdrawal.filter(|account| *account.owner == crate::ID)
+    else {
+        return Ok(false);
+    };
+    pending_withdrawal_bump(pending_withdrawal.key, nonce)?;
+
+    let withdrawal =
+        PendingWithdrawal::try_deserialize(&mut &pending_withdrawal.try_borrow_data()?[..])?;
+    require!(
+        withdrawal.message_hash == *message_hash,
+        Errors::MessageHashMismatch
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= withdrawal.executable_at,
+        Errors::WithdrawalDelayNotElapsed
+    );
+
+    // Close the pending withdrawal, the outbound is carried out by the caller
+    let rent = pending_withdrawal.lamports();
+    pending_withdrawal.sub_lamports(rent)?;
+    signer.add_lamports(rent)?;
+    pending_withdrawal.assign(&system_program::ID);
+    pending_withdrawal.realloc(0, false)?;
+
+    msg!("Queued outbound released: nonce = {}", nonce);
+
+    Ok(true)
+}
+
+/// Returns the bump of the pending withdrawal PDA of a nonce, verifying its address.
+pub fn pending_withdrawal_bump(pending_withdrawal: &Pubkey, nonce: u64) -> Result<u8> {
+    let (expected, bump) =
+        Pubkey::find_program_address(&[b"pending_withdrawal", &nonce.to_le_bytes()], &crate::ID);
+    require_keys_eq!(
+        *pending_withdrawal,
+        expected,
+        Errors::PendingWithdrawalRequired
+    );
+    Ok(bump)
+}
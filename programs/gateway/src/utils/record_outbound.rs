@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use super::consume_rate_limit::{consume_rate_limit, refund_rate_limit};
+use crate::errors::Errors;
+use crate::events::CircuitBreakerTripped;
+use crate::state::{FlowDirection, Pda};
+
+/// Verifies outbounds are not paused and records the outbound against the rate limit of the asset.
+/// Trips the circuit breaker if the outflow within the window exceeds the configured share of custody.
+/// The tripping outbound is not carried out but its nonce stays consumed, so the pause persists,
+/// and later outbounds are rejected until reset. An enabled circuit breaker requires the rate limit
+/// account of the asset, which tracks the outflow within the window.
+///
+/// # Arguments
+///
+/// * `pda` - The gateway PDA
+/// * `rate_limit` - The rate limit account of the asset, may be uninitialized
+/// * `asset` - The asset being moved, the native mint for SOL
+/// * `amount` - The amount being moved
+/// * `custody` - The custody of the asset held by the gateway before the outbound
+///
+/// # Returns
+///
+/// * `Result<bool>` - False if the outbound tripped the circuit breaker and must not be carried out
+pub fn record_outbound(
+    pda: &mut Account<Pda>,
+    rate_limit: &AccountInfo,
+    asset: Pubkey,
+    amount: u64,
+    custody: u64,
+) -> Result<bool> {
+    require!(!pda.outbound_paused, Errors::OutboundPaused);
+
+    let Some(outflow) = consume_rate_limit(rate_limit, asset, FlowDirection::Outbound, amount)?
+    else {
+        require!(pda.circuit_breaker_bps == 0, Errors::RateLimitRequired);
+        return Ok(true);
+    };
+
+    if exceeds_custody_share(outflow, amount, custody, pda.circuit_breaker_bps) {
+        let now = Clock::get()?.unix_timestamp;
+        refund_rate_limit(rate_limit, FlowDirection::Outbound, amount, now)?;
+
+        pda.outbound_paused = true;
+        pda.circuit_breaker_tripped_at = now;
+        pda.circuit_breaker_asset = asset;
+
+        msg!(
+            "Circuit breaker tripped: asset = {}, outflow = {}, custody = {}",
+            asset,
+            outflow,
+            custody
+        );
+        emit!(CircuitBreakerTripped {
+            asset,
+            outflow,
+            custody,
+        });
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+/// Returns true if `outflow` exceeds `bps` of the custody held at the start of the window.
+/// The custody at the start of the window is approximated by adding back the earlier outflow.
+pub fn exceeds_custody_share(outflow: u64, amount: u64, custody: u64, bps: u16) -> bool {
+    if bps == 0 {
+        return false;
+    }
+    let window_custody = custody as u128 + outflow.saturating_sub(amount) as u128;
+    outflow as u128 * 10_000 > window_custody * bps as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exceeds_custody_share_disabled() {
+        assert!(!exceeds_custody_share(1_000, 1_000, 1_000, 0));
+    }
+
+    #[test]
+    fn test_exceeds_custody_share_single_outbound() {
+        // 25% of custody with a 30% threshold
+        assert!(!exceeds_custody_share(250, 250, 1_000, 3_000));
+        // 35% of custody with a 30% threshold
+        assert!(exceeds_custody_share(350, 350, 1_000, 3_000));
+    }
+
+    #[test]
+    fn test_exceeds_custody_share_accumulates_window_outflow() {
+        // 200 left earlier in the window, so custody at the start was 1_000
+        assert!(!exceeds_custody_share(300, 100, 800, 3_000));
+        assert!(exceeds_custody_share(400, 200, 800, 3_000));
+    }
+}
@@ -1,20 +1,29 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::keccak::hash;
-use anchor_lang::solana_program::program_error::ProgramError;
 use anchor_lang::solana_program::secp256k1_recover::secp256k1_recover;
 
 use crate::errors::Errors;
 use crate::state::Pda;
 
+/// Half of the secp256k1 curve order, the largest `s` accepted in a signature.
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
 /// Recovers and verifies eth address from signature.
+/// Recovery ids are accepted both as 0/1 and Ethereum-style 27/28, high-s signatures are rejected.
 pub fn recover_and_verify_eth_address(
     pda: &mut Account<Pda>,
     message_hash: &[u8; 32],
     recovery_id: u8,
     signature: &[u8; 64],
 ) -> Result<()> {
+    let recovery_id = normalize_recovery_id(recovery_id)?;
+    verify_low_s(signature)?;
+
     let pubkey = secp256k1_recover(message_hash, recovery_id, signature)
-        .map_err(|_| ProgramError::InvalidArgument)?;
+        .map_err(|_| Errors::SignatureRecoveryFailed)?;
 
     // pubkey is 64 Bytes, uncompressed public secp256k1 public key
     let h = hash(pubkey.to_bytes().as_slice()).to_bytes();
@@ -31,3 +40,62 @@ pub fn recover_and_verify_eth_address(
 
     Ok(())
 }
+
+/// Maps 0/1 and Ethereum-style 27/28 recovery ids to 0/1.
+fn normalize_recovery_id(recovery_id: u8) -> Result<u8> {
+    match recovery_id {
+        0 | 1 => Ok(recovery_id),
+        27 | 28 => Ok(recovery_id - 27),
+        _ => err!(Errors::InvalidRecoveryId),
+    }
+}
+
+/// Rejects signatures whose `s` lies in the upper half of the curve order, as each
+/// of them has a malleable low-s counterpart.
+fn verify_low_s(signature: &[u8; 64]) -> Result<()> {
+    // s is the big-endian second half of the signature, so byte-wise comparison is numeric
+    require!(
+        signature[32..] <= SECP256K1_HALF_ORDER[..],
+        Errors::MalleableSignature
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_recovery_id() {
+        assert_eq!(normalize_recovery_id(0).unwrap(), 0);
+        assert_eq!(normalize_recovery_id(1).unwrap(), 1);
+        assert_eq!(normalize_recovery_id(27).unwrap(), 0);
+        assert_eq!(normalize_recovery_id(28).unwrap(), 1);
+        for recovery_id in [2, 3, 26, 29, 255] {
+            assert_eq!(
+                normalize_recovery_id(recovery_id).unwrap_err(),
+                Errors::InvalidRecoveryId.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_low_s() {
+        let mut signature = [0u8; 64];
+        signature[32..].copy_from_slice(&SECP256K1_HALF_ORDER);
+        assert!(verify_low_s(&signature).is_ok());
+
+        signature[63] += 1;
+        assert_eq!(
+            verify_low_s(&signature).unwrap_err(),
+            Errors::MalleableSignature.into()
+        );
+
+        signature[32..].copy_from_slice(&[0xff; 32]);
+        assert_eq!(
+            verify_low_s(&signature).unwrap_err(),
+            Errors::MalleableSignature.into()
+        );
+    }
+}
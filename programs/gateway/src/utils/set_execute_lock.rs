@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Pda;
+
+/// Sets the reentrancy lock of the gateway PDA held while a destination program runs.
+/// The PDA is written back immediately, so gateway instructions invoked by the destination
+/// program observe the lock. Every deposit and call is rejected while locked, whatever its signer,
+/// so caller authorities and interchain accounts cannot send back to ZEVM within an execute.
+/// ## Arguments
+/// * `pda` - The gateway PDA.
+/// * `locked` - Flag to lock or unlock deposits.
+pub fn set_execute_lock(pda: &mut Account<Pda>, locked: bool) -> Result<()> {
+    pda.execute_locked = locked;
+    pda.exit(&crate::ID)
+}
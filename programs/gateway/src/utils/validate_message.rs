@@ -1,12 +1,17 @@
 use anchor_lang::prelude::*;
 
+use super::queue_withdrawal::release_queued_outbound;
 use super::recover_and_verify_eth_address::recover_and_verify_eth_address;
 use super::validate_message_hash::validate_message_hash;
 use super::verify_and_update_nonce::verify_and_update_nonce;
+use super::verify_message_expiry;
+use crate::errors::Errors;
 use crate::state::InstructionId;
 use crate::state::Pda;
 
-/// Perform common cross-chain verification steps
+/// Perform common cross-chain verification steps.
+/// The expiry is appended to the signed message with a presence tag.
+#[allow(clippy::too_many_arguments)]
 pub fn validate_message(
     pda: &mut Account<Pda>,
     instruction_id: InstructionId,
@@ -16,15 +21,22 @@ pub fn validate_message(
     message_hash: &[u8; 32],
     signature: &[u8; 64],
     recovery_id: u8,
+    expiry: Option<i64>,
 ) -> Result<()> {
     verify_and_update_nonce(pda, nonce)?;
+    verify_message_expiry(expiry, Clock::get()?.unix_timestamp)?;
+
+    let expiry_bytes = expiry_tag(expiry);
+    let mut message_parts = additional_data.to_vec();
+    message_parts.push(&expiry_bytes);
 
     validate_message_hash(
         instruction_id,
         pda.chain_id,
+        &pda.key(),
         nonce,
         Some(amount),
-        additional_data,
+        &message_parts,
         message_hash,
     )?;
 
@@ -32,3 +44,96 @@ pub fn validate_message(
 
     Ok(())
 }
+
+/// Perform the cross-chain verification steps of an outbound subject to the withdrawal queue.
+/// An outbound queued above the delay threshold had its nonce and signature verified when it was
+/// queued; it is released by resubmitting the same message after the settlement delay, as long as
+/// the message has not expired meanwhile.
+/// Returns true if the outbound was released from the queue.
+#[allow(clippy::too_many_arguments)]
+pub fn validate_outbound_message<'info>(
+    pda: &mut Account<Pda>,
+    pending_withdrawal: Option<&AccountInfo<'info>>,
+    signer: &AccountInfo<'info>,
+    instruction_id: InstructionId,
+    nonce: u64,
+    amount: u64,
+    additional_data: &[&[u8]],
+    message_hash: &[u8; 32],
+    signature: &[u8; 64],
+    recovery_id: u8,
+    expiry: Option<i64>,
+) -> Result<bool> {
+    if !release_queued_outbound(pending_withdrawal, signer, nonce, message_hash)? {
+        validate_message(
+            pda,
+            instruction_id,
+            nonce,
+            amount,
+            additional_data,
+            message_hash,
+            signature,
+            recovery_id,
+            expiry,
+        )?;
+        return Ok(false);
+    }
+
+    require!(!pda.outbound_paused, Errors::OutboundPaused);
+    verify_message_expiry(expiry, Clock::get()?.unix_timestamp)?;
+
+    let expiry_bytes = expiry_tag(expiry);
+    let mut message_parts = additional_data.to_vec();
+    message_parts.push(&expiry_bytes);
+
+    validate_message_hash(
+        instruction_id,
+        pda.chain_id,
+        &pda.key(),
+        nonce,
+        Some(amount),
+        &message_parts,
+        message_hash,
+    )?;
+
+    Ok(true)
+}
+
+/// Encodes the expiry as `0` when absent or `expiry ‖ 1` when present.
+/// The trailing tag keeps the expiry bytes from being read as the tail of variable length data.
+fn expiry_tag(expiry: Option<i64>) -> Vec<u8> {
+    match expiry {
+        Some(expiry) => [&expiry.to_be_bytes()[..], &[1u8]].concat(),
+        None => vec![0],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expiry_tag() {
+        assert_eq!(expiry_tag(None), vec![0]);
+
+        let mut expected = 100i64.to_be_bytes().to_vec();
+        expected.push(1);
+        assert_eq!(expiry_tag(Some(100)), expected);
+    }
+
+    #[test]
+    fn test_expiry_bytes_moved_into_data_change_the_message() {
+        let data = b"payload".to_vec();
+        for expiry in [1_700_000_000i64, 0x6543_2100] {
+            let signed = [data.clone(), expiry_tag(Some(expiry))].concat();
+
+            // A relayer moves the expiry bytes onto the end of the data and drops the expiry.
+            let moved_data = [data.clone(), expiry.to_be_bytes().to_vec()].concat();
+            assert_ne!(signed, [moved_data, expiry_tag(None)].concat());
+
+            // Nor can any other split of the signed bytes pass as a message without expiry.
+            let moved_data = signed[..signed.len() - 1].to_vec();
+            assert_ne!(signed, [moved_data, expiry_tag(None)].concat());
+        }
+    }
+}
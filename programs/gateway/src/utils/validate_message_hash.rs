@@ -6,20 +6,52 @@ use crate::errors::Errors;
 use crate::state::InstructionId;
 
 /// Creates and validates a message hash for cross-chain instruction verification
-/// with optional amount inclusion
+/// with optional amount inclusion.
+/// The hash commits to the executing program id and the gateway PDA, so a signature
+/// is only valid on the deployment it was produced for.
+/// Version 1 hashes are not accepted, see the program module for the upgrade procedure.
 pub fn validate_message_hash(
     instruction_id: InstructionId,
     chain_id: u64,
+    pda: &Pubkey,
     nonce: u64,
     amount: Option<u64>, // Make amount optional
     additional_data: &[&[u8]],
     message_hash: &[u8; 32],
 ) -> Result<()> {
+    let computed_hash = hash(&message_preimage(
+        instruction_id,
+        chain_id,
+        &crate::ID,
+        pda,
+        nonce,
+        amount,
+        additional_data,
+    ))
+    .to_bytes();
+    require!(*message_hash == computed_hash, Errors::MessageHashMismatch);
+
+    Ok(())
+}
+
+/// Builds the version 2 message signed by TSS:
+/// prefix ‖ instruction id ‖ chain id ‖ program id ‖ pda ‖ nonce ‖ [amount] ‖ additional data
+fn message_preimage(
+    instruction_id: InstructionId,
+    chain_id: u64,
+    program_id: &Pubkey,
+    pda: &Pubkey,
+    nonce: u64,
+    amount: Option<u64>,
+    additional_data: &[&[u8]],
+) -> Vec<u8> {
     let mut concatenated_buffer = Vec::new();
 
     concatenated_buffer.extend_from_slice(ZETACHAIN_PREFIX);
-    concatenated_buffer.push(instruction_id as u8);
+    concatenated_buffer.push(instruction_id.hash_id());
     concatenated_buffer.extend_from_slice(&chain_id.to_be_bytes());
+    concatenated_buffer.extend_from_slice(program_id.as_ref());
+    concatenated_buffer.extend_from_slice(pda.as_ref());
     concatenated_buffer.extend_from_slice(&nonce.to_be_bytes());
 
     // Only include amount in the hash if it's provided
@@ -31,10 +63,48 @@ pub fn validate_message_hash(
         concatenated_buffer.extend_from_slice(data);
     }
 
-    let computed_hash = hash(&concatenated_buffer[..]).to_bytes();
-    require!(*message_hash == computed_hash, Errors::MessageHashMismatch);
+    concatenated_buffer
+}
 
-    msg!("Computed message hash: {:?}", message_hash);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Ok(())
+    #[test]
+    fn test_preimage_layout() {
+        let program_id = Pubkey::new_unique();
+        let pda = Pubkey::new_unique();
+        let preimage = message_preimage(
+            InstructionId::Withdraw,
+            7,
+            &program_id,
+            &pda,
+            3,
+            Some(5),
+            &[&[9, 9]],
+        );
+
+        let mut expected = b"ZETACHAIN".to_vec();
+        expected.push(0x81);
+        expected.extend_from_slice(&7u64.to_be_bytes());
+        expected.extend_from_slice(program_id.as_ref());
+        expected.extend_from_slice(pda.as_ref());
+        expected.extend_from_slice(&3u64.to_be_bytes());
+        expected.extend_from_slice(&5u64.to_be_bytes());
+        expected.extend_from_slice(&[9, 9]);
+        assert_eq!(preimage, expected);
+    }
+
+    #[test]
+    fn test_preimage_differs_by_program_id() {
+        let pda = Pubkey::new_unique();
+        let preimage = |program_id: &Pubkey| {
+            message_preimage(InstructionId::ExecuteSol, 1, program_id, &pda, 0, None, &[])
+        };
+
+        assert_ne!(
+            preimage(&Pubkey::new_unique()),
+            preimage(&Pubkey::new_unique())
+        );
+    }
 }
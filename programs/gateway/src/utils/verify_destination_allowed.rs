@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::Errors;
+use crate::state::{ConnectedProgram, ConnectedProgramStatus, Pda};
+
+/// Verifies the destination program of an execution against the connected program registry.
+/// Blocked programs are always rejected, unregistered programs only when the allowlist is enabled.
+///
+/// # Arguments
+///
+/// * `pda` - The gateway PDA
+/// * `connected_program` - The registry entry of the destination program, if registered
+pub fn verify_destination_allowed(
+    pda: &Pda,
+    connected_program: Option<&ConnectedProgram>,
+) -> Result<()> {
+    let status = connected_program
+        .map(|entry| entry.status)
+        .unwrap_or(ConnectedProgramStatus::Unlisted);
+
+    verify_destination_status(status, pda.destination_allowlist_enabled)
+}
+
+/// Verifies a registry status given whether the allowlist is enabled.
+pub fn verify_destination_status(
+    status: ConnectedProgramStatus,
+    allowlist_enabled: bool,
+) -> Result<()> {
+    require!(
+        status != ConnectedProgramStatus::Blocked,
+        Errors::DestinationProgramBlocked
+    );
+    require!(
+        !allowlist_enabled || status == ConnectedProgramStatus::Allowed,
+        Errors::DestinationProgramNotAllowed
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocked_destination_is_rejected() {
+        assert!(verify_destination_status(ConnectedProgramStatus::Blocked, false).is_err());
+        assert!(verify_destination_status(ConnectedProgramStatus::Blocked, true).is_err());
+    }
+
+    #[test]
+    fn test_unlisted_destination_requires_allowlist_disabled() {
+        assert!(verify_destination_status(ConnectedProgramStatus::Unlisted, false).is_ok());
+        assert!(verify_destination_status(ConnectedProgramStatus::Unlisted, true).is_err());
+    }
+
+    #[test]
+    fn test_allowed_destination_is_accepted() {
+        assert!(verify_destination_status(ConnectedProgramStatus::Allowed, false).is_ok());
+        assert!(verify_destination_status(ConnectedProgramStatus::Allowed, true).is_ok());
+    }
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::Errors;
+use crate::state::ConnectedProgram;
+use crate::utils::DEFAULT_CUSTODY_SEED;
+
+/// Verifies the PDA receiving funds is derived from the custody seed declared by the destination
+/// program, or from the default `connected` seed.
+///
+/// # Arguments
+///
+/// * `destination_program` - The destination program
+/// * `destination_program_pda` - The provided PDA receiving funds
+/// * `connected_program` - The registry entry of the destination program, if registered
+pub fn verify_destination_pda(
+    destination_program: &Pubkey,
+    destination_program_pda: &Pubkey,
+    connected_program: Option<&ConnectedProgram>,
+) -> Result<()> {
+    let expected = match connected_program
+        .and_then(|entry| entry.custody_seed().map(|seed| (seed, entry.custody_bump)))
+    {
+        Some((seed, bump)) => Pubkey::create_program_address(&[seed, &[bump]], destination_program)
+            .map_err(|_| Errors::DestinationProgramPdaMismatch)?,
+        None => Pubkey::find_program_address(&[DEFAULT_CUSTODY_SEED], destination_program).0,
+    };
+
+    require_keys_eq!(
+        expected,
+        *destination_program_pda,
+        Errors::DestinationProgramPdaMismatch
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::ConnectedProgramStatus;
+    use crate::utils::MAX_CUSTODY_SEED_LEN;
+
+    fn entry(seed: &[u8], program_id: &Pubkey) -> ConnectedProgram {
+        let mut custody_seed = [0u8; MAX_CUSTODY_SEED_LEN];
+        custody_seed[..seed.len()].copy_from_slice(seed);
+        ConnectedProgram {
+            program_id: *program_id,
+            status: ConnectedProgramStatus::Unlisted,
+            custody_seed,
+            custody_seed_len: seed.len() as u8,
+            custody_bump: Pubkey::find_program_address(&[seed], program_id).1,
+            on_call_discriminator: [0u8; 8],
+            on_revert_discriminator: [0u8; 8],
+            discriminator_len: 0,
+            context_version: 0,
+            on_abort_discriminator: [0u8; 8],
+        }
+    }
+
+    #[test]
+    fn test_default_seed_without_registry_entry() {
+        let program_id = Pubkey::new_unique();
+        let (connected, _) = Pubkey::find_program_address(&[b"connected"], &program_id);
+
+        assert!(verify_destination_pda(&program_id, &connected, None).is_ok());
+        assert!(verify_destination_pda(&program_id, &Pubkey::new_unique(), None).is_err());
+    }
+
+    #[test]
+    fn test_declared_seed_replaces_default_seed() {
+        let program_id = Pubkey::new_unique();
+        let entry = entry(b"vault", &program_id);
+        let (vault, _) = Pubkey::find_program_address(&[b"vault"], &program_id);
+        let (connected, _) = Pubkey::find_program_address(&[b"connected"], &program_id);
+
+        assert!(verify_destination_pda(&program_id, &vault, Some(&entry)).is_ok());
+        assert!(verify_destination_pda(&program_id, &connected, Some(&entry)).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+use crate::errors::Errors;
+use anchor_lang::prelude::*;
+
+/// Verify that a signed message has not expired
+/// ## Arguments
+/// * `expiry` - Optional unix timestamp after which the message is rejected.
+/// * `now` - The current unix timestamp.
+/// ## Returns
+/// * `Result<()>` - Ok if the message has no expiry or has not expired yet, Error otherwise.
+pub fn verify_message_expiry(expiry: Option<i64>, now: i64) -> Result<()> {
+    if let Some(expiry) = expiry {
+        require!(now <= expiry, Errors::MessageExpired);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_expiry_is_accepted() {
+        assert!(verify_message_expiry(None, i64::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_expiry_is_inclusive() {
+        assert!(verify_message_expiry(Some(100), 99).is_ok());
+        assert!(verify_message_expiry(Some(100), 100).is_ok());
+        assert_eq!(
+            verify_message_expiry(Some(100), 101).unwrap_err(),
+            Errors::MessageExpired.into()
+        );
+    }
+}
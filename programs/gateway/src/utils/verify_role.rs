@@ -0,0 +1,93 @@
+use crate::{
+    errors::Errors,
+    state::{Pda, Role, Roles},
+};
+use anchor_lang::prelude::*;
+
+/// Returns true if the signer is the authority of the PDA or holds `role` in the roles account.
+/// A roles account that is not initialized grants no roles.
+///
+/// # Arguments
+///
+/// * `signer` - The signer of the instruction
+/// * `pda` - The gateway PDA
+/// * `roles` - The roles account, may be uninitialized
+/// * `role` - The role required by the instruction
+pub fn has_role(signer: &Pubkey, pda: &Pda, roles: &AccountInfo, role: Role) -> Result<bool> {
+    if *signer == pda.authority {
+        return Ok(true);
+    }
+    if *roles.owner != crate::ID {
+        return Ok(false);
+    }
+
+    let roles = Roles::try_deserialize(&mut &roles.try_borrow_data()?[..])?;
+    Ok(roles.has_role(signer, role))
+}
+
+/// Verifies that the signer is the authority of the PDA or holds `role`.
+/// Returns an error if the signer is not authorized
+pub fn verify_role(signer: &Pubkey, pda: &Pda, roles: &AccountInfo, role: Role) -> Result<()> {
+    require!(
+        has_role(signer, pda, roles, role)?,
+        Errors::SignerIsNotAuthority
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::RoleMember;
+    use crate::utils::MAX_ROLE_MEMBERS;
+
+    fn roles() -> Roles {
+        Roles {
+            members: [RoleMember::default(); MAX_ROLE_MEMBERS],
+        }
+    }
+
+    #[test]
+    fn test_grant_role_only_grants_that_role() {
+        let mut roles = roles();
+        let pauser = Pubkey::new_unique();
+
+        roles.grant(pauser, Role::Pauser).unwrap();
+
+        assert!(roles.has_role(&pauser, Role::Pauser));
+        assert!(!roles.has_role(&pauser, Role::Unpauser));
+        assert!(!roles.has_role(&pauser, Role::TssUpdater));
+    }
+
+    #[test]
+    fn test_revoke_role_frees_slot() {
+        let mut roles = roles();
+        let member = Pubkey::new_unique();
+
+        roles.grant(member, Role::Pauser).unwrap();
+        roles.grant(member, Role::NonceResetter).unwrap();
+        roles.revoke(member, Role::Pauser);
+        assert!(!roles.has_role(&member, Role::Pauser));
+        assert!(roles.has_role(&member, Role::NonceResetter));
+
+        roles.revoke(member, Role::NonceResetter);
+        assert_eq!(roles.members[0], RoleMember::default());
+    }
+
+    #[test]
+    fn test_grant_role_fails_when_full() {
+        let mut roles = roles();
+        for _ in 0..MAX_ROLE_MEMBERS {
+            roles.grant(Pubkey::new_unique(), Role::Pauser).unwrap();
+        }
+
+        assert!(roles.grant(Pubkey::new_unique(), Role::Pauser).is_err());
+    }
+
+    #[test]
+    fn test_default_key_has_no_role() {
+        let roles = roles();
+
+        assert!(!roles.has_role(&Pubkey::default(), Role::Pauser));
+    }
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::errors::Errors;
+use crate::state::WhitelistEntry;
+
+/// Verifies withdrawals of a mint are not paused by its whitelist entry.
+/// Mints without a whitelist entry, or with a not yet migrated legacy entry, can always be withdrawn.
+pub fn verify_withdraw_allowed(whitelist_entry: &AccountInfo) -> Result<()> {
+    if *whitelist_entry.owner != crate::ID
+        || whitelist_entry.data_len() <= WhitelistEntry::DISCRIMINATOR.len()
+    {
+        return Ok(());
+    }
+
+    let entry = WhitelistEntry::try_deserialize(&mut &whitelist_entry.try_borrow_data()?[..])?;
+    require!(!entry.withdraw_paused, Errors::WithdrawPaused);
+
+    Ok(())
+}
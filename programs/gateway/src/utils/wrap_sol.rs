@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{sync_native, SyncNative};
+
+use crate::state::Pda;
+
+/// Moves lamports from the gateway PDA into a native (wSOL) token account and syncs its balance.
+///
+/// # Arguments
+///
+/// * `pda` - The gateway PDA holding the SOL custody
+/// * `token_account` - The native token account receiving the wrapped SOL
+/// * `token_program` - The token program
+/// * `amount` - The amount of lamports to wrap
+pub fn wrap_sol<'info>(
+    pda: &Account<'info, Pda>,
+    token_account: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    pda.sub_lamports(amount)?;
+    token_account.add_lamports(amount)?;
+
+    sync_native(CpiContext::new(
+        token_program.clone(),
+        SyncNative {
+            account: token_account.clone(),
+        },
+    ))
+}
@@ -76,6 +76,44 @@ async function mintSPLToken(
   return txsig;
 }
 
+function messageHeader(
+  programId: anchor.web3.PublicKey,
+  instructionId: number
+): Buffer {
+  const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("meta", "utf-8")],
+    programId
+  );
+  return Buffer.concat([
+    Buffer.from("ZETACHAIN", "utf-8"),
+    Buffer.from([instructionId | 0x80]),
+    chain_id_bn.toArrayLike(Buffer, "be", 8),
+    programId.toBuffer(),
+    pda.toBuffer(),
+  ]);
+}
+
+function expiryTag(expiry: anchor.BN | null): Buffer {
+  return expiry === null
+    ? Buffer.from([0])
+    : Buffer.concat([expiry.toArrayLike(Buffer, "be", 8), Buffer.from([1])]);
+}
+
+function accountsHash(
+  accounts: { pubkey: anchor.web3.PublicKey; isWritable: boolean }[]
+) {
+  return keccak256(
+    Buffer.concat(
+      accounts.map((account) =>
+        Buffer.concat([
+          account.pubkey.toBuffer(),
+          Buffer.from([account.isWritable ? 1 : 0]),
+        ])
+      )
+    )
+  );
+}
+
 async function depositSplTokens(
   gatewayProgram: Program<Gateway>,
   conn: anchor.web3.Connection,
@@ -128,16 +166,15 @@ async function withdrawSplToken(
   gatewayProgram: Program<Gateway>
 ) {
   const buffer = Buffer.concat([
-    Buffer.from("ZETACHAIN", "utf-8"),
-    Buffer.from([0x02]),
-    chain_id_bn.toArrayLike(Buffer, "be", 8),
+    messageHeader(gatewayProgram.programId, 0x02),
     nonce.toArrayLike(Buffer, "be", 8),
     amount.toArrayLike(Buffer, "be", 8),
     mint.publicKey.toBuffer(),
     to.toBuffer(),
+    expiryTag(null),
   ]);
   const message_hash = keccak256(buffer);
-  const signature = keyPair.sign(message_hash, "hex");
+  const signature = keyPair.sign(message_hash, "hex", { canonical: true });
   const { r, s, recoveryParam } = signature;
   const signatureBuffer = Buffer.concat([
     r.toArrayLike(Buffer, "be", 32),
@@ -150,7 +187,8 @@ async function withdrawSplToken(
       Array.from(signatureBuffer),
       Number(recoveryParam),
       Array.from(message_hash),
-      nonce
+      nonce,
+      null
     )
     .accounts({
       pdaAta: from,
@@ -182,6 +220,10 @@ describe("Gateway", () => {
     seeds,
     gatewayProgram.programId
   );
+  const [callerAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("caller_authority", "utf-8"), connectedProgram.programId.toBuffer()],
+    gatewayProgram.programId
+  );
 
   it("Initializes the program", async () => {
     await gatewayProgram.methods.initialize(tssAddress, chain_id_bn).rpc();
@@ -546,16 +588,15 @@ describe("Gateway", () => {
     try {
       const nonce2 = nonce.addn(1);
       const buffer = Buffer.concat([
-        Buffer.from("ZETACHAIN", "utf-8"),
-        Buffer.from([0x02]),
-        chain_id_bn.toArrayLike(Buffer, "be", 8),
+        messageHeader(gatewayProgram.programId, 0x02),
         nonce2.toArrayLike(Buffer, "be", 8),
         amount.toArrayLike(Buffer, "be", 8),
         mint_fake.publicKey.toBuffer(),
         wallet_ata.toBuffer(),
+        expiryTag(null),
       ]);
       const message_hash = keccak256(buffer);
-      const signature = keyPair.sign(message_hash, "hex");
+      const signature = keyPair.sign(message_hash, "hex", { canonical: true });
       const { r, s, recoveryParam } = signature;
       const signatureBuffer = Buffer.concat([
         r.toArrayLike(Buffer, "be", 32),
@@ -568,7 +609,8 @@ describe("Gateway", () => {
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce2
+          nonce2,
+          null
         )
         .accounts({
           pdaAta: pda_ata,
@@ -586,6 +628,55 @@ describe("Gateway", () => {
     }
   });
 
+  it("Withdraw USDC with decimals read from the mint", async () => {
+    const pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const accountBefore = await spl.getAccount(conn, pda_ata);
+
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 21),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      wallet_ata.toBuffer(),
+      Buffer.from([usdcDecimals]),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    await gatewayProgram.methods
+      .withdrawSplTokenV2(
+        amount,
+        Array.from(signatureBuffer),
+        Number(recoveryParam),
+        Array.from(message_hash),
+        nonce,
+        null
+      )
+      .accounts({
+        pdaAta: pda_ata,
+        mintAccount: mint.publicKey,
+        recipientAta: wallet_ata,
+        recipient: wallet.publicKey,
+      })
+      .rpc({ commitment: "processed" });
+
+    const accountAfter = await spl.getAccount(conn, pda_ata);
+    expect(accountAfter.amount - accountBefore.amount).to.be.eq(-500_000n);
+  });
+
   it("Deposit if receiver is empty address should fail", async () => {
     try {
       await gatewayProgram.methods
@@ -608,6 +699,10 @@ describe("Gateway", () => {
       )
       .accounts({
         gatewayPda: pdaAccount,
+        gatewayRateLimit: anchor.web3.PublicKey.findProgramAddressSync(
+          [Buffer.from("rate_limit", "utf-8"), spl.NATIVE_MINT.toBuffer()],
+          gatewayProgram.programId
+        )[0],
         gatewayProgram: gatewayProgram.programId,
       })
       .rpc();
@@ -617,6 +712,81 @@ describe("Gateway", () => {
     expect(balanceAfter - balanceBefore).to.eq(1_000_000_000 + 2_000_000);
   });
 
+  it("Deposit SOL taken from a separate source account", async () => {
+    const source = anchor.web3.Keypair.generate();
+    await anchor.web3.sendAndConfirmTransaction(
+      conn,
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: wallet.publicKey,
+          toPubkey: source.publicKey,
+          lamports: 1_000_000_000,
+        })
+      ),
+      [wallet]
+    );
+
+    const pdaBalanceBefore = await conn.getBalance(pdaAccount);
+    await gatewayProgram.methods
+      .depositFromSource(
+        new anchor.BN(500_000_000),
+        Array.from(address),
+        revertOptions
+      )
+      .accounts({
+        deposit: { signer: wallet.publicKey },
+        source: source.publicKey,
+      })
+      .signers([source])
+      .rpc();
+
+    const pdaBalanceAfter = await conn.getBalance(pdaAccount);
+    expect(pdaBalanceAfter - pdaBalanceBefore).to.eq(500_000_000 + 2_000_000);
+    // the deposit fee is paid by the signer
+    expect(await conn.getBalance(source.publicKey)).to.eq(500_000_000);
+  });
+
+  it("Deposit SPL tokens moved by an approved delegate", async () => {
+    const delegate = anchor.web3.Keypair.generate();
+    const pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    await spl.approve(
+      conn,
+      wallet,
+      wallet_ata,
+      delegate.publicKey,
+      wallet,
+      1_000_000
+    );
+
+    const accountBefore = await spl.getAccount(conn, pda_ata);
+    await gatewayProgram.methods
+      .depositSplTokenWithAuthority(
+        new anchor.BN(1_000_000),
+        Array.from(address),
+        revertOptions
+      )
+      .accounts({
+        deposit: {
+          signer: wallet.publicKey,
+          from: wallet_ata,
+          to: pda_ata,
+          mintAccount: mint.publicKey,
+        },
+        authority: delegate.publicKey,
+      })
+      .signers([delegate])
+      .rpc();
+
+    const accountAfter = await spl.getAccount(conn, pda_ata);
+    expect(accountAfter.amount - accountBefore.amount).to.be.eq(1_000_000n);
+    const walletAccount = await spl.getAccount(conn, wallet_ata);
+    expect(walletAccount.delegatedAmount).to.be.eq(0n);
+  });
+
   it("Deposit and withdraw 0.5 SOL from Gateway with ECDSA signature", async () => {
     const balanceBefore = await conn.getBalance(pdaAccount);
     await gatewayProgram.methods
@@ -633,15 +803,14 @@ describe("Gateway", () => {
       wallet.publicKey
     );
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x01]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x01),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
       to.toBuffer(),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -654,7 +823,8 @@ describe("Gateway", () => {
         Array.from(signatureBuffer),
         Number(recoveryParam),
         Array.from(message_hash),
-        nonce
+        nonce,
+        null
       )
       .accounts({
         recipient: to,
@@ -676,15 +846,14 @@ describe("Gateway", () => {
     );
 
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x01]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x01),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
       to.toBuffer(),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -698,7 +867,8 @@ describe("Gateway", () => {
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce.subn(1)
+          nonce.subn(1),
+          null
         )
         .accounts({
           recipient: to,
@@ -711,25 +881,25 @@ describe("Gateway", () => {
     }
   });
 
-  it("Withdraw with wrong msg hash should fail", async () => {
+  it("Withdraw with expired message should fail", async () => {
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
-    const amount = new anchor.BN(500000000);
+    const amount = new anchor.BN(100_000_000);
+    const expiry = new anchor.BN(Math.floor(Date.now() / 1000) - 3600);
     const to = await spl.getAssociatedTokenAddress(
       mint.publicKey,
       wallet.publicKey
     );
 
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x01]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
-      nonce.subn(1).toArrayLike(Buffer, "be", 8), // wrong nonce
+      messageHeader(gatewayProgram.programId, 0x01),
+      nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
       to.toBuffer(),
+      expiryTag(expiry),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -743,7 +913,8 @@ describe("Gateway", () => {
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          expiry
         )
         .accounts({
           recipient: to,
@@ -752,479 +923,205 @@ describe("Gateway", () => {
       throw new Error("Expected error not thrown");
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("MessageHashMismatch");
+      expect(err.message).to.include("MessageExpired.");
     }
   });
 
-  it("Withdraw with wrong signer should fail", async () => {
-    const key = ec.genKeyPair(); // non TSS key pair
+  it("Withdraw with unexpired message", async () => {
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
-    const amount = new anchor.BN(500000000);
+    const amount = new anchor.BN(100_000_000);
+    const expiry = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
     const to = await spl.getAssociatedTokenAddress(
       mint.publicKey,
       wallet.publicKey
     );
 
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x01]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x01),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
       to.toBuffer(),
+      expiryTag(expiry),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = key.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
       s.toArrayLike(Buffer, "be", 32),
     ]);
 
-    try {
-      await gatewayProgram.methods
-        .withdraw(
-          amount,
-          Array.from(signatureBuffer),
-          Number(recoveryParam),
-          Array.from(message_hash),
-          nonce
-        )
-        .accounts({
-          recipient: to,
-        })
-        .rpc();
-      throw new Error("Expected error not thrown");
-    } catch (err) {
-      expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("TSSAuthenticationFailed");
-    }
-  });
-
-  it("Calls execute and onCall", async () => {
-    await connectedProgram.methods.initialize().rpc();
+    const balanceBefore = await conn.getBalance(to);
     await gatewayProgram.methods
-      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
+      .withdraw(
+        amount,
+        Array.from(signatureBuffer),
+        Number(recoveryParam),
+        Array.from(message_hash),
+        nonce,
+        expiry
+      )
+      .accounts({
+        recipient: to,
+      })
       .rpc();
+    const balanceAfter = await conn.getBalance(to);
+    expect(balanceAfter - balanceBefore).to.eq(100_000_000);
+  });
 
-    const randomWallet = anchor.web3.Keypair.generate();
-    const lastMessageData = "execute_sol";
-    const data = Buffer.from(lastMessageData, "utf-8");
-    let seeds = [Buffer.from("connected", "utf-8")];
-    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      seeds,
-      connectedProgram.programId
-    );
-    const amount = new anchor.BN(500000000);
-
-    // signature
+  it("Withdraw with Ethereum-style recovery id", async () => {
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
+    const amount = new anchor.BN(100_000_000);
+    const to = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      wallet.publicKey
+    );
+
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x05]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x01),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
-      connectedProgram.programId.toBuffer(),
-      Buffer.from(Array.from(address)),
-      data,
+      to.toBuffer(),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
       s.toArrayLike(Buffer, "be", 32),
     ]);
 
-    // balances before call
-    const connectedPdaBalanceBefore = await conn.getBalance(
-      connectedPdaAccount
-    );
-    const randomWalletBalanceBefore = await conn.getBalance(
-      randomWallet.publicKey
-    );
-
-    // call the `execute` function in the gateway program
+    const balanceBefore = await conn.getBalance(to);
     await gatewayProgram.methods
-      .execute(
+      .withdraw(
         amount,
-        Array.from(address),
-        data,
         Array.from(signatureBuffer),
-        Number(recoveryParam),
+        Number(recoveryParam) + 27,
         Array.from(message_hash),
-        nonce
+        nonce,
+        null
       )
-      .accountsPartial({
-        // mandatory predefined accounts
-        signer: wallet.publicKey,
-        pda: pdaAccount,
-        destinationProgram: connectedProgram.programId,
-        destinationProgramPda: connectedPdaAccount,
+      .accounts({
+        recipient: to,
       })
-      .remainingAccounts([
-        // accounts coming from withdraw and call msg
-        { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-        { pubkey: pdaAccount, isSigner: false, isWritable: false },
-        {
-          pubkey: anchor.web3.SystemProgram.programId,
-          isSigner: false,
-          isWritable: false,
-        },
-        {
-          pubkey: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
-          isSigner: false,
-          isWritable: false,
-        },
-        { pubkey: randomWallet.publicKey, isSigner: false, isWritable: true },
-      ])
       .rpc();
-
-    const connectedPdaAfter = await connectedProgram.account.pda.fetch(
-      connectedPdaAccount
-    );
-
-    // check connected pda state was updated
-    expect(connectedPdaAfter.lastMessage).to.be.eq(lastMessageData);
-    expect(Array.from(connectedPdaAfter.lastSender)).to.be.deep.eq(
-      Array.from(address)
-    );
-
-    // check balances were updated
-    const connectedPdaBalanceAfter = await conn.getBalance(connectedPdaAccount);
-    const randomWalletBalanceAfter = await conn.getBalance(
-      randomWallet.publicKey
-    );
-
-    expect(connectedPdaBalanceBefore + amount.toNumber() / 2).to.eq(
-      connectedPdaBalanceAfter
-    );
-    expect(randomWalletBalanceBefore + amount.toNumber() / 2).to.eq(
-      randomWalletBalanceAfter
-    );
+    const balanceAfter = await conn.getBalance(to);
+    expect(balanceAfter - balanceBefore).to.eq(100_000_000);
   });
 
-  it("Calls execute and onCall (ALT)", async () => {
-    const provider = anchor.AnchorProvider.env();
-    const conn = provider.connection;
-    const wallet = provider.wallet as anchor.Wallet;
-
-    await gatewayProgram.methods
-      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
-      .rpc();
-
-    const lastMessageData = "execute_sol_ALT";
-    const data = Buffer.from(lastMessageData, "utf-8");
-
-    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("connected", "utf-8")],
-      connectedProgram.programId
-    );
-
-    const amount = new anchor.BN(500_000_000);
-
+  it("Withdraw with invalid recovery id should fail", async () => {
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
+    const amount = new anchor.BN(100_000_000);
+    const to = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      wallet.publicKey
+    );
 
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x05]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x01),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
-      connectedProgram.programId.toBuffer(),
-      Buffer.from(Array.from(address)),
-      data,
+      to.toBuffer(),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
       s.toArrayLike(Buffer, "be", 32),
     ]);
 
-    const connectedPdaBalanceBefore = await conn.getBalance(
-      connectedPdaAccount
-    );
-
-    // Generate 75 random wallets for testing account limits
-    // 75 seems to be around limit with simple connected program, because of CU not accounts number
-    // since gateway is also doing some checks and preparation
-    const randomWallets = Array.from({ length: 75 }, () =>
-      anchor.web3.Keypair.generate()
-    );
-
-    const executeIx = await gatewayProgram.methods
-      .execute(
-        amount,
-        Array.from(address),
-        data,
-        Array.from(signatureBuffer),
-        Number(recoveryParam),
-        Array.from(message_hash),
-        nonce
-      )
-      .accountsPartial({
-        signer: wallet.publicKey,
-        pda: pdaAccount,
-        destinationProgram: connectedProgram.programId,
-        destinationProgramPda: connectedPdaAccount,
-      })
-      .remainingAccounts([
-        { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-        { pubkey: pdaAccount, isSigner: false, isWritable: false },
-        {
-          pubkey: anchor.web3.SystemProgram.programId,
-          isSigner: false,
-          isWritable: false,
-        },
-        {
-          pubkey: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
-          isSigner: false,
-          isWritable: false,
-        },
-        // Add all random wallets
-        ...randomWallets.map((wallet) => ({
-          pubkey: wallet.publicKey,
-          isSigner: false,
-          isWritable: true,
-        })),
-      ])
-      .instruction();
-
-    const currentSlot = await conn.getSlot("finalized");
-    const [createLutIx, lookupTableAddress] =
-      AddressLookupTableProgram.createLookupTable({
-        authority: wallet.publicKey,
-        payer: wallet.publicKey,
-        recentSlot: currentSlot,
-      });
-
-    // extending alt in couple of steps because of tx size over limit
-    const extendLutIx = AddressLookupTableProgram.extendLookupTable({
-      payer: wallet.publicKey,
-      authority: wallet.publicKey,
-      lookupTable: lookupTableAddress,
-      addresses: [
-        pdaAccount,
-        connectedPdaAccount,
-        anchor.web3.SystemProgram.programId,
-        anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
-        // Add first 25 random wallets
-        ...randomWallets.slice(0, 25).map((wallet) => wallet.publicKey),
-      ],
-    });
-
-    await anchor.web3.sendAndConfirmTransaction(
-      conn,
-      new anchor.web3.Transaction().add(createLutIx, extendLutIx),
-      [wallet.payer]
-    );
-
-    // Wait a bit for LUT activation
-    await new Promise((r) => setTimeout(r, 1000));
-
-    const extendLutIx2 = AddressLookupTableProgram.extendLookupTable({
-      payer: wallet.publicKey,
-      authority: wallet.publicKey,
-      lookupTable: lookupTableAddress,
-      addresses: [
-        // Add next 25 random wallets (indices 25-50)
-        ...randomWallets.slice(25, 50).map((wallet) => wallet.publicKey),
-      ],
-    });
-
-    await anchor.web3.sendAndConfirmTransaction(
-      conn,
-      new anchor.web3.Transaction().add(extendLutIx2),
-      [wallet.payer]
-    );
-
-    // Wait a bit for LUT activation
-    await new Promise((r) => setTimeout(r, 1000));
-
-    const extendLutIx3 = AddressLookupTableProgram.extendLookupTable({
-      payer: wallet.publicKey,
-      authority: wallet.publicKey,
-      lookupTable: lookupTableAddress,
-      addresses: [
-        // Add remaining random wallets
-        ...randomWallets.slice(50, 75).map((wallet) => wallet.publicKey),
-      ],
-    });
-
-    await anchor.web3.sendAndConfirmTransaction(
-      conn,
-      new anchor.web3.Transaction().add(extendLutIx3),
-      [wallet.payer]
-    );
-
-    // Wait a bit for LUT activation
-    await new Promise((r) => setTimeout(r, 1000));
-
-    const { value: lutAccount } = await conn.getAddressLookupTable(
-      lookupTableAddress
-    );
-    if (!lutAccount) throw new Error("Failed to fetch LUT");
-
-    // size is 568 bytes
-    const latestBh = await conn.getLatestBlockhash();
-    const v0Message = new TransactionMessage({
-      payerKey: wallet.publicKey,
-      recentBlockhash: latestBh.blockhash,
-      instructions: [executeIx],
-    }).compileToV0Message([lutAccount]);
-
-    const vtx = new VersionedTransaction(v0Message);
-    vtx.sign([wallet.payer]);
-
-    const sig = await conn.sendTransaction(vtx);
-    await conn.confirmTransaction({ signature: sig, ...latestBh }, "confirmed");
-
-    const connectedPdaAfter = await connectedProgram.account.pda.fetch(
-      connectedPdaAccount
-    );
-
-    expect(connectedPdaAfter.lastMessage).to.eq(lastMessageData);
-    expect(Array.from(connectedPdaAfter.lastSender)).to.deep.eq(
-      Array.from(address)
-    );
-
-    const share = Math.floor(amount.toNumber() / 2 / randomWallets.length);
-    let sharesSum = 0;
-    for (let randomWallet of randomWallets) {
-      const randomWalletBalanceAfter = await conn.getBalance(
-        randomWallet.publicKey
-      );
-      expect(randomWalletBalanceAfter).to.eq(share);
-      sharesSum += share;
+    try {
+      await gatewayProgram.methods
+        .withdraw(
+          amount,
+          Array.from(signatureBuffer),
+          2,
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accounts({
+          recipient: to,
+        })
+        .rpc();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("InvalidRecoveryId.");
     }
-
-    const expectedPdaBalanceIncrease = amount.toNumber() - sharesSum;
-    const connectedPdaBalanceAfter = await conn.getBalance(connectedPdaAccount);
-    expect(connectedPdaBalanceBefore + expectedPdaBalanceIncrease).to.eq(
-      connectedPdaBalanceAfter
-    );
   });
 
-  it("Calls execute and onCall (ALT) fails if no ALT is used", async () => {
-    await gatewayProgram.methods
-      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
-      .rpc();
-
-    const lastMessageData = "execute_sol_ALT";
-    const data = Buffer.from(lastMessageData, "utf-8");
-    let seeds = [Buffer.from("connected", "utf-8")];
-    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      seeds,
-      connectedProgram.programId
-    );
-    const amount = new anchor.BN(500000000);
-
-    // signature
+  it("Withdraw with high-s signature should fail", async () => {
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
+    const amount = new anchor.BN(100_000_000);
+    const to = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      wallet.publicKey
+    );
+
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x05]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x01),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
-      connectedProgram.programId.toBuffer(),
-      Buffer.from(Array.from(address)),
-      data,
+      to.toBuffer(),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
+    // the high-s counterpart of a valid signature recovers the same key
+    const highS = ec.n.sub(s);
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
-      s.toArrayLike(Buffer, "be", 32),
+      highS.toArrayLike(Buffer, "be", 32),
     ]);
 
-    // Generate 30 random wallets for testing account limits
-    const randomWallets = Array.from({ length: 30 }, () =>
-      anchor.web3.Keypair.generate()
-    );
-
     try {
-      // call the `execute` function in the gateway program
-      await await gatewayProgram.methods
-        .execute(
+      await gatewayProgram.methods
+        .withdraw(
           amount,
-          Array.from(address),
-          data,
           Array.from(signatureBuffer),
-          Number(recoveryParam),
+          Number(recoveryParam) ^ 1,
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
-        .accountsPartial({
-          signer: wallet.publicKey,
-          pda: pdaAccount,
-          destinationProgram: connectedProgram.programId,
-          destinationProgramPda: connectedPdaAccount,
+        .accounts({
+          recipient: to,
         })
-        .remainingAccounts([
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: anchor.web3.SystemProgram.programId,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
-            isSigner: false,
-            isWritable: false,
-          },
-          // Add all 30 random wallets
-          ...randomWallets.map((wallet) => ({
-            pubkey: wallet.publicKey,
-            isSigner: false,
-            isWritable: true,
-          })),
-        ])
         .rpc();
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+      throw new Error("Expected error not thrown");
     } catch (err) {
-      expect(err.message).to.contains("Transaction too large");
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("MalleableSignature.");
     }
   });
 
-  it("Calls execute and onCall reverts if connected program reverts", async () => {
-    await gatewayProgram.methods
-      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
-      .rpc();
-
-    const randomWallet = anchor.web3.Keypair.generate();
-    const lastMessageData = "revert";
-    const data = Buffer.from(lastMessageData, "utf-8");
-    let seeds = [Buffer.from("connected", "utf-8")];
-    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      seeds,
-      connectedProgram.programId
-    );
-    const amount = new anchor.BN(500000000);
-
-    // signature
+  it("Withdraw with wrong msg hash should fail", async () => {
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
+    const amount = new anchor.BN(500000000);
+    const to = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      wallet.publicKey
+    );
+
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x05]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
-      nonce.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x01),
+      nonce.subn(1).toArrayLike(Buffer, "be", 8), // wrong nonce
       amount.toArrayLike(Buffer, "be", 8),
-      connectedProgram.programId.toBuffer(),
-      Buffer.from(Array.from(address)),
-      data,
+      to.toBuffer(),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -1232,157 +1129,45 @@ describe("Gateway", () => {
     ]);
 
     try {
-      // call the `execute` function in the gateway program
       await gatewayProgram.methods
-        .execute(
+        .withdraw(
           amount,
-          Array.from(address),
-          data,
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
-        .accountsPartial({
-          // mandatory predefined accounts
-          signer: wallet.publicKey,
-          pda: pdaAccount,
-          destinationProgram: connectedProgram.programId,
-          destinationProgramPda: connectedPdaAccount,
-        })
-        .remainingAccounts([
-          // accounts coming from withdraw and call msg
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: anchor.web3.SystemProgram.programId,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
-            isSigner: false,
-            isWritable: false,
-          },
-          { pubkey: randomWallet.publicKey, isSigner: false, isWritable: true },
-        ])
-        .rpc();
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
-    } catch (err) {
-      expect(err).to.be.instanceof(anchor.AnchorError);
-    }
-  });
-
-  it("Calls execute and onCall reverts if wrong msg hash", async () => {
-    await gatewayProgram.methods
-      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
-      .rpc();
-
-    const randomWallet = anchor.web3.Keypair.generate();
-    const lastMessageData = "revert";
-    const data = Buffer.from(lastMessageData, "utf-8");
-    let seeds = [Buffer.from("connected", "utf-8")];
-    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      seeds,
-      connectedProgram.programId
-    );
-    const amount = new anchor.BN(500000000);
-
-    // signature
-    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const nonce = pdaAccountData.nonce;
-    const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x05]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
-      nonce.subn(1).toArrayLike(Buffer, "be", 8), // wrong nonce
-      amount.toArrayLike(Buffer, "be", 8),
-      connectedProgram.programId.toBuffer(),
-      Buffer.from(Array.from(address)),
-      data,
-    ]);
-    const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
-    const { r, s, recoveryParam } = signature;
-    const signatureBuffer = Buffer.concat([
-      r.toArrayLike(Buffer, "be", 32),
-      s.toArrayLike(Buffer, "be", 32),
-    ]);
-
-    try {
-      // call the `execute` function in the gateway program
-      await gatewayProgram.methods
-        .execute(
-          amount,
-          Array.from(address),
-          data,
-          Array.from(signatureBuffer),
-          Number(recoveryParam),
-          Array.from(message_hash),
-          nonce
-        )
-        .accountsPartial({
-          // mandatory predefined accounts
-          signer: wallet.publicKey,
-          pda: pdaAccount,
-          destinationProgram: connectedProgram.programId,
-          destinationProgramPda: connectedPdaAccount,
+        .accounts({
+          recipient: to,
         })
-        .remainingAccounts([
-          // accounts coming from withdraw and call msg
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: anchor.web3.SystemProgram.programId,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
-            isSigner: false,
-            isWritable: false,
-          },
-          { pubkey: randomWallet.publicKey, isSigner: false, isWritable: true },
-        ])
         .rpc();
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+      throw new Error("Expected error not thrown");
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
       expect(err.message).to.include("MessageHashMismatch");
     }
   });
 
-  it("Calls execute and onCall reverts if wrong signer", async () => {
+  it("Withdraw with wrong signer should fail", async () => {
     const key = ec.genKeyPair(); // non TSS key pair
-    await gatewayProgram.methods
-      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
-      .rpc();
-
-    const randomWallet = anchor.web3.Keypair.generate();
-    const lastMessageData = "revert";
-    const data = Buffer.from(lastMessageData, "utf-8");
-    let seeds = [Buffer.from("connected", "utf-8")];
-    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      seeds,
-      connectedProgram.programId
-    );
-    const amount = new anchor.BN(500000000);
-
-    // signature
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
+    const amount = new anchor.BN(500000000);
+    const to = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      wallet.publicKey
+    );
+
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x05]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x01),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
-      connectedProgram.programId.toBuffer(),
-      Buffer.from(Array.from(address)),
-      data,
+      to.toBuffer(),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = key.sign(message_hash, "hex");
+    const signature = key.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -1390,55 +1175,34 @@ describe("Gateway", () => {
     ]);
 
     try {
-      // call the `execute` function in the gateway program
       await gatewayProgram.methods
-        .execute(
+        .withdraw(
           amount,
-          Array.from(address),
-          data,
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
-        .accountsPartial({
-          // mandatory predefined accounts
-          signer: wallet.publicKey,
-          pda: pdaAccount,
-          destinationProgram: connectedProgram.programId,
-          destinationProgramPda: connectedPdaAccount,
+        .accounts({
+          recipient: to,
         })
-        .remainingAccounts([
-          // accounts coming from withdraw and call msg
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: anchor.web3.SystemProgram.programId,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
-            isSigner: false,
-            isWritable: false,
-          },
-          { pubkey: randomWallet.publicKey, isSigner: false, isWritable: true },
-        ])
         .rpc();
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+      throw new Error("Expected error not thrown");
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
       expect(err.message).to.include("TSSAuthenticationFailed");
     }
   });
 
-  it("Calls execute and onCall reverts if signer is passed in remaining accounts", async () => {
+  it("Calls execute and onCall", async () => {
+    await connectedProgram.methods.initialize().rpc();
     await gatewayProgram.methods
       .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
       .rpc();
 
     const randomWallet = anchor.web3.Keypair.generate();
-    const lastMessageData = "revert";
+    const lastMessageData = "execute_sol";
     const data = Buffer.from(lastMessageData, "utf-8");
     let seeds = [Buffer.from("connected", "utf-8")];
     const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
@@ -1450,93 +1214,34 @@ describe("Gateway", () => {
     // signature
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: callerAuthority,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: randomWallet.publicKey, isSigner: false, isWritable: true },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x05]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x05),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
       connectedProgram.programId.toBuffer(),
       Buffer.from(Array.from(address)),
       data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
-    const { r, s, recoveryParam } = signature;
-    const signatureBuffer = Buffer.concat([
-      r.toArrayLike(Buffer, "be", 32),
-      s.toArrayLike(Buffer, "be", 32),
-    ]);
-
-    try {
-      // call the `execute` function in the gateway program
-      await gatewayProgram.methods
-        .execute(
-          amount,
-          Array.from(address),
-          data,
-          Array.from(signatureBuffer),
-          Number(recoveryParam),
-          Array.from(message_hash),
-          nonce
-        )
-        .accountsPartial({
-          // mandatory predefined accounts
-          signer: wallet.publicKey,
-          pda: pdaAccount,
-          destinationProgram: connectedProgram.programId,
-          destinationProgramPda: connectedPdaAccount,
-        })
-        .remainingAccounts([
-          // accounts coming from withdraw and call msg
-          { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: anchor.web3.SystemProgram.programId,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
-            isSigner: false,
-            isWritable: false,
-          },
-          { pubkey: randomWallet.publicKey, isSigner: false, isWritable: true },
-        ])
-        .rpc();
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
-    } catch (err) {
-      expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("InvalidInstructionData");
-    }
-  });
-
-  it("Calls execute and onRevert", async () => {
-    const lastMessageData = "execute_rev_sol";
-    const data = Buffer.from(lastMessageData, "utf-8");
-    let seeds = [Buffer.from("connected", "utf-8")];
-    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      seeds,
-      connectedProgram.programId
-    );
-    const amount = new anchor.BN(500000000);
-
-    // signature
-    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const nonce = pdaAccountData.nonce;
-    const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x08]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
-      nonce.toArrayLike(Buffer, "be", 8),
-      amount.toArrayLike(Buffer, "be", 8),
-      connectedProgram.programId.toBuffer(),
-      random_account.publicKey.toBuffer(),
-      data,
-    ]);
-    const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -1547,17 +1252,21 @@ describe("Gateway", () => {
     const connectedPdaBalanceBefore = await conn.getBalance(
       connectedPdaAccount
     );
+    const randomWalletBalanceBefore = await conn.getBalance(
+      randomWallet.publicKey
+    );
 
     // call the `execute` function in the gateway program
     await gatewayProgram.methods
-      .executeRevert(
+      .execute(
         amount,
-        random_account.publicKey,
+        Array.from(address),
         data,
         Array.from(signatureBuffer),
         Number(recoveryParam),
         Array.from(message_hash),
-        nonce
+        nonce,
+        null
       )
       .accountsPartial({
         // mandatory predefined accounts
@@ -1566,16 +1275,7 @@ describe("Gateway", () => {
         destinationProgram: connectedProgram.programId,
         destinationProgramPda: connectedPdaAccount,
       })
-      .remainingAccounts([
-        // accounts coming from withdraw and call msg
-        { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-        { pubkey: pdaAccount, isSigner: false, isWritable: false },
-        {
-          pubkey: anchor.web3.SystemProgram.programId,
-          isSigner: false,
-          isWritable: false,
-        },
-      ])
+      .remainingAccounts(remainingAccounts)
       .rpc();
 
     const connectedPdaAfter = await connectedProgram.account.pda.fetch(
@@ -1583,191 +1283,168 @@ describe("Gateway", () => {
     );
 
     // check connected pda state was updated
-    expect(connectedPdaAfter.lastRevertMessage).to.be.eq(lastMessageData);
-    expect(connectedPdaAfter.lastRevertSender.toString()).to.be.eq(
-      random_account.publicKey.toString()
+    expect(connectedPdaAfter.lastMessage).to.be.eq(lastMessageData);
+    expect(Array.from(connectedPdaAfter.lastSender)).to.be.deep.eq(
+      Array.from(address)
     );
 
     // check balances were updated
     const connectedPdaBalanceAfter = await conn.getBalance(connectedPdaAccount);
-    expect(connectedPdaBalanceBefore + amount.toNumber()).to.eq(
+    const randomWalletBalanceAfter = await conn.getBalance(
+      randomWallet.publicKey
+    );
+
+    expect(connectedPdaBalanceBefore + amount.toNumber() / 2).to.eq(
       connectedPdaBalanceAfter
     );
+    expect(randomWalletBalanceBefore + amount.toNumber() / 2).to.eq(
+      randomWalletBalanceAfter
+    );
   });
 
-  it("Calls execute and onRevert reverts if connected program reverts", async () => {
+  it("Releases the execute lock for deposits in the same transaction", async () => {
     await gatewayProgram.methods
       .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
       .rpc();
 
-    const lastMessageData = "revert";
-    const data = Buffer.from(lastMessageData, "utf-8");
-    let seeds = [Buffer.from("connected", "utf-8")];
+    const randomWallet = anchor.web3.Keypair.generate();
+    const data = Buffer.from("execute_sol", "utf-8");
     const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      seeds,
+      [Buffer.from("connected", "utf-8")],
       connectedProgram.programId
     );
+    const [rateLimitAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("rate_limit", "utf-8"), spl.NATIVE_MINT.toBuffer()],
+      gatewayProgram.programId
+    );
     const amount = new anchor.BN(500000000);
 
-    // signature
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
+    const remainingAccounts = [
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: callerAuthority,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: randomWallet.publicKey, isSigner: false, isWritable: true },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x08]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x05),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
       connectedProgram.programId.toBuffer(),
-      random_account.publicKey.toBuffer(),
+      Buffer.from(Array.from(address)),
       data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
       s.toArrayLike(Buffer, "be", 32),
     ]);
 
-    try {
-      // call the `execute` function in the gateway program
-      await gatewayProgram.methods
-        .executeRevert(
-          amount,
-          random_account.publicKey,
-          data,
-          Array.from(signatureBuffer),
-          Number(recoveryParam),
-          Array.from(message_hash),
-          nonce
-        )
-        .accountsPartial({
-          // mandatory predefined accounts
-          signer: wallet.publicKey,
-          pda: pdaAccount,
-          destinationProgram: connectedProgram.programId,
-          destinationProgramPda: connectedPdaAccount,
-        })
-        .remainingAccounts([
-          // accounts coming from withdraw and call msg
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: anchor.web3.SystemProgram.programId,
-            isSigner: false,
-            isWritable: false,
-          },
-        ])
-        .rpc();
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
-    } catch (err) {
-      expect(err).to.be.instanceof(anchor.AnchorError);
-    }
-  });
-
-  it("Calls execute and onRevert reverts if wrong msg hash", async () => {
-    await gatewayProgram.methods
-      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
-      .rpc();
+    const executeIx = await gatewayProgram.methods
+      .execute(
+        amount,
+        Array.from(address),
+        data,
+        Array.from(signatureBuffer),
+        Number(recoveryParam),
+        Array.from(message_hash),
+        nonce,
+        null
+      )
+      .accountsPartial({
+        signer: wallet.publicKey,
+        pda: pdaAccount,
+        destinationProgram: connectedProgram.programId,
+        destinationProgramPda: connectedPdaAccount,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+    const depositIx = await connectedProgram.methods
+      .triggerDeposit(
+        new anchor.BN(1_000_000_000),
+        Array.from(address),
+        revertOptions
+      )
+      .accounts({
+        gatewayPda: pdaAccount,
+        gatewayRateLimit: rateLimitAccount,
+        gatewayProgram: gatewayProgram.programId,
+      })
+      .instruction();
 
-    const lastMessageData = "revert";
-    const data = Buffer.from(lastMessageData, "utf-8");
-    let seeds = [Buffer.from("connected", "utf-8")];
-    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      seeds,
-      connectedProgram.programId
+    const balanceBefore = await conn.getBalance(pdaAccount);
+    await anchor.web3.sendAndConfirmTransaction(
+      conn,
+      new anchor.web3.Transaction().add(executeIx, depositIx),
+      [wallet]
     );
-    const amount = new anchor.BN(500000000);
-
-    // signature
-    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const nonce = pdaAccountData.nonce;
-    const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x08]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
-      nonce.subn(1).toArrayLike(Buffer, "be", 8), // wrong nonce
-      amount.toArrayLike(Buffer, "be", 8),
-      connectedProgram.programId.toBuffer(),
-      random_account.publicKey.toBuffer(),
-      data,
-    ]);
-    const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
-    const { r, s, recoveryParam } = signature;
-    const signatureBuffer = Buffer.concat([
-      r.toArrayLike(Buffer, "be", 32),
-      s.toArrayLike(Buffer, "be", 32),
-    ]);
+    const balanceAfter = await conn.getBalance(pdaAccount);
 
-    try {
-      // call the `execute` function in the gateway program
-      await gatewayProgram.methods
-        .executeRevert(
-          amount,
-          random_account.publicKey,
-          data,
-          Array.from(signatureBuffer),
-          Number(recoveryParam),
-          Array.from(message_hash),
-          nonce
-        )
-        .accountsPartial({
-          // mandatory predefined accounts
-          signer: wallet.publicKey,
-          pda: pdaAccount,
-          destinationProgram: connectedProgram.programId,
-          destinationProgramPda: connectedPdaAccount,
-        })
-        .remainingAccounts([
-          // accounts coming from withdraw and call msg
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: anchor.web3.SystemProgram.programId,
-            isSigner: false,
-            isWritable: false,
-          },
-        ])
-        .rpc();
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
-    } catch (err) {
-      expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("MessageHashMismatch");
-    }
+    // deposit of 1 SOL + fee minus the executed amount
+    expect(balanceAfter - balanceBefore).to.eq(
+      1_000_000_000 + 2_000_000 - amount.toNumber()
+    );
+    const pdaAccountDataAfter = await gatewayProgram.account.pda.fetch(
+      pdaAccount
+    );
+    expect(pdaAccountDataAfter.executeLocked).to.be.false;
   });
 
-  it("Calls execute and onRevert reverts if wrong signer", async () => {
-    const key = ec.genKeyPair(); // non TSS key pair
-    await gatewayProgram.methods
-      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
-      .rpc();
-
-    const lastMessageData = "revert";
-    const data = Buffer.from(lastMessageData, "utf-8");
-    let seeds = [Buffer.from("connected", "utf-8")];
+  it("Rejects a call made by the destination program during execute", async () => {
+    const data = Buffer.from("reentrant_call", "utf-8");
     const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      seeds,
+      [Buffer.from("connected", "utf-8")],
       connectedProgram.programId
     );
-    const amount = new anchor.BN(500000000);
+    const amount = new anchor.BN(0);
 
-    // signature
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
+    const remainingAccounts = [
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: callerAuthority,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: gatewayProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x08]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x05),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
       connectedProgram.programId.toBuffer(),
-      random_account.publicKey.toBuffer(),
+      Buffer.from(Array.from(address)),
       data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = key.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -1775,359 +1452,120 @@ describe("Gateway", () => {
     ]);
 
     try {
-      // call the `execute` function in the gateway program
       await gatewayProgram.methods
-        .executeRevert(
+        .execute(
           amount,
-          random_account.publicKey,
+          Array.from(address),
           data,
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
         .accountsPartial({
-          // mandatory predefined accounts
           signer: wallet.publicKey,
           pda: pdaAccount,
           destinationProgram: connectedProgram.programId,
           destinationProgramPda: connectedPdaAccount,
         })
-        .remainingAccounts([
-          // accounts coming from withdraw and call msg
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: anchor.web3.SystemProgram.programId,
-            isSigner: false,
-            isWritable: false,
-          },
-        ])
+        .remainingAccounts(remainingAccounts)
         .rpc();
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+      throw new Error("Expected error not thrown");
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("TSSAuthenticationFailed");
+      expect(err.message).to.include("ExecuteInProgress.");
     }
   });
 
-  it("Calls execute and onRevert reverts if signer is passed in remaining accounts", async () => {
+  it("Calls execute and onCall (ALT)", async () => {
+    const provider = anchor.AnchorProvider.env();
+    const conn = provider.connection;
+    const wallet = provider.wallet as anchor.Wallet;
+
     await gatewayProgram.methods
       .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
       .rpc();
 
-    const lastMessageData = "revert";
+    const lastMessageData = "execute_sol_ALT";
     const data = Buffer.from(lastMessageData, "utf-8");
-    let seeds = [Buffer.from("connected", "utf-8")];
+
     const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      seeds,
+      [Buffer.from("connected", "utf-8")],
       connectedProgram.programId
     );
-    const amount = new anchor.BN(500000000);
-
-    // signature
-    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const nonce = pdaAccountData.nonce;
-    const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x08]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
-      nonce.toArrayLike(Buffer, "be", 8),
-      amount.toArrayLike(Buffer, "be", 8),
-      connectedProgram.programId.toBuffer(),
-      random_account.publicKey.toBuffer(),
-      data,
-    ]);
-    const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
-    const { r, s, recoveryParam } = signature;
-    const signatureBuffer = Buffer.concat([
-      r.toArrayLike(Buffer, "be", 32),
-      s.toArrayLike(Buffer, "be", 32),
-    ]);
-
-    try {
-      // call the `execute` function in the gateway program
-      await gatewayProgram.methods
-        .executeRevert(
-          amount,
-          random_account.publicKey,
-          data,
-          Array.from(signatureBuffer),
-          Number(recoveryParam),
-          Array.from(message_hash),
-          nonce
-        )
-        .accountsPartial({
-          // mandatory predefined accounts
-          signer: wallet.publicKey,
-          pda: pdaAccount,
-          destinationProgram: connectedProgram.programId,
-          destinationProgramPda: connectedPdaAccount,
-        })
-        .remainingAccounts([
-          // accounts coming from withdraw and call msg
-          { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: anchor.web3.SystemProgram.programId,
-            isSigner: false,
-            isWritable: false,
-          },
-        ])
-        .rpc();
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
-    } catch (err) {
-      expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("InvalidInstructionData");
-    }
-  });
 
-  it("Calls execute spl token and onCall", async () => {
-    await connectedSPLProgram.methods.initialize().rpc();
+    const amount = new anchor.BN(500_000_000);
 
-    const randomWallet = anchor.web3.Keypair.generate();
-    let randomWalletAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      randomWallet.publicKey,
-      true
-    );
-    const lastMessageData = "execute_spl";
-    const data = Buffer.from(lastMessageData, "utf-8");
-    let seeds = [Buffer.from("connected", "utf-8")];
-    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      seeds,
-      connectedSPLProgram.programId
-    );
-    let pda_ata = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      pdaAccount,
-      true
-    );
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
     const nonce = pdaAccountData.nonce;
 
-    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      connectedPdaAccount,
-      true
+    // Generate 75 random wallets for testing account limits
+    // 75 seems to be around limit with simple connected program, because of CU not accounts number
+    // since gateway is also doing some checks and preparation
+    const randomWallets = Array.from({ length: 75 }, () =>
+      anchor.web3.Keypair.generate()
     );
 
+    const remainingAccounts = [
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: callerAuthority,
+        isSigner: false,
+        isWritable: false,
+      },
+      // Add all random wallets
+      ...randomWallets.map((wallet) => ({
+        pubkey: wallet.publicKey,
+        isSigner: false,
+        isWritable: true,
+      })),
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x06]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x05),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
-      destinationPdaAta.address.toBuffer(),
+      connectedProgram.programId.toBuffer(),
       Buffer.from(Array.from(address)),
       data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
-    const { r, s, recoveryParam } = signature;
+    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex", { canonical: true });
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
       s.toArrayLike(Buffer, "be", 32),
     ]);
 
-    // call the `execute_spl_token` function in the gateway program
-    await gatewayProgram.methods
-      .executeSplToken(
-        usdcDecimals,
+    const connectedPdaBalanceBefore = await conn.getBalance(
+      connectedPdaAccount
+    );
+
+    const executeIx = await gatewayProgram.methods
+      .execute(
         amount,
         Array.from(address),
         data,
         Array.from(signatureBuffer),
         Number(recoveryParam),
         Array.from(message_hash),
-        nonce
+        nonce,
+        null
       )
       .accountsPartial({
-        // mandatory predefined accounts
         signer: wallet.publicKey,
         pda: pdaAccount,
-        pdaAta: pda_ata,
-        mintAccount: mint.publicKey,
-        destinationProgram: connectedSPLProgram.programId,
+        destinationProgram: connectedProgram.programId,
         destinationProgramPda: connectedPdaAccount,
-        destinationProgramPdaAta: destinationPdaAta.address,
-        tokenProgram: spl.TOKEN_PROGRAM_ID,
-        associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
-        systemProgram: SYSTEM_PROGRAM_ID,
       })
-      .remainingAccounts([
-        // accounts coming from withdraw and call msg
-        { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-        {
-          pubkey: destinationPdaAta.address,
-          isSigner: false,
-          isWritable: true,
-        },
-        { pubkey: mint.publicKey, isSigner: false, isWritable: false },
-        { pubkey: pdaAccount, isSigner: false, isWritable: false },
-        {
-          pubkey: spl.TOKEN_PROGRAM_ID,
-          isSigner: false,
-          isWritable: false,
-        },
-        {
-          pubkey: SYSTEM_PROGRAM_ID,
-          isSigner: false,
-          isWritable: false,
-        },
-        { pubkey: randomWalletAta.address, isSigner: false, isWritable: true },
-      ])
-      .rpc();
-
-    const connectedPdaAfter = await connectedSPLProgram.account.pda.fetch(
-      connectedPdaAccount
-    );
-
-    // check connected pda state was updated
-    expect(connectedPdaAfter.lastMessage).to.be.eq(lastMessageData);
-    expect(Array.from(connectedPdaAfter.lastSender)).to.be.deep.eq(
-      Array.from(address)
-    );
-
-    // check amount was split between connected pda and random wallet ata
-    const destinationPdaAtaAcc = await spl.getAccount(
-      conn,
-      destinationPdaAta.address
-    );
-    const randomWalletAtaAcc = await spl.getAccount(
-      conn,
-      randomWalletAta.address
-    );
-
-    expect(destinationPdaAtaAcc.amount).to.be.eq(250000n);
-    expect(randomWalletAtaAcc.amount).to.be.eq(250000n);
-  });
-
-  it("Calls execute spl token and onCall (ALT)", async () => {
-    const lastMessageData = "execute_spl_ALT";
-    const data = Buffer.from(lastMessageData, "utf-8");
-    let seeds = [Buffer.from("connected", "utf-8")];
-    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
-      seeds,
-      connectedSPLProgram.programId
-    );
-    let pda_ata = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      pdaAccount,
-      true
-    );
-    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
-    const nonce = pdaAccountData.nonce;
-
-    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      connectedPdaAccount,
-      true
-    );
-
-    const destinationPdaAtaAccBefore = await spl.getAccount(
-      conn,
-      destinationPdaAta.address
-    );
-
-    const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x06]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
-      nonce.toArrayLike(Buffer, "be", 8),
-      amount.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
-      destinationPdaAta.address.toBuffer(),
-      Buffer.from(Array.from(address)),
-      data,
-    ]);
-    const message_hash = keccak256(buffer);
-    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex");
-    const signatureBuffer = Buffer.concat([
-      r.toArrayLike(Buffer, "be", 32),
-      s.toArrayLike(Buffer, "be", 32),
-    ]);
-
-    // Generate 45 random wallets for testing account limits with SPL tokens
-    const randomWallets = Array.from({ length: 45 }, () =>
-      anchor.web3.Keypair.generate()
-    );
-
-    // Create ATAs for all random wallets
-    const randomWalletAtas = [];
-    for (const randomWallet of randomWallets) {
-      const ata = await spl.getOrCreateAssociatedTokenAccount(
-        conn,
-        wallet,
-        mint.publicKey,
-        randomWallet.publicKey,
-        true
-      );
-      randomWalletAtas.push(ata);
-    }
-
-    const executeIx = await gatewayProgram.methods
-      .executeSplToken(
-        usdcDecimals,
-        amount,
-        Array.from(address),
-        data,
-        Array.from(signatureBuffer),
-        Number(recoveryParam),
-        Array.from(message_hash),
-        nonce
-      )
-      .accountsPartial({
-        // mandatory predefined accounts
-        signer: wallet.publicKey,
-        pda: pdaAccount,
-        pdaAta: pda_ata,
-        mintAccount: mint.publicKey,
-        destinationProgram: connectedSPLProgram.programId,
-        destinationProgramPda: connectedPdaAccount,
-        destinationProgramPdaAta: destinationPdaAta.address,
-        tokenProgram: spl.TOKEN_PROGRAM_ID,
-        associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
-        systemProgram: SYSTEM_PROGRAM_ID,
-      })
-      .remainingAccounts([
-        // accounts coming from withdraw and call msg
-        { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-        {
-          pubkey: destinationPdaAta.address,
-          isSigner: false,
-          isWritable: true,
-        },
-        { pubkey: mint.publicKey, isSigner: false, isWritable: false },
-        { pubkey: pdaAccount, isSigner: false, isWritable: false },
-        {
-          pubkey: spl.TOKEN_PROGRAM_ID,
-          isSigner: false,
-          isWritable: false,
-        },
-        {
-          pubkey: SYSTEM_PROGRAM_ID,
-          isSigner: false,
-          isWritable: false,
-        },
-        // Add all random wallet ATAs
-        ...randomWalletAtas.map((ata) => ({
-          pubkey: ata.address,
-          isSigner: false,
-          isWritable: true,
-        })),
-      ])
+      .remainingAccounts(remainingAccounts)
       .instruction();
 
     const currentSlot = await conn.getSlot("finalized");
@@ -2144,21 +1582,19 @@ describe("Gateway", () => {
       authority: wallet.publicKey,
       lookupTable: lookupTableAddress,
       addresses: [
-        connectedPdaAccount,
-        destinationPdaAta.address,
-        mint.publicKey,
         pdaAccount,
-        spl.TOKEN_PROGRAM_ID,
-        SYSTEM_PROGRAM_ID,
-        // Add first 15 random wallet ATAs
-        ...randomWalletAtas.slice(0, 15).map((ata) => ata.address),
+        connectedPdaAccount,
+        anchor.web3.SystemProgram.programId,
+        callerAuthority,
+        // Add first 25 random wallets
+        ...randomWallets.slice(0, 25).map((wallet) => wallet.publicKey),
       ],
     });
 
     await anchor.web3.sendAndConfirmTransaction(
       conn,
       new anchor.web3.Transaction().add(createLutIx, extendLutIx),
-      [wallet]
+      [wallet.payer]
     );
 
     // Wait a bit for LUT activation
@@ -2169,15 +1605,34 @@ describe("Gateway", () => {
       authority: wallet.publicKey,
       lookupTable: lookupTableAddress,
       addresses: [
-        // Add remaining random wallet ATAs
-        ...randomWalletAtas.slice(15, 45).map((ata) => ata.address),
+        // Add next 25 random wallets (indices 25-50)
+        ...randomWallets.slice(25, 50).map((wallet) => wallet.publicKey),
       ],
     });
 
     await anchor.web3.sendAndConfirmTransaction(
       conn,
       new anchor.web3.Transaction().add(extendLutIx2),
-      [wallet]
+      [wallet.payer]
+    );
+
+    // Wait a bit for LUT activation
+    await new Promise((r) => setTimeout(r, 1000));
+
+    const extendLutIx3 = AddressLookupTableProgram.extendLookupTable({
+      payer: wallet.publicKey,
+      authority: wallet.publicKey,
+      lookupTable: lookupTableAddress,
+      addresses: [
+        // Add remaining random wallets
+        ...randomWallets.slice(50, 75).map((wallet) => wallet.publicKey),
+      ],
+    });
+
+    await anchor.web3.sendAndConfirmTransaction(
+      conn,
+      new anchor.web3.Transaction().add(extendLutIx3),
+      [wallet.payer]
     );
 
     // Wait a bit for LUT activation
@@ -2188,168 +1643,126 @@ describe("Gateway", () => {
     );
     if (!lutAccount) throw new Error("Failed to fetch LUT");
 
-    const computeBudgetIx =
-      anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({
-        units: 1_000_000,
-      });
-
+    // size is 568 bytes
     const latestBh = await conn.getLatestBlockhash();
     const v0Message = new TransactionMessage({
       payerKey: wallet.publicKey,
       recentBlockhash: latestBh.blockhash,
-      instructions: [computeBudgetIx, executeIx],
+      instructions: [executeIx],
     }).compileToV0Message([lutAccount]);
 
     const vtx = new VersionedTransaction(v0Message);
-    vtx.sign([wallet]);
+    vtx.sign([wallet.payer]);
 
     const sig = await conn.sendTransaction(vtx);
     await conn.confirmTransaction({ signature: sig, ...latestBh }, "confirmed");
 
-    const connectedPdaAfter = await connectedSPLProgram.account.pda.fetch(
+    const connectedPdaAfter = await connectedProgram.account.pda.fetch(
       connectedPdaAccount
     );
 
-    // check connected pda state was updated
-    expect(connectedPdaAfter.lastMessage).to.be.eq(lastMessageData);
-    expect(Array.from(connectedPdaAfter.lastSender)).to.be.deep.eq(
+    expect(connectedPdaAfter.lastMessage).to.eq(lastMessageData);
+    expect(Array.from(connectedPdaAfter.lastSender)).to.deep.eq(
       Array.from(address)
     );
 
-    // check amount was split between destination pda and all random wallet atas
-    const destinationPdaAtaAcc = await spl.getAccount(
-      conn,
-      destinationPdaAta.address
-    );
-
-    const share = Math.floor(amount.toNumber() / 2 / randomWalletAtas.length);
-    let totalShares = 0n;
-    for (const ata of randomWalletAtas) {
-      const randomWalletAtaAcc = await spl.getAccount(conn, ata.address);
-      expect(randomWalletAtaAcc.amount).to.be.eq(BigInt(share));
-      totalShares += randomWalletAtaAcc.amount;
+    const share = Math.floor(amount.toNumber() / 2 / randomWallets.length);
+    let sharesSum = 0;
+    for (let randomWallet of randomWallets) {
+      const randomWalletBalanceAfter = await conn.getBalance(
+        randomWallet.publicKey
+      );
+      expect(randomWalletBalanceAfter).to.eq(share);
+      sharesSum += share;
     }
 
-    const expectedDestinationAmount = BigInt(amount.toNumber()) - totalShares;
-    expect(Number(destinationPdaAtaAcc.amount)).to.be.eq(
-      Number(destinationPdaAtaAccBefore.amount) +
-        Number(expectedDestinationAmount)
+    const expectedPdaBalanceIncrease = amount.toNumber() - sharesSum;
+    const connectedPdaBalanceAfter = await conn.getBalance(connectedPdaAccount);
+    expect(connectedPdaBalanceBefore + expectedPdaBalanceIncrease).to.eq(
+      connectedPdaBalanceAfter
     );
   });
 
-  it("Calls execute spl token and onCall (ALT) fails if no ALT is used", async () => {
-    const lastMessageData = "execute_spl_ALT";
+  it("Calls execute and onCall (ALT) fails if no ALT is used", async () => {
+    await gatewayProgram.methods
+      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
+      .rpc();
+
+    const lastMessageData = "execute_sol_ALT";
     const data = Buffer.from(lastMessageData, "utf-8");
     let seeds = [Buffer.from("connected", "utf-8")];
     const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
       seeds,
-      connectedSPLProgram.programId
-    );
-    let pda_ata = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      pdaAccount,
-      true
+      connectedProgram.programId
     );
+    const amount = new anchor.BN(500000000);
+
+    // signature
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
     const nonce = pdaAccountData.nonce;
-
-    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      connectedPdaAccount,
-      true
+    // Generate 30 random wallets for testing account limits
+    const randomWallets = Array.from({ length: 30 }, () =>
+      anchor.web3.Keypair.generate()
     );
 
+    const remainingAccounts = [
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: callerAuthority,
+        isSigner: false,
+        isWritable: false,
+      },
+      // Add all 30 random wallets
+      ...randomWallets.map((wallet) => ({
+        pubkey: wallet.publicKey,
+        isSigner: false,
+        isWritable: true,
+      })),
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x06]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x05),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
-      destinationPdaAta.address.toBuffer(),
+      connectedProgram.programId.toBuffer(),
       Buffer.from(Array.from(address)),
       data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
       s.toArrayLike(Buffer, "be", 32),
     ]);
 
-    // Generate 45 random wallets for testing account limits with SPL tokens
-    const randomWallets = Array.from({ length: 45 }, () =>
-      anchor.web3.Keypair.generate()
-    );
-
-    // Create ATAs for all random wallets
-    const randomWalletAtas = [];
-    for (const randomWallet of randomWallets) {
-      const ata = await spl.getOrCreateAssociatedTokenAccount(
-        conn,
-        wallet,
-        mint.publicKey,
-        randomWallet.publicKey,
-        true
-      );
-      randomWalletAtas.push(ata);
-    }
-
     try {
-      await gatewayProgram.methods
-        .executeSplToken(
-          usdcDecimals,
+      // call the `execute` function in the gateway program
+      await await gatewayProgram.methods
+        .execute(
           amount,
           Array.from(address),
           data,
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
         .accountsPartial({
-          // mandatory predefined accounts
           signer: wallet.publicKey,
           pda: pdaAccount,
-          pdaAta: pda_ata,
-          mintAccount: mint.publicKey,
-          destinationProgram: connectedSPLProgram.programId,
+          destinationProgram: connectedProgram.programId,
           destinationProgramPda: connectedPdaAccount,
-          destinationProgramPdaAta: destinationPdaAta.address,
-          tokenProgram: spl.TOKEN_PROGRAM_ID,
-          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: SYSTEM_PROGRAM_ID,
         })
-        .remainingAccounts([
-          // accounts coming from withdraw and call msg
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          {
-            pubkey: destinationPdaAta.address,
-            isSigner: false,
-            isWritable: true,
-          },
-          { pubkey: mint.publicKey, isSigner: false, isWritable: false },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: spl.TOKEN_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: SYSTEM_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-          // Add all random wallet ATAs
-          ...randomWalletAtas.map((ata) => ({
-            pubkey: ata.address,
-            isSigner: false,
-            isWritable: true,
-          })),
-        ])
+        .remainingAccounts(remainingAccounts)
         .rpc();
       throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
     } catch (err) {
@@ -2357,52 +1770,52 @@ describe("Gateway", () => {
     }
   });
 
-  it("Calls execute spl token and onCall reverts if connected program reverts", async () => {
+  it("Calls execute and onCall reverts if connected program reverts", async () => {
+    await gatewayProgram.methods
+      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
+      .rpc();
+
     const randomWallet = anchor.web3.Keypair.generate();
-    let randomWalletAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      randomWallet.publicKey,
-      true
-    );
     const lastMessageData = "revert";
     const data = Buffer.from(lastMessageData, "utf-8");
     let seeds = [Buffer.from("connected", "utf-8")];
     const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
       seeds,
-      connectedSPLProgram.programId
-    );
-    let pda_ata = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      pdaAccount,
-      true
+      connectedProgram.programId
     );
+    const amount = new anchor.BN(500000000);
+
+    // signature
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
     const nonce = pdaAccountData.nonce;
-
-    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      connectedPdaAccount,
-      true
-    );
-
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: callerAuthority,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: randomWallet.publicKey, isSigner: false, isWritable: true },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x06]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x05),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
-      destinationPdaAta.address.toBuffer(),
+      connectedProgram.programId.toBuffer(),
       Buffer.from(Array.from(address)),
       data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -2410,57 +1823,26 @@ describe("Gateway", () => {
     ]);
 
     try {
-      // call the `execute_spl_token` function in the gateway program
+      // call the `execute` function in the gateway program
       await gatewayProgram.methods
-        .executeSplToken(
-          usdcDecimals,
+        .execute(
           amount,
           Array.from(address),
           data,
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
         .accountsPartial({
           // mandatory predefined accounts
           signer: wallet.publicKey,
           pda: pdaAccount,
-          pdaAta: pda_ata,
-          mintAccount: mint.publicKey,
-          destinationProgram: connectedSPLProgram.programId,
+          destinationProgram: connectedProgram.programId,
           destinationProgramPda: connectedPdaAccount,
-          destinationProgramPdaAta: destinationPdaAta.address,
-          tokenProgram: spl.TOKEN_PROGRAM_ID,
-          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: SYSTEM_PROGRAM_ID,
         })
-        .remainingAccounts([
-          // accounts coming from withdraw and call msg
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          {
-            pubkey: destinationPdaAta.address,
-            isSigner: false,
-            isWritable: true,
-          },
-          { pubkey: mint.publicKey, isSigner: false, isWritable: false },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: spl.TOKEN_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: SYSTEM_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: randomWalletAta.address,
-            isSigner: false,
-            isWritable: true,
-          },
-        ])
+        .remainingAccounts(remainingAccounts)
         .rpc();
       throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
     } catch (err) {
@@ -2468,52 +1850,52 @@ describe("Gateway", () => {
     }
   });
 
-  it("Calls execute spl token and onCall reverts if signer is passed in remaining accounts", async () => {
+  it("Calls execute and onCall reverts if wrong msg hash", async () => {
+    await gatewayProgram.methods
+      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
+      .rpc();
+
     const randomWallet = anchor.web3.Keypair.generate();
-    let randomWalletAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      randomWallet.publicKey,
-      true
-    );
     const lastMessageData = "revert";
     const data = Buffer.from(lastMessageData, "utf-8");
     let seeds = [Buffer.from("connected", "utf-8")];
     const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
       seeds,
-      connectedSPLProgram.programId
-    );
-    let pda_ata = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      pdaAccount,
-      true
+      connectedProgram.programId
     );
+    const amount = new anchor.BN(500000000);
+
+    // signature
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
     const nonce = pdaAccountData.nonce;
-
-    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      connectedPdaAccount,
-      true
-    );
-
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: callerAuthority,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: randomWallet.publicKey, isSigner: false, isWritable: true },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x06]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
-      nonce.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x05),
+      nonce.subn(1).toArrayLike(Buffer, "be", 8), // wrong nonce
       amount.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
-      destinationPdaAta.address.toBuffer(),
+      connectedProgram.programId.toBuffer(),
       Buffer.from(Array.from(address)),
       data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -2521,112 +1903,81 @@ describe("Gateway", () => {
     ]);
 
     try {
-      // call the `execute_spl_token` function in the gateway program
+      // call the `execute` function in the gateway program
       await gatewayProgram.methods
-        .executeSplToken(
-          usdcDecimals,
+        .execute(
           amount,
           Array.from(address),
           data,
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
         .accountsPartial({
           // mandatory predefined accounts
           signer: wallet.publicKey,
           pda: pdaAccount,
-          pdaAta: pda_ata,
-          mintAccount: mint.publicKey,
-          destinationProgram: connectedSPLProgram.programId,
+          destinationProgram: connectedProgram.programId,
           destinationProgramPda: connectedPdaAccount,
-          destinationProgramPdaAta: destinationPdaAta.address,
-          tokenProgram: spl.TOKEN_PROGRAM_ID,
-          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: SYSTEM_PROGRAM_ID,
         })
-        .remainingAccounts([
-          // accounts coming from withdraw and call msg
-          { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          {
-            pubkey: destinationPdaAta.address,
-            isSigner: false,
-            isWritable: true,
-          },
-          { pubkey: mint.publicKey, isSigner: false, isWritable: false },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: spl.TOKEN_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: SYSTEM_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: randomWalletAta.address,
-            isSigner: false,
-            isWritable: true,
-          },
-        ])
+        .remainingAccounts(remainingAccounts)
         .rpc();
       throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("InvalidInstructionData");
+      expect(err.message).to.include("MessageHashMismatch");
     }
   });
 
-  it("Calls execute spl token and onCall reverts if wrong msg hash", async () => {
+  it("Calls execute and onCall reverts if wrong signer", async () => {
+    const key = ec.genKeyPair(); // non TSS key pair
+    await gatewayProgram.methods
+      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
+      .rpc();
+
     const randomWallet = anchor.web3.Keypair.generate();
-    let randomWalletAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      randomWallet.publicKey,
-      true
-    );
     const lastMessageData = "revert";
     const data = Buffer.from(lastMessageData, "utf-8");
     let seeds = [Buffer.from("connected", "utf-8")];
     const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
       seeds,
-      connectedSPLProgram.programId
-    );
-    let pda_ata = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      pdaAccount,
-      true
+      connectedProgram.programId
     );
+    const amount = new anchor.BN(500000000);
+
+    // signature
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
     const nonce = pdaAccountData.nonce;
-
-    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      connectedPdaAccount,
-      true
-    );
-
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: callerAuthority,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: randomWallet.publicKey, isSigner: false, isWritable: true },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x06]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
-      nonce.subn(1).toArrayLike(Buffer, "be", 8), // wrong nonce
+      messageHeader(gatewayProgram.programId, 0x05),
+      nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
-      destinationPdaAta.address.toBuffer(),
+      connectedProgram.programId.toBuffer(),
       Buffer.from(Array.from(address)),
       data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = key.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -2634,112 +1985,81 @@ describe("Gateway", () => {
     ]);
 
     try {
-      // call the `execute_spl_token` function in the gateway program
+      // call the `execute` function in the gateway program
       await gatewayProgram.methods
-        .executeSplToken(
-          usdcDecimals,
+        .execute(
           amount,
           Array.from(address),
           data,
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
         .accountsPartial({
           // mandatory predefined accounts
           signer: wallet.publicKey,
           pda: pdaAccount,
-          pdaAta: pda_ata,
-          mintAccount: mint.publicKey,
-          destinationProgram: connectedSPLProgram.programId,
+          destinationProgram: connectedProgram.programId,
           destinationProgramPda: connectedPdaAccount,
-          destinationProgramPdaAta: destinationPdaAta.address,
-          tokenProgram: spl.TOKEN_PROGRAM_ID,
-          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: SYSTEM_PROGRAM_ID,
         })
-        .remainingAccounts([
-          // accounts coming from withdraw and call msg
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          {
-            pubkey: destinationPdaAta.address,
-            isSigner: false,
-            isWritable: true,
-          },
-          { pubkey: mint.publicKey, isSigner: false, isWritable: false },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: spl.TOKEN_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: SYSTEM_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: randomWalletAta.address,
-            isSigner: false,
-            isWritable: true,
-          },
-        ])
+        .remainingAccounts(remainingAccounts)
         .rpc();
       throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("MessageHashMismatch");
+      expect(err.message).to.include("TSSAuthenticationFailed");
     }
   });
 
-  it("Calls execute spl token and onCall reverts if wrong signer", async () => {
-    const key = ec.genKeyPair(); // non TSS key pair
+  it("Calls execute and onCall reverts if signer is passed in remaining accounts", async () => {
+    await gatewayProgram.methods
+      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
+      .rpc();
+
     const randomWallet = anchor.web3.Keypair.generate();
-    let randomWalletAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      randomWallet.publicKey,
-      true
-    );
     const lastMessageData = "revert";
     const data = Buffer.from(lastMessageData, "utf-8");
     let seeds = [Buffer.from("connected", "utf-8")];
     const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
       seeds,
-      connectedSPLProgram.programId
-    );
-    let pda_ata = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      pdaAccount,
-      true
+      connectedProgram.programId
     );
+    const amount = new anchor.BN(500000000);
+
+    // signature
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
     const nonce = pdaAccountData.nonce;
-
-    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      connectedPdaAccount,
-      true
-    );
-
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: callerAuthority,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: randomWallet.publicKey, isSigner: false, isWritable: true },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x06]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x05),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
-      destinationPdaAta.address.toBuffer(),
+      connectedProgram.programId.toBuffer(),
       Buffer.from(Array.from(address)),
       data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = key.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -2747,224 +2067,158 @@ describe("Gateway", () => {
     ]);
 
     try {
-      // call the `execute_spl_token` function in the gateway program
+      // call the `execute` function in the gateway program
       await gatewayProgram.methods
-        .executeSplToken(
-          usdcDecimals,
+        .execute(
           amount,
           Array.from(address),
           data,
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
         .accountsPartial({
           // mandatory predefined accounts
           signer: wallet.publicKey,
           pda: pdaAccount,
-          pdaAta: pda_ata,
-          mintAccount: mint.publicKey,
-          destinationProgram: connectedSPLProgram.programId,
+          destinationProgram: connectedProgram.programId,
           destinationProgramPda: connectedPdaAccount,
-          destinationProgramPdaAta: destinationPdaAta.address,
-          tokenProgram: spl.TOKEN_PROGRAM_ID,
-          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: SYSTEM_PROGRAM_ID,
         })
-        .remainingAccounts([
-          // accounts coming from withdraw and call msg
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          {
-            pubkey: destinationPdaAta.address,
-            isSigner: false,
-            isWritable: true,
-          },
-          { pubkey: mint.publicKey, isSigner: false, isWritable: false },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: spl.TOKEN_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: SYSTEM_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: randomWalletAta.address,
-            isSigner: false,
-            isWritable: true,
-          },
-        ])
+        .remainingAccounts(remainingAccounts)
         .rpc();
       throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("TSSAuthenticationFailed");
+      expect(err.message).to.include("InvalidInstructionData");
     }
   });
 
-  it("Calls execute spl token and onRevert", async () => {
-    const randomWallet = anchor.web3.Keypair.generate();
-    const lastMessageData = "execute_spl";
+  it("Calls execute and onRevert", async () => {
+    const lastMessageData = "execute_rev_sol";
     const data = Buffer.from(lastMessageData, "utf-8");
     let seeds = [Buffer.from("connected", "utf-8")];
     const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
       seeds,
-      connectedSPLProgram.programId
-    );
-    let pda_ata = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      pdaAccount,
-      true
+      connectedProgram.programId
     );
+    const amount = new anchor.BN(500000000);
+
+    // signature
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
     const nonce = pdaAccountData.nonce;
-
-    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      connectedPdaAccount,
-      true
-    );
-
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x09]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x08),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
-      destinationPdaAta.address.toBuffer(),
-      randomWallet.publicKey.toBuffer(),
+      connectedProgram.programId.toBuffer(),
+      random_account.publicKey.toBuffer(),
       data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
       s.toArrayLike(Buffer, "be", 32),
     ]);
 
-    // balances before
-    const destinationPdaAtaAccBefore = await spl.getAccount(
-      conn,
-      destinationPdaAta.address
+    // balances before call
+    const connectedPdaBalanceBefore = await conn.getBalance(
+      connectedPdaAccount
     );
 
-    // call the `execute_spl_token_revert` function in the gateway program
+    // call the `execute` function in the gateway program
     await gatewayProgram.methods
-      .executeSplTokenRevert(
-        usdcDecimals,
+      .executeRevert(
         amount,
-        randomWallet.publicKey,
+        random_account.publicKey,
         data,
         Array.from(signatureBuffer),
         Number(recoveryParam),
         Array.from(message_hash),
-        nonce
+        nonce,
+        null
       )
       .accountsPartial({
         // mandatory predefined accounts
         signer: wallet.publicKey,
         pda: pdaAccount,
-        pdaAta: pda_ata,
-        mintAccount: mint.publicKey,
-        destinationProgram: connectedSPLProgram.programId,
+        destinationProgram: connectedProgram.programId,
         destinationProgramPda: connectedPdaAccount,
-        destinationProgramPdaAta: destinationPdaAta.address,
-        tokenProgram: spl.TOKEN_PROGRAM_ID,
-        associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
-        systemProgram: SYSTEM_PROGRAM_ID,
       })
-      .remainingAccounts([
-        // accounts coming from revert msg
-        { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-        {
-          pubkey: destinationPdaAta.address,
-          isSigner: false,
-          isWritable: true,
-        },
-        { pubkey: mint.publicKey, isSigner: false, isWritable: false },
-        { pubkey: pdaAccount, isSigner: false, isWritable: false },
-        {
-          pubkey: spl.TOKEN_PROGRAM_ID,
-          isSigner: false,
-          isWritable: false,
-        },
-        {
-          pubkey: SYSTEM_PROGRAM_ID,
-          isSigner: false,
-          isWritable: false,
-        },
-      ])
+      .remainingAccounts(remainingAccounts)
       .rpc();
 
-    const connectedPdaAfter = await connectedSPLProgram.account.pda.fetch(
+    const connectedPdaAfter = await connectedProgram.account.pda.fetch(
       connectedPdaAccount
     );
 
     // check connected pda state was updated
     expect(connectedPdaAfter.lastRevertMessage).to.be.eq(lastMessageData);
-    expect(connectedPdaAfter.lastRevertSender.toString()).to.be.deep.eq(
-      randomWallet.publicKey.toString()
+    expect(connectedPdaAfter.lastRevertSender.toString()).to.be.eq(
+      random_account.publicKey.toString()
     );
 
     // check balances were updated
-    const destinationPdaAtaAcc = await spl.getAccount(
-      conn,
-      destinationPdaAta.address
-    );
-
-    expect(Number(destinationPdaAtaAcc.amount.toString())).to.be.eq(
-      Number(destinationPdaAtaAccBefore.amount) + amount.toNumber()
+    const connectedPdaBalanceAfter = await conn.getBalance(connectedPdaAccount);
+    expect(connectedPdaBalanceBefore + amount.toNumber()).to.eq(
+      connectedPdaBalanceAfter
     );
   });
 
-  it("Calls execute spl token and onRevert reverts if connected program reverts", async () => {
-    const randomWallet = anchor.web3.Keypair.generate();
-    const lastMessageData = "execute_spl_revert";
+  it("Calls execute and onRevert reverts if connected program reverts", async () => {
+    await gatewayProgram.methods
+      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
+      .rpc();
+
+    const lastMessageData = "revert";
     const data = Buffer.from(lastMessageData, "utf-8");
     let seeds = [Buffer.from("connected", "utf-8")];
     const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
       seeds,
-      connectedSPLProgram.programId
-    );
-    let pda_ata = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      pdaAccount,
-      true
+      connectedProgram.programId
     );
+    const amount = new anchor.BN(500000000);
+
+    // signature
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
     const nonce = pdaAccountData.nonce;
-
-    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      connectedPdaAccount,
-      true
-    );
-
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x09]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x08),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
-      destinationPdaAta.address.toBuffer(),
-      randomWallet.publicKey.toBuffer(),
+      connectedProgram.programId.toBuffer(),
+      random_account.publicKey.toBuffer(),
       data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -2972,52 +2226,26 @@ describe("Gateway", () => {
     ]);
 
     try {
-      // call the `execute_spl_token_revert` function in the gateway program
+      // call the `execute` function in the gateway program
       await gatewayProgram.methods
-        .executeSplTokenRevert(
-          usdcDecimals,
+        .executeRevert(
           amount,
-          randomWallet.publicKey,
+          random_account.publicKey,
           data,
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
         .accountsPartial({
           // mandatory predefined accounts
           signer: wallet.publicKey,
           pda: pdaAccount,
-          pdaAta: pda_ata,
-          mintAccount: mint.publicKey,
-          destinationProgram: connectedSPLProgram.programId,
+          destinationProgram: connectedProgram.programId,
           destinationProgramPda: connectedPdaAccount,
-          destinationProgramPdaAta: destinationPdaAta.address,
-          tokenProgram: spl.TOKEN_PROGRAM_ID,
-          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: SYSTEM_PROGRAM_ID,
         })
-        .remainingAccounts([
-          // accounts coming from revert msg
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          {
-            pubkey: destinationPdaAta.address,
-            isSigner: false,
-            isWritable: true,
-          },
-          { pubkey: mint.publicKey, isSigner: false, isWritable: false },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: spl.TOKEN_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: SYSTEM_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-        ])
+        .remainingAccounts(remainingAccounts)
         .rpc();
       throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
     } catch (err) {
@@ -3025,45 +2253,45 @@ describe("Gateway", () => {
     }
   });
 
-  it("Calls execute spl token and onRevert reverts if signer is passed in remaining accounts", async () => {
-    const randomWallet = anchor.web3.Keypair.generate();
-    const lastMessageData = "execute_spl";
+  it("Calls execute and onRevert reverts if wrong msg hash", async () => {
+    await gatewayProgram.methods
+      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
+      .rpc();
+
+    const lastMessageData = "revert";
     const data = Buffer.from(lastMessageData, "utf-8");
     let seeds = [Buffer.from("connected", "utf-8")];
     const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
       seeds,
-      connectedSPLProgram.programId
-    );
-    let pda_ata = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      pdaAccount,
-      true
+      connectedProgram.programId
     );
+    const amount = new anchor.BN(500000000);
+
+    // signature
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
     const nonce = pdaAccountData.nonce;
-
-    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      connectedPdaAccount,
-      true
-    );
-
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x09]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
-      nonce.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x08),
+      nonce.subn(1).toArrayLike(Buffer, "be", 8), // wrong nonce
       amount.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
-      destinationPdaAta.address.toBuffer(),
-      randomWallet.publicKey.toBuffer(),
+      connectedProgram.programId.toBuffer(),
+      random_account.publicKey.toBuffer(),
       data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -3071,99 +2299,74 @@ describe("Gateway", () => {
     ]);
 
     try {
-      // call the `execute_spl_token_revert` function in the gateway program
+      // call the `execute` function in the gateway program
       await gatewayProgram.methods
-        .executeSplTokenRevert(
-          usdcDecimals,
+        .executeRevert(
           amount,
-          randomWallet.publicKey,
+          random_account.publicKey,
           data,
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
         .accountsPartial({
           // mandatory predefined accounts
           signer: wallet.publicKey,
           pda: pdaAccount,
-          pdaAta: pda_ata,
-          mintAccount: mint.publicKey,
-          destinationProgram: connectedSPLProgram.programId,
+          destinationProgram: connectedProgram.programId,
           destinationProgramPda: connectedPdaAccount,
-          destinationProgramPdaAta: destinationPdaAta.address,
-          tokenProgram: spl.TOKEN_PROGRAM_ID,
-          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: SYSTEM_PROGRAM_ID,
         })
-        .remainingAccounts([
-          // accounts coming from revert msg
-          { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          {
-            pubkey: destinationPdaAta.address,
-            isSigner: false,
-            isWritable: true,
-          },
-          { pubkey: mint.publicKey, isSigner: false, isWritable: false },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: spl.TOKEN_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: SYSTEM_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-        ])
+        .remainingAccounts(remainingAccounts)
         .rpc();
       throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
     } catch (err) {
-      expect(err.message).to.include("InvalidInstructionData");
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("MessageHashMismatch");
     }
   });
 
-  it("Calls execute spl token and onRevert reverts if wrong message hash", async () => {
-    const randomWallet = anchor.web3.Keypair.generate();
-    const lastMessageData = "execute_spl";
+  it("Calls execute and onRevert reverts if wrong signer", async () => {
+    const key = ec.genKeyPair(); // non TSS key pair
+    await gatewayProgram.methods
+      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
+      .rpc();
+
+    const lastMessageData = "revert";
     const data = Buffer.from(lastMessageData, "utf-8");
     let seeds = [Buffer.from("connected", "utf-8")];
     const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
       seeds,
-      connectedSPLProgram.programId
-    );
-    let pda_ata = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      pdaAccount,
-      true
+      connectedProgram.programId
     );
+    const amount = new anchor.BN(500000000);
+
+    // signature
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
     const nonce = pdaAccountData.nonce;
-
-    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      connectedPdaAccount,
-      true
-    );
-
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x09]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
-      nonce.subn(1).toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x08),
+      nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
-      destinationPdaAta.address.toBuffer(),
-      randomWallet.publicKey.toBuffer(),
+      connectedProgram.programId.toBuffer(),
+      random_account.publicKey.toBuffer(),
       data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = key.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -3171,99 +2374,74 @@ describe("Gateway", () => {
     ]);
 
     try {
-      // call the `execute_spl_token_revert` function in the gateway program
+      // call the `execute` function in the gateway program
       await gatewayProgram.methods
-        .executeSplTokenRevert(
-          usdcDecimals,
+        .executeRevert(
           amount,
-          randomWallet.publicKey,
+          random_account.publicKey,
           data,
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
         .accountsPartial({
           // mandatory predefined accounts
           signer: wallet.publicKey,
           pda: pdaAccount,
-          pdaAta: pda_ata,
-          mintAccount: mint.publicKey,
-          destinationProgram: connectedSPLProgram.programId,
+          destinationProgram: connectedProgram.programId,
           destinationProgramPda: connectedPdaAccount,
-          destinationProgramPdaAta: destinationPdaAta.address,
-          tokenProgram: spl.TOKEN_PROGRAM_ID,
-          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: SYSTEM_PROGRAM_ID,
         })
-        .remainingAccounts([
-          // accounts coming from revert msg
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          {
-            pubkey: destinationPdaAta.address,
-            isSigner: false,
-            isWritable: true,
-          },
-          { pubkey: mint.publicKey, isSigner: false, isWritable: false },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: spl.TOKEN_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: SYSTEM_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-        ])
+        .remainingAccounts(remainingAccounts)
         .rpc();
       throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
     } catch (err) {
-      expect(err.message).to.include("MessageHashMismatch");
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("TSSAuthenticationFailed");
     }
   });
 
-  it("Calls execute spl token and onRevert reverts if wrong signer", async () => {
-    const key = ec.genKeyPair(); // non TSS key pair
-    const randomWallet = anchor.web3.Keypair.generate();
-    const lastMessageData = "execute_spl";
+  it("Calls execute and onRevert reverts if signer is passed in remaining accounts", async () => {
+    await gatewayProgram.methods
+      .deposit(new anchor.BN(1_000_000_000), Array.from(address), revertOptions)
+      .rpc();
+
+    const lastMessageData = "revert";
     const data = Buffer.from(lastMessageData, "utf-8");
     let seeds = [Buffer.from("connected", "utf-8")];
     const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
       seeds,
-      connectedSPLProgram.programId
-    );
-    let pda_ata = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      pdaAccount,
-      true
+      connectedProgram.programId
     );
+    const amount = new anchor.BN(500000000);
+
+    // signature
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
     const nonce = pdaAccountData.nonce;
-
-    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
-      conn,
-      wallet,
-      mint.publicKey,
-      connectedPdaAccount,
-      true
-    );
-
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x09]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x08),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
-      destinationPdaAta.address.toBuffer(),
-      randomWallet.publicKey.toBuffer(),
+      connectedProgram.programId.toBuffer(),
+      random_account.publicKey.toBuffer(),
       data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = key.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
@@ -3271,97 +2449,52 @@ describe("Gateway", () => {
     ]);
 
     try {
-      // call the `execute_spl_token_revert` function in the gateway program
+      // call the `execute` function in the gateway program
       await gatewayProgram.methods
-        .executeSplTokenRevert(
-          usdcDecimals,
+        .executeRevert(
           amount,
-          randomWallet.publicKey,
+          random_account.publicKey,
           data,
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
         .accountsPartial({
           // mandatory predefined accounts
           signer: wallet.publicKey,
           pda: pdaAccount,
-          pdaAta: pda_ata,
-          mintAccount: mint.publicKey,
-          destinationProgram: connectedSPLProgram.programId,
+          destinationProgram: connectedProgram.programId,
           destinationProgramPda: connectedPdaAccount,
-          destinationProgramPdaAta: destinationPdaAta.address,
-          tokenProgram: spl.TOKEN_PROGRAM_ID,
-          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: SYSTEM_PROGRAM_ID,
         })
-        .remainingAccounts([
-          // accounts coming from revert msg
-          { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
-          {
-            pubkey: destinationPdaAta.address,
-            isSigner: false,
-            isWritable: true,
-          },
-          { pubkey: mint.publicKey, isSigner: false, isWritable: false },
-          { pubkey: pdaAccount, isSigner: false, isWritable: false },
-          {
-            pubkey: spl.TOKEN_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-          {
-            pubkey: SYSTEM_PROGRAM_ID,
-            isSigner: false,
-            isWritable: false,
-          },
-        ])
+        .remainingAccounts(remainingAccounts)
         .rpc();
       throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
     } catch (err) {
-      expect(err.message).to.include("TSSAuthenticationFailed");
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("InvalidInstructionData");
     }
   });
 
-  it("Withdraw SPL token to a non-existent account should succeed by creating it", async () => {
-    let rentPayerPdaBal0 = await conn.getBalance(pdaAccount);
-    let pda_ata = await spl.getAssociatedTokenAddress(
+  it("Calls execute spl token and onCall", async () => {
+    await connectedSPLProgram.methods.initialize().rpc();
+
+    const randomWallet = anchor.web3.Keypair.generate();
+    let randomWalletAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
       mint.publicKey,
-      pdaAccount,
+      randomWallet.publicKey,
       true
     );
-    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
-    const nonce = pdaAccountData.nonce;
-    const wallet2 = anchor.web3.Keypair.generate();
-    const to = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      wallet2.publicKey
-    );
-
-    let to_ata_bal = await conn.getBalance(to);
-    expect(to_ata_bal).to.be.eq(0); // the new ata account (owned by wallet2) should be non-existent;
-    await withdrawSplToken(
-      mint,
-      usdcDecimals,
-      amount,
-      nonce,
-      pda_ata,
-      to,
-      wallet2.publicKey,
-      gatewayProgram
+    const lastMessageData = "execute_spl";
+    const data = Buffer.from(lastMessageData, "utf-8");
+    let seeds = [Buffer.from("connected", "utf-8")];
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      seeds,
+      connectedSPLProgram.programId
     );
-    to_ata_bal = await conn.getBalance(to);
-    expect(to_ata_bal).to.be.gt(2_000_000); // the new ata account (owned by wallet2) should be created
-
-    // pda should have reduced balance
-    let rentPayerPdaBal1 = await conn.getBalance(pdaAccount);
-    // expected reimbursement to be gas fee (5000 lamports) + ATA creation cost 2039280 lamports
-    expect(rentPayerPdaBal0 - rentPayerPdaBal1).to.be.eq(to_ata_bal + 5000); // rentPayer pays rent
-  });
-
-  it("Withdraw SPL token with wrong nonce should fail", async () => {
     let pda_ata = await spl.getAssociatedTokenAddress(
       mint.publicKey,
       pdaAccount,
@@ -3370,419 +2503,3839 @@ describe("Gateway", () => {
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const amount = new anchor.BN(500_000);
     const nonce = pdaAccountData.nonce;
-    const wallet2 = anchor.web3.Keypair.generate();
-    const to = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      wallet2.publicKey
-    );
-
-    try {
-      await withdrawSplToken(
-        mint,
-        usdcDecimals,
-        amount,
-        nonce.subn(1),
-        pda_ata,
-        to,
-        wallet2.publicKey,
-        gatewayProgram
-      );
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
-    } catch (err) {
-      expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("NonceMismatch.");
-    }
-  });
 
-  it("Withdraw SPL token with wrong msg hash should fail", async () => {
-    let pda_ata = await spl.getAssociatedTokenAddress(
+    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
       mint.publicKey,
-      pdaAccount,
+      connectedPdaAccount,
       true
     );
-    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
-    const nonce = pdaAccountData.nonce;
-    const wallet2 = anchor.web3.Keypair.generate();
-    const to = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      wallet2.publicKey
-    );
 
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      {
+        pubkey: destinationPdaAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: spl.TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: SYSTEM_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: randomWalletAta.address, isSigner: false, isWritable: true },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x02]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
-      nonce.subn(1).toArrayLike(Buffer, "be", 8), // wrong nonce
+      messageHeader(gatewayProgram.programId, 0x06),
+      nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
       mint.publicKey.toBuffer(),
-      to.toBuffer(),
+      destinationPdaAta.address.toBuffer(),
+      Buffer.from(Array.from(address)),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
       s.toArrayLike(Buffer, "be", 32),
     ]);
-    try {
-      await gatewayProgram.methods
-        .withdrawSplToken(
-          usdcDecimals,
-          amount,
-          Array.from(signatureBuffer),
-          Number(recoveryParam),
-          Array.from(message_hash),
-          nonce
-        )
-        .accounts({
-          pdaAta: pda_ata,
-          mintAccount: mint.publicKey,
-          recipientAta: to,
-          recipient: wallet2.publicKey,
-        })
-        .rpc({ commitment: "processed" });
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
-    } catch (err) {
-      expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("MessageHashMismatch");
-    }
-  });
 
-  it("Withdraw SPL token with wrong signer should fail", async () => {
-    const key = ec.genKeyPair(); // non TSS key pair
-    let pda_ata = await spl.getAssociatedTokenAddress(
-      mint.publicKey,
-      pdaAccount,
-      true
-    );
-    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const amount = new anchor.BN(500_000);
+    // call the `execute_spl_token` function in the gateway program
+    await gatewayProgram.methods
+      .executeSplToken(
+        usdcDecimals,
+        amount,
+        Array.from(address),
+        data,
+        Array.from(signatureBuffer),
+        Number(recoveryParam),
+        Array.from(message_hash),
+        nonce,
+        null
+      )
+      .accountsPartial({
+        // mandatory predefined accounts
+        signer: wallet.publicKey,
+        pda: pdaAccount,
+        pdaAta: pda_ata,
+        mintAccount: mint.publicKey,
+        destinationProgram: connectedSPLProgram.programId,
+        destinationProgramPda: connectedPdaAccount,
+        destinationProgramPdaAta: destinationPdaAta.address,
+        tokenProgram: spl.TOKEN_PROGRAM_ID,
+        associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+
+    const connectedPdaAfter = await connectedSPLProgram.account.pda.fetch(
+      connectedPdaAccount
+    );
+
+    // check connected pda state was updated
+    expect(connectedPdaAfter.lastMessage).to.be.eq(lastMessageData);
+    expect(Array.from(connectedPdaAfter.lastSender)).to.be.deep.eq(
+      Array.from(address)
+    );
+
+    // check amount was split between connected pda and random wallet ata
+    const destinationPdaAtaAcc = await spl.getAccount(
+      conn,
+      destinationPdaAta.address
+    );
+    const randomWalletAtaAcc = await spl.getAccount(
+      conn,
+      randomWalletAta.address
+    );
+
+    expect(destinationPdaAtaAcc.amount).to.be.eq(250000n);
+    expect(randomWalletAtaAcc.amount).to.be.eq(250000n);
+  });
+
+  it("Calls execute spl token and onCall (ALT)", async () => {
+    const lastMessageData = "execute_spl_ALT";
+    const data = Buffer.from(lastMessageData, "utf-8");
+    let seeds = [Buffer.from("connected", "utf-8")];
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      seeds,
+      connectedSPLProgram.programId
+    );
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+
+    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      connectedPdaAccount,
+      true
+    );
+
+    const destinationPdaAtaAccBefore = await spl.getAccount(
+      conn,
+      destinationPdaAta.address
+    );
+
+    // Generate 45 random wallets for testing account limits with SPL tokens
+    const randomWallets = Array.from({ length: 45 }, () =>
+      anchor.web3.Keypair.generate()
+    );
+
+    // Create ATAs for all random wallets
+    const randomWalletAtas = [];
+    for (const randomWallet of randomWallets) {
+      const ata = await spl.getOrCreateAssociatedTokenAccount(
+        conn,
+        wallet,
+        mint.publicKey,
+        randomWallet.publicKey,
+        true
+      );
+      randomWalletAtas.push(ata);
+    }
+
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      {
+        pubkey: destinationPdaAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: spl.TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: SYSTEM_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      // Add all random wallet ATAs
+      ...randomWalletAtas.map((ata) => ({
+        pubkey: ata.address,
+        isSigner: false,
+        isWritable: true,
+      })),
+    ];
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x06),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      destinationPdaAta.address.toBuffer(),
+      Buffer.from(Array.from(address)),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex", { canonical: true });
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    const executeIx = await gatewayProgram.methods
+      .executeSplToken(
+        usdcDecimals,
+        amount,
+        Array.from(address),
+        data,
+        Array.from(signatureBuffer),
+        Number(recoveryParam),
+        Array.from(message_hash),
+        nonce,
+        null
+      )
+      .accountsPartial({
+        // mandatory predefined accounts
+        signer: wallet.publicKey,
+        pda: pdaAccount,
+        pdaAta: pda_ata,
+        mintAccount: mint.publicKey,
+        destinationProgram: connectedSPLProgram.programId,
+        destinationProgramPda: connectedPdaAccount,
+        destinationProgramPdaAta: destinationPdaAta.address,
+        tokenProgram: spl.TOKEN_PROGRAM_ID,
+        associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+
+    const currentSlot = await conn.getSlot("finalized");
+    const [createLutIx, lookupTableAddress] =
+      AddressLookupTableProgram.createLookupTable({
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+        recentSlot: currentSlot,
+      });
+
+    // extending alt in couple of steps because of tx size over limit
+    const extendLutIx = AddressLookupTableProgram.extendLookupTable({
+      payer: wallet.publicKey,
+      authority: wallet.publicKey,
+      lookupTable: lookupTableAddress,
+      addresses: [
+        connectedPdaAccount,
+        destinationPdaAta.address,
+        mint.publicKey,
+        pdaAccount,
+        spl.TOKEN_PROGRAM_ID,
+        SYSTEM_PROGRAM_ID,
+        // Add first 15 random wallet ATAs
+        ...randomWalletAtas.slice(0, 15).map((ata) => ata.address),
+      ],
+    });
+
+    await anchor.web3.sendAndConfirmTransaction(
+      conn,
+      new anchor.web3.Transaction().add(createLutIx, extendLutIx),
+      [wallet]
+    );
+
+    // Wait a bit for LUT activation
+    await new Promise((r) => setTimeout(r, 1000));
+
+    const extendLutIx2 = AddressLookupTableProgram.extendLookupTable({
+      payer: wallet.publicKey,
+      authority: wallet.publicKey,
+      lookupTable: lookupTableAddress,
+      addresses: [
+        // Add remaining random wallet ATAs
+        ...randomWalletAtas.slice(15, 45).map((ata) => ata.address),
+      ],
+    });
+
+    await anchor.web3.sendAndConfirmTransaction(
+      conn,
+      new anchor.web3.Transaction().add(extendLutIx2),
+      [wallet]
+    );
+
+    // Wait a bit for LUT activation
+    await new Promise((r) => setTimeout(r, 1000));
+
+    const { value: lutAccount } = await conn.getAddressLookupTable(
+      lookupTableAddress
+    );
+    if (!lutAccount) throw new Error("Failed to fetch LUT");
+
+    const computeBudgetIx =
+      anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({
+        units: 1_000_000,
+      });
+
+    const latestBh = await conn.getLatestBlockhash();
+    const v0Message = new TransactionMessage({
+      payerKey: wallet.publicKey,
+      recentBlockhash: latestBh.blockhash,
+      instructions: [computeBudgetIx, executeIx],
+    }).compileToV0Message([lutAccount]);
+
+    const vtx = new VersionedTransaction(v0Message);
+    vtx.sign([wallet]);
+
+    const sig = await conn.sendTransaction(vtx);
+    await conn.confirmTransaction({ signature: sig, ...latestBh }, "confirmed");
+
+    const connectedPdaAfter = await connectedSPLProgram.account.pda.fetch(
+      connectedPdaAccount
+    );
+
+    // check connected pda state was updated
+    expect(connectedPdaAfter.lastMessage).to.be.eq(lastMessageData);
+    expect(Array.from(connectedPdaAfter.lastSender)).to.be.deep.eq(
+      Array.from(address)
+    );
+
+    // check amount was split between destination pda and all random wallet atas
+    const destinationPdaAtaAcc = await spl.getAccount(
+      conn,
+      destinationPdaAta.address
+    );
+
+    const share = Math.floor(amount.toNumber() / 2 / randomWalletAtas.length);
+    let totalShares = 0n;
+    for (const ata of randomWalletAtas) {
+      const randomWalletAtaAcc = await spl.getAccount(conn, ata.address);
+      expect(randomWalletAtaAcc.amount).to.be.eq(BigInt(share));
+      totalShares += randomWalletAtaAcc.amount;
+    }
+
+    const expectedDestinationAmount = BigInt(amount.toNumber()) - totalShares;
+    expect(Number(destinationPdaAtaAcc.amount)).to.be.eq(
+      Number(destinationPdaAtaAccBefore.amount) +
+        Number(expectedDestinationAmount)
+    );
+  });
+
+  it("Calls execute spl token and onCall (ALT) fails if no ALT is used", async () => {
+    const lastMessageData = "execute_spl_ALT";
+    const data = Buffer.from(lastMessageData, "utf-8");
+    let seeds = [Buffer.from("connected", "utf-8")];
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      seeds,
+      connectedSPLProgram.programId
+    );
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+
+    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      connectedPdaAccount,
+      true
+    );
+
+    // Generate 45 random wallets for testing account limits with SPL tokens
+    const randomWallets = Array.from({ length: 45 }, () =>
+      anchor.web3.Keypair.generate()
+    );
+
+    // Create ATAs for all random wallets
+    const randomWalletAtas = [];
+    for (const randomWallet of randomWallets) {
+      const ata = await spl.getOrCreateAssociatedTokenAccount(
+        conn,
+        wallet,
+        mint.publicKey,
+        randomWallet.publicKey,
+        true
+      );
+      randomWalletAtas.push(ata);
+    }
+
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      {
+        pubkey: destinationPdaAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: spl.TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: SYSTEM_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      // Add all random wallet ATAs
+      ...randomWalletAtas.map((ata) => ({
+        pubkey: ata.address,
+        isSigner: false,
+        isWritable: true,
+      })),
+    ];
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x06),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      destinationPdaAta.address.toBuffer(),
+      Buffer.from(Array.from(address)),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex", { canonical: true });
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    try {
+      await gatewayProgram.methods
+        .executeSplToken(
+          usdcDecimals,
+          amount,
+          Array.from(address),
+          data,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accountsPartial({
+          // mandatory predefined accounts
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          pdaAta: pda_ata,
+          mintAccount: mint.publicKey,
+          destinationProgram: connectedSPLProgram.programId,
+          destinationProgramPda: connectedPdaAccount,
+          destinationProgramPdaAta: destinationPdaAta.address,
+          tokenProgram: spl.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SYSTEM_PROGRAM_ID,
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err.message).to.contains("Transaction too large");
+    }
+  });
+
+  it("Calls execute spl token and onCall reverts if connected program reverts", async () => {
+    const randomWallet = anchor.web3.Keypair.generate();
+    let randomWalletAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      randomWallet.publicKey,
+      true
+    );
+    const lastMessageData = "revert";
+    const data = Buffer.from(lastMessageData, "utf-8");
+    let seeds = [Buffer.from("connected", "utf-8")];
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      seeds,
+      connectedSPLProgram.programId
+    );
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+
+    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      connectedPdaAccount,
+      true
+    );
+
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      {
+        pubkey: destinationPdaAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: spl.TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: SYSTEM_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: randomWalletAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+    ];
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x06),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      destinationPdaAta.address.toBuffer(),
+      Buffer.from(Array.from(address)),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    try {
+      // call the `execute_spl_token` function in the gateway program
+      await gatewayProgram.methods
+        .executeSplToken(
+          usdcDecimals,
+          amount,
+          Array.from(address),
+          data,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accountsPartial({
+          // mandatory predefined accounts
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          pdaAta: pda_ata,
+          mintAccount: mint.publicKey,
+          destinationProgram: connectedSPLProgram.programId,
+          destinationProgramPda: connectedPdaAccount,
+          destinationProgramPdaAta: destinationPdaAta.address,
+          tokenProgram: spl.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SYSTEM_PROGRAM_ID,
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+    }
+  });
+
+  it("Calls execute spl token and onCall reverts if signer is passed in remaining accounts", async () => {
+    const randomWallet = anchor.web3.Keypair.generate();
+    let randomWalletAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      randomWallet.publicKey,
+      true
+    );
+    const lastMessageData = "revert";
+    const data = Buffer.from(lastMessageData, "utf-8");
+    let seeds = [Buffer.from("connected", "utf-8")];
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      seeds,
+      connectedSPLProgram.programId
+    );
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+
+    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      connectedPdaAccount,
+      true
+    );
+
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      {
+        pubkey: destinationPdaAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: spl.TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: SYSTEM_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: randomWalletAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+    ];
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x06),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      destinationPdaAta.address.toBuffer(),
+      Buffer.from(Array.from(address)),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    try {
+      // call the `execute_spl_token` function in the gateway program
+      await gatewayProgram.methods
+        .executeSplToken(
+          usdcDecimals,
+          amount,
+          Array.from(address),
+          data,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accountsPartial({
+          // mandatory predefined accounts
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          pdaAta: pda_ata,
+          mintAccount: mint.publicKey,
+          destinationProgram: connectedSPLProgram.programId,
+          destinationProgramPda: connectedPdaAccount,
+          destinationProgramPdaAta: destinationPdaAta.address,
+          tokenProgram: spl.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SYSTEM_PROGRAM_ID,
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("InvalidInstructionData");
+    }
+  });
+
+  it("Calls execute spl token and onCall reverts if wrong msg hash", async () => {
+    const randomWallet = anchor.web3.Keypair.generate();
+    let randomWalletAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      randomWallet.publicKey,
+      true
+    );
+    const lastMessageData = "revert";
+    const data = Buffer.from(lastMessageData, "utf-8");
+    let seeds = [Buffer.from("connected", "utf-8")];
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      seeds,
+      connectedSPLProgram.programId
+    );
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+
+    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      connectedPdaAccount,
+      true
+    );
+
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      {
+        pubkey: destinationPdaAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: spl.TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: SYSTEM_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: randomWalletAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+    ];
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x06),
+      nonce.subn(1).toArrayLike(Buffer, "be", 8), // wrong nonce
+      amount.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      destinationPdaAta.address.toBuffer(),
+      Buffer.from(Array.from(address)),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    try {
+      // call the `execute_spl_token` function in the gateway program
+      await gatewayProgram.methods
+        .executeSplToken(
+          usdcDecimals,
+          amount,
+          Array.from(address),
+          data,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accountsPartial({
+          // mandatory predefined accounts
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          pdaAta: pda_ata,
+          mintAccount: mint.publicKey,
+          destinationProgram: connectedSPLProgram.programId,
+          destinationProgramPda: connectedPdaAccount,
+          destinationProgramPdaAta: destinationPdaAta.address,
+          tokenProgram: spl.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SYSTEM_PROGRAM_ID,
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("MessageHashMismatch");
+    }
+  });
+
+  it("Calls execute spl token and onCall reverts if wrong signer", async () => {
+    const key = ec.genKeyPair(); // non TSS key pair
+    const randomWallet = anchor.web3.Keypair.generate();
+    let randomWalletAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      randomWallet.publicKey,
+      true
+    );
+    const lastMessageData = "revert";
+    const data = Buffer.from(lastMessageData, "utf-8");
+    let seeds = [Buffer.from("connected", "utf-8")];
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      seeds,
+      connectedSPLProgram.programId
+    );
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+
+    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      connectedPdaAccount,
+      true
+    );
+
+    const remainingAccounts = [
+      // accounts coming from withdraw and call msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      {
+        pubkey: destinationPdaAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: spl.TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: SYSTEM_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: randomWalletAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+    ];
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x06),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      destinationPdaAta.address.toBuffer(),
+      Buffer.from(Array.from(address)),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = key.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    try {
+      // call the `execute_spl_token` function in the gateway program
+      await gatewayProgram.methods
+        .executeSplToken(
+          usdcDecimals,
+          amount,
+          Array.from(address),
+          data,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accountsPartial({
+          // mandatory predefined accounts
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          pdaAta: pda_ata,
+          mintAccount: mint.publicKey,
+          destinationProgram: connectedSPLProgram.programId,
+          destinationProgramPda: connectedPdaAccount,
+          destinationProgramPdaAta: destinationPdaAta.address,
+          tokenProgram: spl.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SYSTEM_PROGRAM_ID,
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("TSSAuthenticationFailed");
+    }
+  });
+
+  it("Calls execute spl token and onRevert", async () => {
+    const randomWallet = anchor.web3.Keypair.generate();
+    const lastMessageData = "execute_spl";
+    const data = Buffer.from(lastMessageData, "utf-8");
+    let seeds = [Buffer.from("connected", "utf-8")];
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      seeds,
+      connectedSPLProgram.programId
+    );
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+
+    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      connectedPdaAccount,
+      true
+    );
+
+    const remainingAccounts = [
+      // accounts coming from revert msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      {
+        pubkey: destinationPdaAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: spl.TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: SYSTEM_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x09),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      destinationPdaAta.address.toBuffer(),
+      randomWallet.publicKey.toBuffer(),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    // balances before
+    const destinationPdaAtaAccBefore = await spl.getAccount(
+      conn,
+      destinationPdaAta.address
+    );
+
+    // call the `execute_spl_token_revert` function in the gateway program
+    await gatewayProgram.methods
+      .executeSplTokenRevert(
+        usdcDecimals,
+        amount,
+        randomWallet.publicKey,
+        data,
+        Array.from(signatureBuffer),
+        Number(recoveryParam),
+        Array.from(message_hash),
+        nonce,
+        null
+      )
+      .accountsPartial({
+        // mandatory predefined accounts
+        signer: wallet.publicKey,
+        pda: pdaAccount,
+        pdaAta: pda_ata,
+        mintAccount: mint.publicKey,
+        destinationProgram: connectedSPLProgram.programId,
+        destinationProgramPda: connectedPdaAccount,
+        destinationProgramPdaAta: destinationPdaAta.address,
+        tokenProgram: spl.TOKEN_PROGRAM_ID,
+        associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+
+    const connectedPdaAfter = await connectedSPLProgram.account.pda.fetch(
+      connectedPdaAccount
+    );
+
+    // check connected pda state was updated
+    expect(connectedPdaAfter.lastRevertMessage).to.be.eq(lastMessageData);
+    expect(connectedPdaAfter.lastRevertSender.toString()).to.be.deep.eq(
+      randomWallet.publicKey.toString()
+    );
+
+    // check balances were updated
+    const destinationPdaAtaAcc = await spl.getAccount(
+      conn,
+      destinationPdaAta.address
+    );
+
+    expect(Number(destinationPdaAtaAcc.amount.toString())).to.be.eq(
+      Number(destinationPdaAtaAccBefore.amount) + amount.toNumber()
+    );
+  });
+
+  it("Calls execute spl token and onRevert reverts if connected program reverts", async () => {
+    const randomWallet = anchor.web3.Keypair.generate();
+    const lastMessageData = "execute_spl_revert";
+    const data = Buffer.from(lastMessageData, "utf-8");
+    let seeds = [Buffer.from("connected", "utf-8")];
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      seeds,
+      connectedSPLProgram.programId
+    );
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+
+    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      connectedPdaAccount,
+      true
+    );
+
+    const remainingAccounts = [
+      // accounts coming from revert msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      {
+        pubkey: destinationPdaAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: spl.TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: SYSTEM_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x09),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      destinationPdaAta.address.toBuffer(),
+      randomWallet.publicKey.toBuffer(),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    try {
+      // call the `execute_spl_token_revert` function in the gateway program
+      await gatewayProgram.methods
+        .executeSplTokenRevert(
+          usdcDecimals,
+          amount,
+          randomWallet.publicKey,
+          data,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accountsPartial({
+          // mandatory predefined accounts
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          pdaAta: pda_ata,
+          mintAccount: mint.publicKey,
+          destinationProgram: connectedSPLProgram.programId,
+          destinationProgramPda: connectedPdaAccount,
+          destinationProgramPdaAta: destinationPdaAta.address,
+          tokenProgram: spl.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SYSTEM_PROGRAM_ID,
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+    }
+  });
+
+  it("Calls execute spl token and onRevert reverts if signer is passed in remaining accounts", async () => {
+    const randomWallet = anchor.web3.Keypair.generate();
+    const lastMessageData = "execute_spl";
+    const data = Buffer.from(lastMessageData, "utf-8");
+    let seeds = [Buffer.from("connected", "utf-8")];
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      seeds,
+      connectedSPLProgram.programId
+    );
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+
+    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      connectedPdaAccount,
+      true
+    );
+
+    const remainingAccounts = [
+      // accounts coming from revert msg
+      { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      {
+        pubkey: destinationPdaAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: spl.TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: SYSTEM_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x09),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      destinationPdaAta.address.toBuffer(),
+      randomWallet.publicKey.toBuffer(),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    try {
+      // call the `execute_spl_token_revert` function in the gateway program
+      await gatewayProgram.methods
+        .executeSplTokenRevert(
+          usdcDecimals,
+          amount,
+          randomWallet.publicKey,
+          data,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accountsPartial({
+          // mandatory predefined accounts
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          pdaAta: pda_ata,
+          mintAccount: mint.publicKey,
+          destinationProgram: connectedSPLProgram.programId,
+          destinationProgramPda: connectedPdaAccount,
+          destinationProgramPdaAta: destinationPdaAta.address,
+          tokenProgram: spl.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SYSTEM_PROGRAM_ID,
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err.message).to.include("InvalidInstructionData");
+    }
+  });
+
+  it("Calls execute spl token and onRevert reverts if wrong message hash", async () => {
+    const randomWallet = anchor.web3.Keypair.generate();
+    const lastMessageData = "execute_spl";
+    const data = Buffer.from(lastMessageData, "utf-8");
+    let seeds = [Buffer.from("connected", "utf-8")];
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      seeds,
+      connectedSPLProgram.programId
+    );
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+
+    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      connectedPdaAccount,
+      true
+    );
+
+    const remainingAccounts = [
+      // accounts coming from revert msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      {
+        pubkey: destinationPdaAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: spl.TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: SYSTEM_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x09),
+      nonce.subn(1).toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      destinationPdaAta.address.toBuffer(),
+      randomWallet.publicKey.toBuffer(),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    try {
+      // call the `execute_spl_token_revert` function in the gateway program
+      await gatewayProgram.methods
+        .executeSplTokenRevert(
+          usdcDecimals,
+          amount,
+          randomWallet.publicKey,
+          data,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accountsPartial({
+          // mandatory predefined accounts
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          pdaAta: pda_ata,
+          mintAccount: mint.publicKey,
+          destinationProgram: connectedSPLProgram.programId,
+          destinationProgramPda: connectedPdaAccount,
+          destinationProgramPdaAta: destinationPdaAta.address,
+          tokenProgram: spl.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SYSTEM_PROGRAM_ID,
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err.message).to.include("MessageHashMismatch");
+    }
+  });
+
+  it("Calls execute spl token and onRevert reverts if wrong signer", async () => {
+    const key = ec.genKeyPair(); // non TSS key pair
+    const randomWallet = anchor.web3.Keypair.generate();
+    const lastMessageData = "execute_spl";
+    const data = Buffer.from(lastMessageData, "utf-8");
+    let seeds = [Buffer.from("connected", "utf-8")];
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      seeds,
+      connectedSPLProgram.programId
+    );
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+
+    let destinationPdaAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      connectedPdaAccount,
+      true
+    );
+
+    const remainingAccounts = [
+      // accounts coming from revert msg
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      {
+        pubkey: destinationPdaAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: spl.TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: SYSTEM_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x09),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      destinationPdaAta.address.toBuffer(),
+      randomWallet.publicKey.toBuffer(),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = key.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    try {
+      // call the `execute_spl_token_revert` function in the gateway program
+      await gatewayProgram.methods
+        .executeSplTokenRevert(
+          usdcDecimals,
+          amount,
+          randomWallet.publicKey,
+          data,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accountsPartial({
+          // mandatory predefined accounts
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          pdaAta: pda_ata,
+          mintAccount: mint.publicKey,
+          destinationProgram: connectedSPLProgram.programId,
+          destinationProgramPda: connectedPdaAccount,
+          destinationProgramPdaAta: destinationPdaAta.address,
+          tokenProgram: spl.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SYSTEM_PROGRAM_ID,
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err.message).to.include("TSSAuthenticationFailed");
+    }
+  });
+
+  it("Withdraw SPL token to a non-existent account should succeed by creating it", async () => {
+    let rentPayerPdaBal0 = await conn.getBalance(pdaAccount);
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+    const wallet2 = anchor.web3.Keypair.generate();
+    const to = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      wallet2.publicKey
+    );
+
+    let to_ata_bal = await conn.getBalance(to);
+    expect(to_ata_bal).to.be.eq(0); // the new ata account (owned by wallet2) should be non-existent;
+    await withdrawSplToken(
+      mint,
+      usdcDecimals,
+      amount,
+      nonce,
+      pda_ata,
+      to,
+      wallet2.publicKey,
+      gatewayProgram
+    );
+    to_ata_bal = await conn.getBalance(to);
+    expect(to_ata_bal).to.be.gt(2_000_000); // the new ata account (owned by wallet2) should be created
+
+    // pda should have reduced balance
+    let rentPayerPdaBal1 = await conn.getBalance(pdaAccount);
+    // expected reimbursement to be gas fee (5000 lamports) + ATA creation cost 2039280 lamports
+    expect(rentPayerPdaBal0 - rentPayerPdaBal1).to.be.eq(to_ata_bal + 5000); // rentPayer pays rent
+  });
+
+  it("Withdraw SPL token with wrong nonce should fail", async () => {
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+    const wallet2 = anchor.web3.Keypair.generate();
+    const to = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      wallet2.publicKey
+    );
+
+    try {
+      await withdrawSplToken(
+        mint,
+        usdcDecimals,
+        amount,
+        nonce.subn(1),
+        pda_ata,
+        to,
+        wallet2.publicKey,
+        gatewayProgram
+      );
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("NonceMismatch.");
+    }
+  });
+
+  it("Withdraw SPL token with wrong msg hash should fail", async () => {
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+    const wallet2 = anchor.web3.Keypair.generate();
+    const to = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      wallet2.publicKey
+    );
+
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x02),
+      nonce.subn(1).toArrayLike(Buffer, "be", 8), // wrong nonce
+      amount.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      to.toBuffer(),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+    try {
+      await gatewayProgram.methods
+        .withdrawSplToken(
+          usdcDecimals,
+          amount,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accounts({
+          pdaAta: pda_ata,
+          mintAccount: mint.publicKey,
+          recipientAta: to,
+          recipient: wallet2.publicKey,
+        })
+        .rpc({ commitment: "processed" });
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("MessageHashMismatch");
+    }
+  });
+
+  it("Withdraw SPL token with wrong signer should fail", async () => {
+    const key = ec.genKeyPair(); // non TSS key pair
+    let pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const amount = new anchor.BN(500_000);
+    const nonce = pdaAccountData.nonce;
+    const wallet2 = anchor.web3.Keypair.generate();
+    const to = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      wallet2.publicKey
+    );
+
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x02),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      to.toBuffer(),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = key.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+    try {
+      await gatewayProgram.methods
+        .withdrawSplToken(
+          usdcDecimals,
+          amount,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accounts({
+          pdaAta: pda_ata,
+          mintAccount: mint.publicKey,
+          recipientAta: to,
+          recipient: wallet2.publicKey,
+        })
+        .rpc({ commitment: "processed" });
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("TSSAuthenticationFailed");
+    }
+  });
+
+  it("Deposit and call with empty address receiver should fail", async () => {
+    try {
+      await gatewayProgram.methods
+        .depositAndCall(
+          new anchor.BN(1_000_000_000),
+          Array(20).fill(0),
+          Buffer.from("hello", "utf-8"),
+          revertOptions
+        )
+        .rpc();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("EmptyReceiver");
+    }
+  });
+
+  it("Deposit and call with above max payload size should fail", async () => {
+    try {
+      await gatewayProgram.methods
+        .depositAndCall(
+          new anchor.BN(1_000_000_000),
+          Array.from(address),
+          Buffer.from(Array(maxPayloadSize + 1).fill(1)),
+          revertOptions
+        )
+        .rpc();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("MemoLengthExceeded");
+    }
+  });
+
+  it("Call with empty address receiver should fail", async () => {
+    try {
+      await gatewayProgram.methods
+        .call(Array(20).fill(0), Buffer.from("hello", "utf-8"), revertOptions)
+        .rpc();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("EmptyReceiver");
+    }
+  });
+
+  it("Call with above max payload size should fail", async () => {
+    try {
+      await gatewayProgram.methods
+        .call(
+          Array.from(address),
+          Buffer.from(Array(maxPayloadSize + 1).fill(1)),
+          revertOptions
+        )
+        .rpc();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("MemoLengthExceeded");
+    }
+  });
+
+  it("Call with max payload size", async () => {
+    const txsig = await gatewayProgram.methods
+      .call(
+        Array.from(address),
+        Buffer.from(Array(maxPayloadSize).fill(1)),
+        revertOptions
+      )
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 400000 }),
+      ])
+      .rpc({ commitment: "processed" });
+    await conn.getParsedTransaction(txsig, "confirmed");
+  });
+
+  it("Deposit and call with max payload size", async () => {
+    const bal1 = await conn.getBalance(pdaAccount);
+    const txsig = await gatewayProgram.methods
+      .depositAndCall(
+        new anchor.BN(1_000_000_000),
+        Array.from(address),
+        Buffer.from(Array(maxPayloadSize).fill(1)),
+        revertOptions
+      )
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 400000 }),
+      ])
+      .rpc({ commitment: "processed" });
+    await conn.getParsedTransaction(txsig, "confirmed");
+    const bal2 = await conn.getBalance(pdaAccount);
+    expect(bal2 - bal1).to.be.gte(1_000_000_000);
+  });
+
+  it("Deposit and call", async () => {
+    let bal1 = await conn.getBalance(pdaAccount);
+    const txsig = await gatewayProgram.methods
+      .depositAndCall(
+        new anchor.BN(1_000_000_000),
+        Array.from(address),
+        Buffer.from("hello", "utf-8"),
+        revertOptions
+      )
+      .rpc({ commitment: "processed" });
+    await conn.getParsedTransaction(txsig, "confirmed");
+    let bal2 = await conn.getBalance(pdaAccount);
+    expect(bal2 - bal1).to.be.gte(1_000_000_000);
+  });
+
+  it("Deposit SPL with empty address receiver should fail", async () => {
+    try {
+      await depositSplTokens(
+        gatewayProgram,
+        conn,
+        wallet,
+        mint,
+        Buffer.alloc(20)
+      );
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("EmptyReceiver");
+    }
+  });
+
+  it("Unwhitelist SPL token and deposit should fail", async () => {
+    await gatewayProgram.methods
+      .unwhitelistSplMint([], 0, [], new anchor.BN(0))
+      .accounts({
+        whitelistCandidate: mint.publicKey,
+      })
+      .rpc();
+
+    try {
+      await depositSplTokens(gatewayProgram, conn, wallet, mint, address);
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("AccountNotInitialized");
+    }
+  });
+
+  it("Re-whitelist SPL token and deposit should succeed", async () => {
+    await gatewayProgram.methods
+      .whitelistSplMint([], 0, [], new anchor.BN(0))
+      .accounts({
+        whitelistCandidate: mint.publicKey,
+      })
+      .rpc();
+    await depositSplTokens(gatewayProgram, conn, wallet, mint, address);
+  });
+
+  it("Unwhitelist SPL token using TSS signature and deposit should fail", async () => {
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const nonce = pdaAccountData.nonce;
+
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x04),
+      nonce.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    await gatewayProgram.methods
+      .unwhitelistSplMint(
+        Array.from(signatureBuffer),
+        Number(recoveryParam),
+        Array.from(message_hash),
+        nonce
+      )
+      .accounts({
+        whitelistCandidate: mint.publicKey,
+      })
+      .rpc();
+
+    try {
+      await depositSplTokens(gatewayProgram, conn, wallet, mint, address);
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("AccountNotInitialized");
+    }
+  });
+
+  it("Re-whitelist SPL token using TSS signature and deposit should succeed", async () => {
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const nonce = pdaAccountData.nonce;
+
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x03),
+      nonce.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    await gatewayProgram.methods
+      .whitelistSplMint(
+        Array.from(signatureBuffer),
+        Number(recoveryParam),
+        Array.from(message_hash),
+        nonce
+      )
+      .accounts({
+        whitelistCandidate: mint.publicKey,
+      })
+      .rpc();
+    await depositSplTokens(gatewayProgram, conn, wallet, mint, address);
+  });
+
+  it("Unwhitelist SPL token using wrong msg hash should fail", async () => {
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const nonce = pdaAccountData.nonce;
+
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x03),
+      nonce.subn(1).toArrayLike(Buffer, "be", 8), // wrong nonce
+      mint.publicKey.toBuffer(),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    try {
+      await gatewayProgram.methods
+        .unwhitelistSplMint(
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce
+        )
+        .accounts({
+          whitelistCandidate: mint.publicKey,
+        })
+        .rpc();
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("MessageHashMismatch");
+    }
+  });
+
+  it("Unwhitelist SPL token using wrong signer should fail", async () => {
+    const key = ec.genKeyPair(); // non TSS key pair
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const nonce = pdaAccountData.nonce;
+
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x04),
+      nonce.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = key.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    try {
+      await gatewayProgram.methods
+        .unwhitelistSplMint(
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce
+        )
+        .accounts({
+          whitelistCandidate: mint.publicKey,
+        })
+        .rpc();
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("TSSAuthenticationFailed");
+    }
+  });
+
+  it("Unwhitelist SPL token using wrong nonce should fail", async () => {
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const nonce = pdaAccountData.nonce;
+
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x03),
+      nonce.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    try {
+      await gatewayProgram.methods
+        .unwhitelistSplMint(
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce.subn(1)
+        )
+        .accounts({
+          whitelistCandidate: mint.publicKey,
+        })
+        .rpc();
+      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("NonceMismatch.");
+    }
+  });
+
+  it("Withdraw SOL as wSOL to a non-existent ATA should succeed by creating it", async () => {
+    const recipient = anchor.web3.Keypair.generate();
+    const recipientAta = await spl.getAssociatedTokenAddress(
+      spl.NATIVE_MINT,
+      recipient.publicKey
+    );
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const nonce = pdaAccountData.nonce;
+    const amount = new anchor.BN(100_000_000);
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x0a),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      spl.NATIVE_MINT.toBuffer(),
+      recipientAta.toBuffer(),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    const pdaBalanceBefore = await conn.getBalance(pdaAccount);
+    await gatewayProgram.methods
+      .withdrawWsol(
+        amount,
+        Array.from(signatureBuffer),
+        Number(recoveryParam),
+        Array.from(message_hash),
+        nonce,
+        null
+      )
+      .accounts({
+        recipient: recipient.publicKey,
+        recipientAta: recipientAta,
+      })
+      .rpc();
+
+    const recipientAccount = await spl.getAccount(conn, recipientAta);
+    expect(recipientAccount.isNative).to.be.true;
+    expect(recipientAccount.amount).to.be.eq(100_000_000n);
+
+    // pda pays the wrapped amount and reimburses the ATA rent and gas cost to the signer
+    const ataBalance = await conn.getBalance(recipientAta);
+    const pdaBalanceAfter = await conn.getBalance(pdaAccount);
+    expect(pdaBalanceBefore - pdaBalanceAfter).to.be.eq(ataBalance + 5000);
+  });
+
+  it("Calls execute wSOL and onRevert", async () => {
+    const lastMessageData = "execute_rev_wsol";
+    const data = Buffer.from(lastMessageData, "utf-8");
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("connected", "utf-8")],
+      connectedProgram.programId
+    );
+    const connectedPdaAta = await spl.getAssociatedTokenAddress(
+      spl.NATIVE_MINT,
+      connectedPdaAccount,
+      true
+    );
+    const amount = new anchor.BN(100_000_000);
+
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const nonce = pdaAccountData.nonce;
+    const remainingAccounts = [
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x18),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      spl.NATIVE_MINT.toBuffer(),
+      connectedPdaAta.toBuffer(),
+      random_account.publicKey.toBuffer(),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    const pdaBalanceBefore = await conn.getBalance(pdaAccount);
+    await gatewayProgram.methods
+      .executeWsolRevert(
+        amount,
+        random_account.publicKey,
+        data,
+        Array.from(signatureBuffer),
+        Number(recoveryParam),
+        Array.from(message_hash),
+        nonce,
+        null
+      )
+      .accountsPartial({
+        signer: wallet.publicKey,
+        pda: pdaAccount,
+        destinationProgram: connectedProgram.programId,
+        destinationProgramPda: connectedPdaAccount,
+        destinationProgramPdaAta: connectedPdaAta,
+      })
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+
+    const connectedPdaAfter = await connectedProgram.account.pda.fetch(
+      connectedPdaAccount
+    );
+    expect(connectedPdaAfter.lastRevertMessage).to.be.eq(lastMessageData);
+    expect(connectedPdaAfter.lastRevertSender.toString()).to.be.eq(
+      random_account.publicKey.toString()
+    );
+
+    // the ATA is created, and its rent and the gas cost are reimbursed to the signer
+    const connectedPdaAtaAccount = await spl.getAccount(conn, connectedPdaAta);
+    expect(connectedPdaAtaAccount.isNative).to.be.true;
+    expect(connectedPdaAtaAccount.amount).to.be.eq(100_000_000n);
+    const ataBalance = await conn.getBalance(connectedPdaAta);
+    const pdaBalanceAfter = await conn.getBalance(pdaAccount);
+    expect(pdaBalanceBefore - pdaBalanceAfter).to.be.eq(ataBalance + 5000);
+  });
+
+  it("Deposit wSOL is unwrapped and credited as SOL", async () => {
+    const walletWsolAta = await spl.createWrappedNativeAccount(
+      conn,
+      wallet,
+      wallet.publicKey,
+      1_000_000_000,
+      anchor.web3.Keypair.generate()
+    );
+    const pdaWsolAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      spl.NATIVE_MINT,
+      pdaAccount,
+      true
+    );
+    const [unwrapAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("wsol_unwrap", "utf-8"), wallet.publicKey.toBuffer()],
+      gatewayProgram.programId
+    );
+
+    const pdaBalanceBefore = await conn.getBalance(pdaAccount);
+    const pdaWsolBalanceBefore = await conn.getBalance(pdaWsolAta.address);
+    const txsig = await gatewayProgram.methods
+      .depositWsol(new anchor.BN(500_000_000), Array.from(address), revertOptions)
+      .accountsPartial({
+        from: walletWsolAta,
+        unwrapAccount,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    // amount + deposit fee is held as lamports and logged as a SOL deposit
+    const pdaBalanceAfter = await conn.getBalance(pdaAccount);
+    expect(pdaBalanceAfter - pdaBalanceBefore).to.eq(500_000_000 + 2_000_000);
+    const tx = await conn.getParsedTransaction(txsig, "confirmed");
+    expect(
+      tx.meta.logMessages.some((log) => log.includes("Deposit executed"))
+    ).to.be.true;
+
+    // the gateway wSOL custody and the temporary account are left untouched
+    expect(await conn.getBalance(pdaWsolAta.address)).to.eq(
+      pdaWsolBalanceBefore
+    );
+    expect(await conn.getAccountInfo(unwrapAccount)).to.be.null;
+
+    const walletWsolAccount = await spl.getAccount(conn, walletWsolAta);
+    expect(walletWsolAccount.amount).to.be.eq(500_000_000n);
+  });
+
+  const defaultWhitelistEntryConfig = {
+    zrc20: Array(20).fill(0),
+    minDepositAmount: new anchor.BN(0),
+    depositPaused: false,
+    withdrawPaused: false,
+    feeOverride: null,
+  };
+
+  it("Whitelist entry stores mint metadata", async () => {
+    const [whitelistEntry] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("whitelist", "utf-8"), mint.publicKey.toBuffer()],
+      gatewayProgram.programId
+    );
+    const entry = await gatewayProgram.account.whitelistEntry.fetch(
+      whitelistEntry
+    );
+    expect(entry.decimals).to.be.eq(usdcDecimals);
+    expect(entry.tokenProgram.toBase58()).to.be.eq(
+      spl.TOKEN_PROGRAM_ID.toBase58()
+    );
+    expect(entry.depositPaused).to.be.false;
+    expect(entry.withdrawPaused).to.be.false;
+    expect(entry.feeOverride).to.be.null;
+  });
+
+  it("Update whitelist entry enforces minimum deposit and fee override", async () => {
+    await gatewayProgram.methods
+      .updateWhitelistEntry(
+        {
+          ...defaultWhitelistEntryConfig,
+          minDepositAmount: new anchor.BN(2_000_000),
+        },
+        [],
+        0,
+        [],
+        new anchor.BN(0)
+      )
+      .accounts({
+        whitelistCandidate: mint.publicKey,
+      })
+      .rpc();
+
+    try {
+      await depositSplTokens(gatewayProgram, conn, wallet, mint, address);
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("DepositBelowMinimum");
+    }
+
+    await gatewayProgram.methods
+      .updateWhitelistEntry(
+        {
+          ...defaultWhitelistEntryConfig,
+          feeOverride: new anchor.BN(1_000),
+        },
+        [],
+        0,
+        [],
+        new anchor.BN(0)
+      )
+      .accounts({
+        whitelistCandidate: mint.publicKey,
+      })
+      .rpc();
+
+    const balanceBefore = await conn.getBalance(pdaAccount);
+    await depositSplTokens(gatewayProgram, conn, wallet, mint, address);
+    const balanceAfter = await conn.getBalance(pdaAccount);
+    expect(balanceAfter - balanceBefore).to.be.eq(1_000);
+  });
+
+  it("Update whitelist entry using TSS signature pauses withdrawals", async () => {
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
-    const wallet2 = anchor.web3.Keypair.generate();
-    const to = await spl.getAssociatedTokenAddress(
+
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x0c),
+      nonce.toArrayLike(Buffer, "be", 8),
+      mint.publicKey.toBuffer(),
+      Buffer.alloc(20), // zrc20
+      new anchor.BN(0).toArrayLike(Buffer, "be", 8), // min deposit amount
+      Buffer.from([0x00, 0x01]), // deposit paused, withdraw paused
+      Buffer.from([0x00]), // no fee override
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    await gatewayProgram.methods
+      .updateWhitelistEntry(
+        { ...defaultWhitelistEntryConfig, withdrawPaused: true },
+        Array.from(signatureBuffer),
+        Number(recoveryParam),
+        Array.from(message_hash),
+        nonce
+      )
+      .accounts({
+        whitelistCandidate: mint.publicKey,
+      })
+      .rpc();
+
+    const pda_ata = await spl.getAssociatedTokenAddress(
       mint.publicKey,
-      wallet2.publicKey
+      pdaAccount,
+      true
+    );
+    try {
+      await withdrawSplToken(
+        mint,
+        usdcDecimals,
+        new anchor.BN(1_000),
+        nonce.addn(1),
+        pda_ata,
+        wallet_ata,
+        wallet.publicKey,
+        gatewayProgram
+      );
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("WithdrawPaused");
+    }
+
+    await gatewayProgram.methods
+      .updateWhitelistEntry(
+        defaultWhitelistEntryConfig,
+        [],
+        0,
+        [],
+        new anchor.BN(0)
+      )
+      .accounts({
+        whitelistCandidate: mint.publicKey,
+      })
+      .rpc();
+  });
+
+  it("Withdraw above the SOL rate limit should fail", async () => {
+    const rateLimitConfig = {
+      windowDuration: new anchor.BN(3600),
+      outboundLimit: new anchor.BN(100_000_000),
+      inboundLimit: new anchor.BN(0),
+      warningThresholdBps: 8_000,
+      delayThreshold: new anchor.BN(0),
+      settlementDelay: new anchor.BN(0),
+    };
+    await gatewayProgram.methods
+      .setRateLimit(spl.NATIVE_MINT, rateLimitConfig)
+      .rpc();
+
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const nonce = pdaAccountData.nonce;
+    const amount = new anchor.BN(200_000_000);
+    const to = anchor.web3.Keypair.generate().publicKey;
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x01),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      to.toBuffer(),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+    const { r, s, recoveryParam } = signature;
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    try {
+      await gatewayProgram.methods
+        .withdraw(
+          amount,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accounts({
+          recipient: to,
+        })
+        .rpc();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("RateLimitExceeded");
+    }
+
+    const [rateLimit] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("rate_limit", "utf-8"), spl.NATIVE_MINT.toBuffer()],
+      gatewayProgram.programId
+    );
+    const rateLimitData = await gatewayProgram.account.rateLimit.fetch(
+      rateLimit
+    );
+    expect(rateLimitData.outboundUsed.toNumber()).to.be.eq(0);
+
+    // disable the outbound limit again
+    await gatewayProgram.methods
+      .setRateLimit(spl.NATIVE_MINT, {
+        ...rateLimitConfig,
+        outboundLimit: new anchor.BN(0),
+      })
+      .rpc();
+  });
+
+  it("Withdraw above the delay threshold is queued until finalized or cancelled", async () => {
+    const rateLimitConfig = {
+      windowDuration: new anchor.BN(3600),
+      outboundLimit: new anchor.BN(0),
+      inboundLimit: new anchor.BN(0),
+      warningThresholdBps: 0,
+      delayThreshold: new anchor.BN(50_000_000),
+      settlementDelay: new anchor.BN(0),
+    };
+    await gatewayProgram.methods
+      .setRateLimit(spl.NATIVE_MINT, rateLimitConfig)
+      .rpc();
+
+    const queueWithdraw = async (
+      amount: anchor.BN,
+      to: anchor.web3.PublicKey
+    ) => {
+      const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+      const nonce = pdaAccountData.nonce;
+      const buffer = Buffer.concat([
+        messageHeader(gatewayProgram.programId, 0x01),
+        nonce.toArrayLike(Buffer, "be", 8),
+        amount.toArrayLike(Buffer, "be", 8),
+        to.toBuffer(),
+        expiryTag(null),
+      ]);
+      const message_hash = keccak256(buffer);
+      const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+      const { r, s, recoveryParam } = signature;
+      const signatureBuffer = Buffer.concat([
+        r.toArrayLike(Buffer, "be", 32),
+        s.toArrayLike(Buffer, "be", 32),
+      ]);
+      const [pendingWithdrawal] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pending_withdrawal", "utf-8"),
+          nonce.toArrayLike(Buffer, "le", 8),
+        ],
+        gatewayProgram.programId
+      );
+      await gatewayProgram.methods
+        .withdraw(
+          amount,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accounts({
+          recipient: to,
+          pendingWithdrawal,
+        })
+        .rpc();
+      return pendingWithdrawal;
+    };
+
+    const amount = new anchor.BN(100_000_000);
+    const to = anchor.web3.Keypair.generate().publicKey;
+
+    // escrowed and paid out once finalized
+    const pendingWithdrawal = await queueWithdraw(amount, to);
+    expect(await conn.getBalance(to)).to.be.eq(0);
+    const pendingData = await gatewayProgram.account.pendingWithdrawal.fetch(
+      pendingWithdrawal
+    );
+    expect(pendingData.amount.toNumber()).to.be.eq(amount.toNumber());
+    expect(pendingData.recipient.toBase58()).to.be.eq(to.toBase58());
+
+    await gatewayProgram.methods
+      .finalizeWithdrawal()
+      .accountsPartial({
+        pendingWithdrawal,
+        payer: wallet.publicKey,
+        recipient: to,
+        mintAccount: null,
+        pendingWithdrawalAta: null,
+        recipientAta: null,
+        tokenProgram: null,
+        associatedTokenProgram: null,
+      })
+      .rpc();
+    expect(await conn.getBalance(to)).to.be.eq(amount.toNumber());
+    expect(await conn.getAccountInfo(pendingWithdrawal)).to.be.null;
+
+    // not finalizable before the delay, cancelled and closed for revert
+    await gatewayProgram.methods
+      .setRateLimit(spl.NATIVE_MINT, {
+        ...rateLimitConfig,
+        settlementDelay: new anchor.BN(3600),
+      })
+      .rpc();
+    const delayedWithdrawal = await queueWithdraw(amount, to);
+
+    try {
+      await gatewayProgram.methods
+        .finalizeWithdrawal()
+        .accountsPartial({
+          pendingWithdrawal: delayedWithdrawal,
+          payer: wallet.publicKey,
+          recipient: to,
+          mintAccount: null,
+          pendingWithdrawalAta: null,
+          recipientAta: null,
+          tokenProgram: null,
+          associatedTokenProgram: null,
+        })
+        .rpc();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("WithdrawalDelayNotElapsed");
+    }
+
+    const [rateLimit] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("rate_limit", "utf-8"), spl.NATIVE_MINT.toBuffer()],
+      gatewayProgram.programId
+    );
+    const outboundUsedBefore = (
+      await gatewayProgram.account.rateLimit.fetch(rateLimit)
+    ).outboundUsed.toNumber();
+    const pdaBalanceBefore = await conn.getBalance(pdaAccount);
+    await gatewayProgram.methods
+      .cancelWithdrawal()
+      .accountsPartial({
+        pendingWithdrawal: delayedWithdrawal,
+        rateLimit,
+        payer: wallet.publicKey,
+        mintAccount: null,
+        pendingWithdrawalAta: null,
+        pdaAta: null,
+        tokenProgram: null,
+      })
+      .rpc();
+    expect(await conn.getBalance(pdaAccount)).to.be.eq(
+      pdaBalanceBefore + amount.toNumber()
+    );
+    expect(await conn.getAccountInfo(delayedWithdrawal)).to.be.null;
+    const rateLimitData = await gatewayProgram.account.rateLimit.fetch(
+      rateLimit
+    );
+    expect(rateLimitData.outboundUsed.toNumber()).to.be.eq(
+      outboundUsedBefore - amount.toNumber()
+    );
+
+    await gatewayProgram.methods
+      .setRateLimit(spl.NATIVE_MINT, {
+        ...rateLimitConfig,
+        delayThreshold: new anchor.BN(0),
+      })
+      .rpc();
+  });
+
+  it("Execute above the delay threshold is queued until resubmitted", async () => {
+    const rateLimitConfig = {
+      windowDuration: new anchor.BN(3600),
+      outboundLimit: new anchor.BN(0),
+      inboundLimit: new anchor.BN(0),
+      warningThresholdBps: 0,
+      delayThreshold: new anchor.BN(50_000_000),
+      settlementDelay: new anchor.BN(0),
+    };
+    await gatewayProgram.methods
+      .setRateLimit(spl.NATIVE_MINT, rateLimitConfig)
+      .rpc();
+
+    const data = Buffer.from("execute_queued", "utf-8");
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("connected", "utf-8")],
+      connectedProgram.programId
     );
+    const amount = new anchor.BN(100_000_000);
 
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const nonce = pdaAccountData.nonce;
+    const [pendingWithdrawal] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("pending_withdrawal", "utf-8"),
+        nonce.toArrayLike(Buffer, "le", 8),
+      ],
+      gatewayProgram.programId
+    );
+    const remainingAccounts = [
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: callerAuthority,
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x02]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x05),
       nonce.toArrayLike(Buffer, "be", 8),
       amount.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
-      to.toBuffer(),
+      connectedProgram.programId.toBuffer(),
+      Buffer.from(Array.from(address)),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = key.sign(message_hash, "hex");
+    const signature = keyPair.sign(message_hash, "hex", { canonical: true });
     const { r, s, recoveryParam } = signature;
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
       s.toArrayLike(Buffer, "be", 32),
     ]);
+    const execute = (signer: anchor.web3.Keypair = wallet) =>
+      gatewayProgram.methods
+        .execute(
+          amount,
+          Array.from(address),
+          data,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accountsPartial({
+          signer: signer.publicKey,
+          pda: pdaAccount,
+          destinationProgram: connectedProgram.programId,
+          destinationProgramPda: connectedPdaAccount,
+          pendingWithdrawal,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([signer])
+        .rpc();
+
+    // queued without escrow, the call is not made yet
+    const connectedPdaBalanceBefore = await conn.getBalance(connectedPdaAccount);
+    await execute();
+    expect(await conn.getBalance(connectedPdaAccount)).to.be.eq(
+      connectedPdaBalanceBefore
+    );
+    const pendingData = await gatewayProgram.account.pendingWithdrawal.fetch(
+      pendingWithdrawal
+    );
+    expect(pendingData.escrowed).to.be.false;
+    expect(pendingData.amount.toNumber()).to.be.eq(amount.toNumber());
+
+    // any relayer resubmitting the same message after the delay carries out the call
+    const relayer = anchor.web3.Keypair.generate();
+    await execute(relayer);
+    expect(await conn.getBalance(relayer.publicKey)).to.be.greaterThan(0);
+    expect(await conn.getBalance(connectedPdaAccount)).to.be.eq(
+      connectedPdaBalanceBefore + amount.toNumber()
+    );
+    const connectedPdaAfter = await connectedProgram.account.pda.fetch(
+      connectedPdaAccount
+    );
+    expect(connectedPdaAfter.lastMessage).to.be.eq("execute_queued");
+    expect(await conn.getAccountInfo(pendingWithdrawal)).to.be.null;
+
+    // the released outbound cannot be resubmitted again
     try {
-      await gatewayProgram.methods
-        .withdrawSplToken(
-          usdcDecimals,
+      await execute();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("NonceMismatch");
+    }
+
+    await gatewayProgram.methods
+      .setRateLimit(spl.NATIVE_MINT, {
+        ...rateLimitConfig,
+        delayThreshold: new anchor.BN(0),
+      })
+      .rpc();
+  });
+
+  it("Circuit breaker pauses outbounds until reset", async () => {
+    const guardian = anchor.web3.Keypair.generate();
+    await gatewayProgram.methods
+      .updateGuardians([guardian.publicKey])
+      .rpc();
+    // trip on any outflow above 0.01% of custody
+    await gatewayProgram.methods
+      .setCircuitBreaker(1, new anchor.BN(3600))
+      .rpc();
+
+    const signWithdraw = async (amount: anchor.BN, to: anchor.web3.PublicKey) => {
+      const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+      const nonce = pdaAccountData.nonce;
+      const buffer = Buffer.concat([
+        messageHeader(gatewayProgram.programId, 0x01),
+        nonce.toArrayLike(Buffer, "be", 8),
+        amount.toArrayLike(Buffer, "be", 8),
+        to.toBuffer(),
+        expiryTag(null),
+      ]);
+      const message_hash = keccak256(buffer);
+      const signature = keyPair.sign(message_hash, "hex", { canonical: true });
+      const { r, s, recoveryParam } = signature;
+      const signatureBuffer = Buffer.concat([
+        r.toArrayLike(Buffer, "be", 32),
+        s.toArrayLike(Buffer, "be", 32),
+      ]);
+      return gatewayProgram.methods
+        .withdraw(
           amount,
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
         .accounts({
-          pdaAta: pda_ata,
-          mintAccount: mint.publicKey,
-          recipientAta: to,
-          recipient: wallet2.publicKey,
+          recipient: to,
+        });
+    };
+
+    const amount = new anchor.BN(100_000_000);
+    const to = anchor.web3.Keypair.generate().publicKey;
+
+    // the withdraw tripping the circuit breaker is not carried out, its nonce is consumed
+    const nonceBefore = (await gatewayProgram.account.pda.fetch(pdaAccount))
+      .nonce;
+    await (await signWithdraw(amount, to)).rpc();
+    expect(await conn.getBalance(to)).to.be.eq(0);
+    let pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    expect(pdaAccountData.nonce.toNumber()).to.be.eq(nonceBefore.toNumber() + 1);
+    expect(pdaAccountData.outboundPaused).to.be.true;
+    expect(pdaAccountData.circuitBreakerAsset.toBase58()).to.be.eq(
+      spl.NATIVE_MINT.toBase58()
+    );
+
+    try {
+      await (await signWithdraw(amount, to)).rpc();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("OutboundPaused");
+    }
+
+    try {
+      await gatewayProgram.methods
+        .resetCircuitBreaker()
+        .accounts({
+          signer: guardian.publicKey,
         })
-        .rpc({ commitment: "processed" });
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+        .signers([guardian])
+        .rpc();
+      throw new Error("Expected error not thrown");
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("TSSAuthenticationFailed");
+      expect(err.message).to.include("GuardianResetDelayNotElapsed");
     }
+
+    await gatewayProgram.methods.resetCircuitBreaker().rpc();
+    pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    expect(pdaAccountData.outboundPaused).to.be.false;
+    expect(pdaAccountData.circuitBreakerTrippedAt.toNumber()).to.be.eq(0);
+
+    await gatewayProgram.methods
+      .setCircuitBreaker(0, new anchor.BN(0))
+      .rpc();
+    await gatewayProgram.methods.updateGuardians([]).rpc();
   });
 
-  it("Deposit and call with empty address receiver should fail", async () => {
+  it("Pauser role can pause deposits but not unpause or update TSS", async () => {
+    const pauser = anchor.web3.Keypair.generate();
+    await gatewayProgram.methods
+      .grantRole(pauser.publicKey, { pauser: {} })
+      .rpc();
+
+    await gatewayProgram.methods
+      .setDepositPaused(true)
+      .accounts({
+        signer: pauser.publicKey,
+      })
+      .signers([pauser])
+      .rpc();
+    let pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    expect(pdaAccountData.depositPaused).to.be.true;
+
     try {
       await gatewayProgram.methods
-        .depositAndCall(
-          new anchor.BN(1_000_000_000),
-          Array(20).fill(0),
-          Buffer.from("hello", "utf-8"),
-          revertOptions
+        .setDepositPaused(false)
+        .accounts({
+          signer: pauser.publicKey,
+        })
+        .signers([pauser])
+        .rpc();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("SignerIsNotAuthority");
+    }
+
+    try {
+      await gatewayProgram.methods
+        .updateTss(Array.from(new Uint8Array(20).fill(1)))
+        .accounts({
+          signer: pauser.publicKey,
+        })
+        .signers([pauser])
+        .rpc();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("SignerIsNotAuthority");
+    }
+
+    await gatewayProgram.methods.setDepositPaused(false).rpc();
+    pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    expect(pdaAccountData.depositPaused).to.be.false;
+
+    await gatewayProgram.methods
+      .revokeRole(pauser.publicKey, { pauser: {} })
+      .rpc();
+    try {
+      await gatewayProgram.methods
+        .setDepositPaused(true)
+        .accounts({
+          signer: pauser.publicKey,
+        })
+        .signers([pauser])
+        .rpc();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("SignerIsNotAuthority");
+    }
+  });
+
+  it("Guardian can pause deposits and outbounds but not unpause", async () => {
+    const guardian = anchor.web3.Keypair.generate();
+    await gatewayProgram.methods
+      .updateGuardians([guardian.publicKey])
+      .rpc();
+
+    await gatewayProgram.methods
+      .guardianPause(true, true)
+      .accounts({
+        signer: guardian.publicKey,
+      })
+      .signers([guardian])
+      .rpc();
+    let pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    expect(pdaAccountData.depositPaused).to.be.true;
+    expect(pdaAccountData.outboundPaused).to.be.true;
+
+    try {
+      await gatewayProgram.methods
+        .setDepositPaused(false)
+        .accounts({
+          signer: guardian.publicKey,
+        })
+        .signers([guardian])
+        .rpc();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("SignerIsNotAuthority");
+    }
+
+    try {
+      await gatewayProgram.methods
+        .resetCircuitBreaker()
+        .accounts({
+          signer: guardian.publicKey,
+        })
+        .signers([guardian])
+        .rpc();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("CircuitBreakerNotTripped");
+    }
+
+    await gatewayProgram.methods.setDepositPaused(false).rpc();
+    await gatewayProgram.methods.resetCircuitBreaker().rpc();
+    pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    expect(pdaAccountData.depositPaused).to.be.false;
+    expect(pdaAccountData.outboundPaused).to.be.false;
+
+    await gatewayProgram.methods.updateGuardians([]).rpc();
+  });
+
+  it("Execute respects the connected program registry", async () => {
+    const randomWallet = anchor.web3.Keypair.generate();
+    const data = Buffer.from("execute_sol", "utf-8");
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("connected", "utf-8")],
+      connectedProgram.programId
+    );
+    const amount = new anchor.BN(1_000_000);
+    const zeroSignature = Array.from(new Uint8Array(64));
+    const zeroHash = Array.from(new Uint8Array(32));
+
+    const execute = async () => {
+      const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+      const nonce = pdaAccountData.nonce;
+      const remainingAccounts = [
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: callerAuthority,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: randomWallet.publicKey, isSigner: false, isWritable: true },
+    ];
+    const buffer = Buffer.concat([
+        messageHeader(gatewayProgram.programId, 0x05),
+        nonce.toArrayLike(Buffer, "be", 8),
+        amount.toArrayLike(Buffer, "be", 8),
+        connectedProgram.programId.toBuffer(),
+        Buffer.from(Array.from(address)),
+        data,
+      accountsHash(remainingAccounts),
+        expiryTag(null),
+      ]);
+      const message_hash = keccak256(buffer);
+      const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex", { canonical: true });
+      const signatureBuffer = Buffer.concat([
+        r.toArrayLike(Buffer, "be", 32),
+        s.toArrayLike(Buffer, "be", 32),
+      ]);
+      await gatewayProgram.methods
+        .execute(
+          amount,
+          Array.from(address),
+          data,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
         )
+        .accountsPartial({
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          destinationProgram: connectedProgram.programId,
+          destinationProgramPda: connectedPdaAccount,
+        })
+        .remainingAccounts(remainingAccounts)
         .rpc();
+    };
+
+    // unregistered program is rejected with the allowlist enabled
+    await gatewayProgram.methods.setDestinationAllowlist(true).rpc();
+    try {
+      await execute();
       throw new Error("Expected error not thrown");
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("EmptyReceiver");
+      expect(err.message).to.include("DestinationProgramNotAllowed");
+    }
+
+    await gatewayProgram.methods
+      .setConnectedProgram(
+        connectedProgram.programId,
+        { allowed: {} },
+        zeroSignature,
+        0,
+        zeroHash,
+        new anchor.BN(0)
+      )
+      .rpc();
+    await execute();
+
+    // blocked program is rejected with the allowlist disabled
+    await gatewayProgram.methods.setDestinationAllowlist(false).rpc();
+    await gatewayProgram.methods
+      .setConnectedProgram(
+        connectedProgram.programId,
+        { blocked: {} },
+        zeroSignature,
+        0,
+        zeroHash,
+        new anchor.BN(0)
+      )
+      .rpc();
+    try {
+      await execute();
+      throw new Error("Expected error not thrown");
+    } catch (err) {
+      expect(err).to.be.instanceof(anchor.AnchorError);
+      expect(err.message).to.include("DestinationProgramBlocked");
     }
+
+    await gatewayProgram.methods
+      .setConnectedProgram(
+        connectedProgram.programId,
+        { unlisted: {} },
+        zeroSignature,
+        0,
+        zeroHash,
+        new anchor.BN(0)
+      )
+      .rpc();
   });
 
-  it("Deposit and call with above max payload size should fail", async () => {
+  it("Execute uses the custody seed declared by the connected program", async () => {
+    const data = Buffer.from("execute_sol", "utf-8");
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("connected", "utf-8")],
+      connectedProgram.programId
+    );
+    const amount = new anchor.BN(1_000_000);
+    const zeroSignature = Array.from(new Uint8Array(64));
+    const zeroHash = Array.from(new Uint8Array(32));
+
+    await gatewayProgram.methods
+      .configureConnectedProgram(
+        connectedProgram.programId,
+        {
+          custodySeed: Buffer.from("vault", "utf-8"),
+          onCallDiscriminator: Array.from(new Uint8Array(8)),
+          onRevertDiscriminator: Array.from(new Uint8Array(8)),
+          discriminatorLen: 0,
+          contextVersion: 0,
+          onAbortDiscriminator: Array.from(new Uint8Array(8)),
+        },
+        zeroSignature,
+        0,
+        zeroHash,
+        new anchor.BN(0)
+      )
+      .rpc();
+
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const nonce = pdaAccountData.nonce;
+    const remainingAccounts = [
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+    ];
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 0x05),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      connectedProgram.programId.toBuffer(),
+      Buffer.from(Array.from(address)),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex", { canonical: true });
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    // the default `connected` PDA no longer receives funds
     try {
       await gatewayProgram.methods
-        .depositAndCall(
-          new anchor.BN(1_000_000_000),
+        .execute(
+          amount,
           Array.from(address),
-          Buffer.from(Array(maxPayloadSize + 1).fill(1)),
-          revertOptions
+          data,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
         )
+        .accountsPartial({
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          destinationProgram: connectedProgram.programId,
+          destinationProgramPda: connectedPdaAccount,
+        })
+        .remainingAccounts(remainingAccounts)
         .rpc();
       throw new Error("Expected error not thrown");
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("MemoLengthExceeded");
+      expect(err.message).to.include("DestinationProgramPdaMismatch");
     }
+
+    await gatewayProgram.methods
+      .configureConnectedProgram(
+        connectedProgram.programId,
+        {
+          custodySeed: Buffer.from([]),
+          onCallDiscriminator: Array.from(new Uint8Array(8)),
+          onRevertDiscriminator: Array.from(new Uint8Array(8)),
+          discriminatorLen: 0,
+          contextVersion: 0,
+          onAbortDiscriminator: Array.from(new Uint8Array(8)),
+        },
+        zeroSignature,
+        0,
+        zeroHash,
+        new anchor.BN(0)
+      )
+      .rpc();
   });
 
-  it("Call with empty address receiver should fail", async () => {
+  it("Connected program rejects calls not signed by the gateway caller authority", async () => {
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("connected", "utf-8")],
+      connectedProgram.programId
+    );
+
     try {
-      await gatewayProgram.methods
-        .call(Array(20).fill(0), Buffer.from("hello", "utf-8"), revertOptions)
+      await connectedProgram.methods
+        .onCall(
+          new anchor.BN(0),
+          Array.from(address),
+          Buffer.from("direct call", "utf-8")
+        )
+        .accountsPartial({
+          pda: connectedPdaAccount,
+          gatewayPda: pdaAccount,
+          gatewayCaller: callerAuthority,
+        })
         .rpc();
       throw new Error("Expected error not thrown");
     } catch (err) {
-      expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("EmptyReceiver");
+      expect(err.message).to.not.include("Expected error not thrown");
     }
   });
 
-  it("Call with above max payload size should fail", async () => {
+  it("Configure connected program rejects unknown message context version", async () => {
     try {
       await gatewayProgram.methods
-        .call(
-          Array.from(address),
-          Buffer.from(Array(maxPayloadSize + 1).fill(1)),
-          revertOptions
+        .configureConnectedProgram(
+          connectedProgram.programId,
+          {
+            custodySeed: Buffer.from([]),
+            onCallDiscriminator: Array.from(new Uint8Array(8)),
+            onRevertDiscriminator: Array.from(new Uint8Array(8)),
+            discriminatorLen: 0,
+            contextVersion: 2,
+            onAbortDiscriminator: Array.from(new Uint8Array(8)),
+          },
+          Array.from(new Uint8Array(64)),
+          0,
+          Array.from(new Uint8Array(32)),
+          new anchor.BN(0)
         )
         .rpc();
       throw new Error("Expected error not thrown");
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("MemoLengthExceeded");
+      expect(err.message).to.include("InvalidConnectedProgramConfig");
     }
   });
 
-  it("Call with max payload size", async () => {
-    const txsig = await gatewayProgram.methods
-      .call(
-        Array.from(address),
-        Buffer.from(Array(maxPayloadSize).fill(1)),
-        revertOptions
-      )
-      .preInstructions([
-        ComputeBudgetProgram.setComputeUnitLimit({ units: 400000 }),
-      ])
-      .rpc({ commitment: "processed" });
-    await conn.getParsedTransaction(txsig, "confirmed");
-  });
+  it("Calls execute and onAbort", async () => {
+    const revertMessage = Buffer.from("execute_abort_sol", "utf-8");
+    const sender = Buffer.from(Array.from(address));
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("connected", "utf-8")],
+      connectedProgram.programId
+    );
+    const amount = new anchor.BN(1_000_000);
+    const lengthBuffer = Buffer.alloc(4);
+    lengthBuffer.writeUInt32LE(sender.length);
 
-  it("Deposit and call with max payload size", async () => {
-    const bal1 = await conn.getBalance(pdaAccount);
-    const txsig = await gatewayProgram.methods
-      .depositAndCall(
-        new anchor.BN(1_000_000_000),
-        Array.from(address),
-        Buffer.from(Array(maxPayloadSize).fill(1)),
-        revertOptions
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const nonce = pdaAccountData.nonce;
+    const remainingAccounts = [
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ];
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 15),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      connectedProgram.programId.toBuffer(),
+      Buffer.from([1]),
+      lengthBuffer,
+      sender,
+      revertMessage,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex", { canonical: true });
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    const connectedPdaBalanceBefore = await conn.getBalance(
+      connectedPdaAccount
+    );
+
+    await gatewayProgram.methods
+      .executeAbort(
+        amount,
+        sender,
+        revertMessage,
+        true,
+        Array.from(signatureBuffer),
+        Number(recoveryParam),
+        Array.from(message_hash),
+        nonce,
+        null
       )
-      .preInstructions([
-        ComputeBudgetProgram.setComputeUnitLimit({ units: 400000 }),
-      ])
-      .rpc({ commitment: "processed" });
-    await conn.getParsedTransaction(txsig, "confirmed");
-    const bal2 = await conn.getBalance(pdaAccount);
-    expect(bal2 - bal1).to.be.gte(1_000_000_000);
+      .accountsPartial({
+        signer: wallet.publicKey,
+        pda: pdaAccount,
+        destinationProgram: connectedProgram.programId,
+        destinationProgramPda: connectedPdaAccount,
+      })
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+
+    const connectedPdaBalanceAfter = await conn.getBalance(connectedPdaAccount);
+    expect(connectedPdaBalanceBefore + amount.toNumber()).to.eq(
+      connectedPdaBalanceAfter
+    );
   });
 
-  it("Deposit and call", async () => {
-    let bal1 = await conn.getBalance(pdaAccount);
-    const txsig = await gatewayProgram.methods
-      .depositAndCall(
-        new anchor.BN(1_000_000_000),
-        Array.from(address),
-        Buffer.from("hello", "utf-8"),
-        revertOptions
+  it("Calls an arbitrary instruction on destination program", async () => {
+    const lastMessageData = "arbitrary_call";
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("connected", "utf-8")],
+      connectedProgram.programId
+    );
+    const ix = await connectedProgram.methods
+      .onRevert(
+        new anchor.BN(0),
+        random_account.publicKey,
+        Buffer.from(lastMessageData, "utf-8")
       )
-      .rpc({ commitment: "processed" });
-    await conn.getParsedTransaction(txsig, "confirmed");
-    let bal2 = await conn.getBalance(pdaAccount);
-    expect(bal2 - bal1).to.be.gte(1_000_000_000);
-  });
+      .accountsPartial({
+        pda: connectedPdaAccount,
+        gatewayPda: pdaAccount,
+      })
+      .instruction();
+    const accounts = ix.keys.map((key) => ({
+      pubkey: key.pubkey,
+      isWritable: key.isWritable,
+    }));
+    const amount = new anchor.BN(0);
+
+    const accountsBuffer = Buffer.concat(
+      accounts.map((account) =>
+        Buffer.concat([
+          account.pubkey.toBuffer(),
+          Buffer.from([account.isWritable ? 1 : 0]),
+        ])
+      )
+    );
+    const countBuffer = Buffer.alloc(4);
+    countBuffer.writeUInt32LE(accounts.length);
 
-  it("Deposit SPL with empty address receiver should fail", async () => {
-    try {
-      await depositSplTokens(
-        gatewayProgram,
-        conn,
-        wallet,
-        mint,
-        Buffer.alloc(20)
-      );
-      throw new Error("Expected error not thrown");
-    } catch (err) {
-      expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("EmptyReceiver");
-    }
-  });
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const nonce = pdaAccountData.nonce;
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 17),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      connectedProgram.programId.toBuffer(),
+      anchor.web3.PublicKey.default.toBuffer(),
+      countBuffer,
+      accountsBuffer,
+      ix.data,
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex", { canonical: true });
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
 
-  it("Unwhitelist SPL token and deposit should fail", async () => {
     await gatewayProgram.methods
-      .unwhitelistSplMint([], 0, [], new anchor.BN(0))
-      .accounts({
-        whitelistCandidate: mint.publicKey,
+      .executeArbitraryCall(
+        amount,
+        accounts,
+        ix.data,
+        Array.from(signatureBuffer),
+        Number(recoveryParam),
+        Array.from(message_hash),
+        nonce,
+        null
+      )
+      .accountsPartial({
+        signer: wallet.publicKey,
+        pda: pdaAccount,
+        destinationProgram: connectedProgram.programId,
+        recipient: null,
       })
+      .remainingAccounts(
+        ix.keys.map((key) => ({
+          pubkey: key.pubkey,
+          isSigner: false,
+          isWritable: key.isWritable,
+        }))
+      )
       .rpc();
 
+    const connectedPdaAfter = await connectedProgram.account.pda.fetch(
+      connectedPdaAccount
+    );
+    expect(connectedPdaAfter.lastRevertMessage).to.be.eq(lastMessageData);
+  });
+
+  it("Arbitrary call fails if the expiry is moved into the call data", async () => {
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("connected", "utf-8")],
+      connectedProgram.programId
+    );
+    const ix = await connectedProgram.methods
+      .onRevert(
+        new anchor.BN(0),
+        random_account.publicKey,
+        Buffer.from("expiry_moved", "utf-8")
+      )
+      .accountsPartial({
+        pda: connectedPdaAccount,
+        gatewayPda: pdaAccount,
+      })
+      .instruction();
+    const accounts = ix.keys.map((key) => ({
+      pubkey: key.pubkey,
+      isWritable: key.isWritable,
+    }));
+    const amount = new anchor.BN(0);
+    const expiry = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+    const accountsBuffer = Buffer.concat(
+      accounts.map((account) =>
+        Buffer.concat([
+          account.pubkey.toBuffer(),
+          Buffer.from([account.isWritable ? 1 : 0]),
+        ])
+      )
+    );
+    const countBuffer = Buffer.alloc(4);
+    countBuffer.writeUInt32LE(accounts.length);
+
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const nonce = pdaAccountData.nonce;
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 17),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      connectedProgram.programId.toBuffer(),
+      anchor.web3.PublicKey.default.toBuffer(),
+      countBuffer,
+      accountsBuffer,
+      ix.data,
+      expiryTag(expiry),
+    ]);
+    const message_hash = keccak256(buffer);
+    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex", { canonical: true });
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
+    // relayer appends the expiry bytes to the call data and drops the expiry
+    const movedData = Buffer.concat([
+      ix.data,
+      expiry.toArrayLike(Buffer, "be", 8),
+    ]);
+
     try {
-      await depositSplTokens(gatewayProgram, conn, wallet, mint, address);
+      await gatewayProgram.methods
+        .executeArbitraryCall(
+          amount,
+          accounts,
+          movedData,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accountsPartial({
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          destinationProgram: connectedProgram.programId,
+          recipient: null,
+        })
+        .remainingAccounts(
+          ix.keys.map((key) => ({
+            pubkey: key.pubkey,
+            isSigner: false,
+            isWritable: key.isWritable,
+          }))
+        )
+        .rpc();
       throw new Error("Expected error not thrown");
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("AccountNotInitialized");
+      expect(err.message).to.include("MessageHashMismatch.");
     }
   });
 
-  it("Re-whitelist SPL token and deposit should succeed", async () => {
-    await gatewayProgram.methods
-      .whitelistSplMint([], 0, [], new anchor.BN(0))
-      .accounts({
-        whitelistCandidate: mint.publicKey,
-      })
-      .rpc();
-    await depositSplTokens(gatewayProgram, conn, wallet, mint, address);
-  });
+  it("Executes a transfer signed by the interchain account of a ZEVM sender", async () => {
+    const [interchainAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("interchain_account", "utf-8"), Buffer.from(address)],
+      gatewayProgram.programId
+    );
+    const recipient = anchor.web3.Keypair.generate();
+
+    // fund the interchain account
+    await anchor.web3.sendAndConfirmTransaction(
+      conn,
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: wallet.publicKey,
+          toPubkey: interchainAccount,
+          lamports: 10_000_000,
+        })
+      ),
+      [wallet]
+    );
+
+    const ix = anchor.web3.SystemProgram.transfer({
+      fromPubkey: interchainAccount,
+      toPubkey: recipient.publicKey,
+      lamports: 5_000_000,
+    });
+    const remainingAccounts = [
+      { pubkey: interchainAccount, isSigner: false, isWritable: true },
+      { pubkey: recipient.publicKey, isSigner: false, isWritable: true },
+    ];
+
+    const accountsBuffer = Buffer.concat(
+      remainingAccounts.map((account) =>
+        Buffer.concat([
+          account.pubkey.toBuffer(),
+          Buffer.from([account.isWritable ? 1 : 0]),
+        ])
+      )
+    );
+    const countBuffer = Buffer.alloc(4);
+    countBuffer.writeUInt32LE(remainingAccounts.length);
 
-  it("Unwhitelist SPL token using TSS signature and deposit should fail", async () => {
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
-
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x04]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 18),
       nonce.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
+      new anchor.BN(0).toArrayLike(Buffer, "be", 8),
+      anchor.web3.SystemProgram.programId.toBuffer(),
+      Buffer.from(address),
+      countBuffer,
+      accountsBuffer,
+      ix.data,
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
-    const { r, s, recoveryParam } = signature;
+    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex", { canonical: true });
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
       s.toArrayLike(Buffer, "be", 32),
     ]);
 
     await gatewayProgram.methods
-      .unwhitelistSplMint(
+      .executeAsAccount(
+        Array.from(address),
+        ix.data,
         Array.from(signatureBuffer),
         Number(recoveryParam),
         Array.from(message_hash),
-        nonce
+        nonce,
+        null
       )
-      .accounts({
-        whitelistCandidate: mint.publicKey,
+      .accountsPartial({
+        signer: wallet.publicKey,
+        pda: pdaAccount,
+        destinationProgram: anchor.web3.SystemProgram.programId,
+        interchainAccount,
       })
+      .remainingAccounts(remainingAccounts)
       .rpc();
 
+    expect(await conn.getBalance(recipient.publicKey)).to.eq(5_000_000);
+  });
+
+  it("Rejects a call back to ZEVM signed by the interchain account during execute", async () => {
+    const [interchainAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("interchain_account", "utf-8"), Buffer.from(address)],
+      gatewayProgram.programId
+    );
+
+    const ix = await gatewayProgram.methods
+      .call(Array.from(address), Buffer.from("reply", "utf-8"), null)
+      .accountsPartial({
+        signer: interchainAccount,
+        pda: pdaAccount,
+      })
+      .instruction();
+    const remainingAccounts = [
+      { pubkey: interchainAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+    ];
+
+    const accountsBuffer = Buffer.concat(
+      remainingAccounts.map((account) =>
+        Buffer.concat([
+          account.pubkey.toBuffer(),
+          Buffer.from([account.isWritable ? 1 : 0]),
+        ])
+      )
+    );
+    const countBuffer = Buffer.alloc(4);
+    countBuffer.writeUInt32LE(remainingAccounts.length);
+
+    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
+    const nonce = pdaAccountData.nonce;
+    const buffer = Buffer.concat([
+      messageHeader(gatewayProgram.programId, 18),
+      nonce.toArrayLike(Buffer, "be", 8),
+      new anchor.BN(0).toArrayLike(Buffer, "be", 8),
+      gatewayProgram.programId.toBuffer(),
+      Buffer.from(address),
+      countBuffer,
+      accountsBuffer,
+      ix.data,
+      expiryTag(null),
+    ]);
+    const message_hash = keccak256(buffer);
+    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex", { canonical: true });
+    const signatureBuffer = Buffer.concat([
+      r.toArrayLike(Buffer, "be", 32),
+      s.toArrayLike(Buffer, "be", 32),
+    ]);
+
     try {
-      await depositSplTokens(gatewayProgram, conn, wallet, mint, address);
+      await gatewayProgram.methods
+        .executeAsAccount(
+          Array.from(address),
+          ix.data,
+          Array.from(signatureBuffer),
+          Number(recoveryParam),
+          Array.from(message_hash),
+          nonce,
+          null
+        )
+        .accountsPartial({
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          destinationProgram: gatewayProgram.programId,
+          interchainAccount,
+        })
+        .remainingAccounts(remainingAccounts)
+        .rpc();
       throw new Error("Expected error not thrown");
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("AccountNotInitialized");
+      expect(err.message).to.include("ExecuteInProgress.");
     }
   });
 
-  it("Re-whitelist SPL token using TSS signature and deposit should succeed", async () => {
+  it("Calls several destination programs in one execute multi", async () => {
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("connected", "utf-8")],
+      connectedProgram.programId
+    );
+    const [connectedProgramEntry] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("connected_program", "utf-8"), connectedProgram.programId.toBuffer()],
+      gatewayProgram.programId
+    );
+    const onCallData = async (message: string) =>
+      (
+        await connectedProgram.methods
+          .onCall(new anchor.BN(0), Array.from(address), Buffer.from(message, "utf-8"))
+          .accountsPartial({
+            pda: connectedPdaAccount,
+            gatewayPda: pdaAccount,
+            gatewayCaller: callerAuthority,
+          })
+          .instruction()
+      ).data;
+    const pda_ata = await spl.getAssociatedTokenAddress(
+      mint.publicKey,
+      pdaAccount,
+      true
+    );
+    const connectedPdaAta = await spl.getOrCreateAssociatedTokenAccount(
+      conn,
+      wallet,
+      mint.publicKey,
+      connectedPdaAccount,
+      true
+    );
+    const [whitelistEntry] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("whitelist", "utf-8"), mint.publicKey.toBuffer()],
+      gatewayProgram.programId
+    );
+    const [usdcRateLimit] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("rate_limit", "utf-8"), mint.publicKey.toBuffer()],
+      gatewayProgram.programId
+    );
+    const tokenAmount = new anchor.BN(100_000);
+    const steps = [
+      {
+        programId: connectedProgram.programId,
+        accountsStart: 0,
+        accountsLen: 4,
+        data: await onCallData("multi_one"),
+        amount: new anchor.BN(1_000_000),
+        token: null,
+      },
+      {
+        programId: connectedProgram.programId,
+        accountsStart: 0,
+        accountsLen: 4,
+        data: await onCallData("multi_two"),
+        amount: new anchor.BN(0),
+        token: {
+          mint: mint.publicKey,
+          decimals: usdcDecimals,
+          amount: tokenAmount,
+        },
+      },
+    ];
+    const stepsBuffer = Buffer.concat(
+      steps.map((step) => {
+        const dataLength = Buffer.alloc(4);
+        dataLength.writeUInt32LE(step.data.length);
+        const token = step.token
+          ? Buffer.concat([
+              Buffer.from([1]),
+              step.token.mint.toBuffer(),
+              Buffer.from([step.token.decimals]),
+              step.token.amount.toArrayLike(Buffer, "be", 8),
+            ])
+          : Buffer.from([0]);
+        return Buffer.concat([
+          step.programId.toBuffer(),
+          Buffer.from([step.accountsStart, step.accountsLen]),
+          step.amount.toArrayLike(Buffer, "be", 8),
+          token,
+          dataLength,
+          step.data,
+        ]);
+      })
+    );
+    const amount = new anchor.BN(1_000_000);
+
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
-
+    const remainingAccounts = [
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: callerAuthority, isSigner: false, isWritable: false },
+      { pubkey: connectedProgramEntry, isSigner: false, isWritable: false },
+      {
+        pubkey: connectedProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: mint.publicKey, isSigner: false, isWritable: false },
+      { pubkey: whitelistEntry, isSigner: false, isWritable: false },
+      { pubkey: usdcRateLimit, isSigner: false, isWritable: true },
+      { pubkey: pda_ata, isSigner: false, isWritable: true },
+      {
+        pubkey: connectedPdaAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x03]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 19),
       nonce.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
+      amount.toArrayLike(Buffer, "be", 8),
+      stepsBuffer,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
-    const { r, s, recoveryParam } = signature;
+    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex", { canonical: true });
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
       s.toArrayLike(Buffer, "be", 32),
     ]);
 
+    const connectedPdaBalanceBefore = await conn.getBalance(
+      connectedPdaAccount
+    );
+    const connectedPdaAtaBefore = await spl.getAccount(
+      conn,
+      connectedPdaAta.address
+    );
+
     await gatewayProgram.methods
-      .whitelistSplMint(
+      .executeMulti(
+        steps,
         Array.from(signatureBuffer),
         Number(recoveryParam),
         Array.from(message_hash),
-        nonce
+        nonce,
+        null
       )
-      .accounts({
-        whitelistCandidate: mint.publicKey,
+      .accountsPartial({
+        signer: wallet.publicKey,
+        pda: pdaAccount,
       })
+      .remainingAccounts(remainingAccounts)
       .rpc();
-    await depositSplTokens(gatewayProgram, conn, wallet, mint, address);
+
+    const connectedPdaAfter = await connectedProgram.account.pda.fetch(
+      connectedPdaAccount
+    );
+    expect(connectedPdaAfter.lastMessage).to.be.eq("multi_two");
+    expect(await conn.getBalance(connectedPdaAccount)).to.eq(
+      connectedPdaBalanceBefore + amount.toNumber()
+    );
+    const connectedPdaAtaAfter = await spl.getAccount(
+      conn,
+      connectedPdaAta.address
+    );
+    expect(connectedPdaAtaAfter.amount).to.eq(
+      connectedPdaAtaBefore.amount + BigInt(tokenAmount.toNumber())
+    );
   });
 
-  it("Unwhitelist SPL token using wrong msg hash should fail", async () => {
+  it("Calls execute with the payload staged in a message buffer", async () => {
+    const lastMessageData = "execute_staged_message";
+    const data = Buffer.from(lastMessageData, "utf-8");
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("connected", "utf-8")],
+      connectedProgram.programId
+    );
+    const amount = new anchor.BN(1_000_000);
+
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
+    const [messageBuffer] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("message_buffer", "utf-8"),
+        wallet.publicKey.toBuffer(),
+        nonce.toArrayLike(Buffer, "le", 8),
+      ],
+      gatewayProgram.programId
+    );
+
+    // stage payload in chunks
+    const chunkSize = 8;
+    for (let offset = 0; offset < data.length; offset += chunkSize) {
+      await gatewayProgram.methods
+        .stageMessage(
+          nonce,
+          data.length,
+          offset,
+          data.subarray(offset, offset + chunkSize)
+        )
+        .accountsPartial({
+          relayer: wallet.publicKey,
+          messageBuffer,
+        })
+        .rpc();
+    }
 
+    const remainingAccounts = [
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      {
+        pubkey: anchor.web3.SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: callerAuthority, isSigner: false, isWritable: false },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x03]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
-      nonce.subn(1).toArrayLike(Buffer, "be", 8), // wrong nonce
-      mint.publicKey.toBuffer(),
+      messageHeader(gatewayProgram.programId, 20),
+      nonce.toArrayLike(Buffer, "be", 8),
+      amount.toArrayLike(Buffer, "be", 8),
+      connectedProgram.programId.toBuffer(),
+      Buffer.from(Array.from(address)),
+      keccak256(data),
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
-    const { r, s, recoveryParam } = signature;
+    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex", { canonical: true });
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
       s.toArrayLike(Buffer, "be", 32),
     ]);
 
-    try {
+    await gatewayProgram.methods
+      .executeFromBuffer(
+        amount,
+        Array.from(address),
+        Array.from(signatureBuffer),
+        Number(recoveryParam),
+        Array.from(message_hash),
+        nonce,
+        null
+      )
+      .accountsPartial({
+        execute: {
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          destinationProgram: connectedProgram.programId,
+          destinationProgramPda: connectedPdaAccount,
+        },
+        messageBuffer,
+        relayer: wallet.publicKey,
+      })
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+
+    const connectedPdaAfter = await connectedProgram.account.pda.fetch(
+      connectedPdaAccount
+    );
+    expect(connectedPdaAfter.lastMessage).to.be.eq(lastMessageData);
+    expect(await conn.getAccountInfo(messageBuffer)).to.be.null;
+  });
+
+  it("Deposit and call with the message staged in a deposit buffer", async () => {
+    const message = Buffer.alloc(2000, 7);
+    const [depositBuffer] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("deposit_buffer", "utf-8"), wallet.publicKey.toBuffer()],
+      gatewayProgram.programId
+    );
+
+    // stage message in chunks
+    const chunkSize = 800;
+    for (let offset = 0; offset < message.length; offset += chunkSize) {
       await gatewayProgram.methods
-        .unwhitelistSplMint(
-          Array.from(signatureBuffer),
-          Number(recoveryParam),
-          Array.from(message_hash),
-          nonce
+        .stageDepositMessage(
+          message.length,
+          offset,
+          message.subarray(offset, offset + chunkSize)
         )
-        .accounts({
-          whitelistCandidate: mint.publicKey,
-        })
         .rpc();
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
-    } catch (err) {
-      expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("MessageHashMismatch");
     }
+
+    await gatewayProgram.methods
+      .depositAndCallFromBuffer(
+        new anchor.BN(1_000_000_000),
+        Array.from(address),
+        null
+      )
+      .accountsPartial({
+        deposit: {
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+        },
+      })
+      .rpc();
+
+    expect(await conn.getAccountInfo(depositBuffer)).to.be.null;
   });
 
-  it("Unwhitelist SPL token using wrong signer should fail", async () => {
-    const key = ec.genKeyPair(); // non TSS key pair
+  it("Execute rejects duplicate remaining accounts", async () => {
+    const data = Buffer.from("execute_duplicate", "utf-8");
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("connected", "utf-8")],
+      connectedProgram.programId
+    );
+    const amount = new anchor.BN(1_000_000);
+
     const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
     const nonce = pdaAccountData.nonce;
-
+    const remainingAccounts = [
+      { pubkey: connectedPdaAccount, isSigner: false, isWritable: true },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+      { pubkey: pdaAccount, isSigner: false, isWritable: false },
+    ];
     const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x04]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
+      messageHeader(gatewayProgram.programId, 0x05),
       nonce.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
+      amount.toArrayLike(Buffer, "be", 8),
+      connectedProgram.programId.toBuffer(),
+      Buffer.from(Array.from(address)),
+      data,
+      accountsHash(remainingAccounts),
+      expiryTag(null),
     ]);
     const message_hash = keccak256(buffer);
-    const signature = key.sign(message_hash, "hex");
-    const { r, s, recoveryParam } = signature;
+    const { r, s, recoveryParam } = keyPair.sign(message_hash, "hex", { canonical: true });
     const signatureBuffer = Buffer.concat([
       r.toArrayLike(Buffer, "be", 32),
       s.toArrayLike(Buffer, "be", 32),
@@ -3790,58 +6343,86 @@ describe("Gateway", () => {
 
     try {
       await gatewayProgram.methods
-        .unwhitelistSplMint(
+        .execute(
+          amount,
+          Array.from(address),
+          data,
           Array.from(signatureBuffer),
           Number(recoveryParam),
           Array.from(message_hash),
-          nonce
+          nonce,
+          null
         )
-        .accounts({
-          whitelistCandidate: mint.publicKey,
+        .accountsPartial({
+          signer: wallet.publicKey,
+          pda: pdaAccount,
+          destinationProgram: connectedProgram.programId,
+          destinationProgramPda: connectedPdaAccount,
         })
+        .remainingAccounts(remainingAccounts)
         .rpc();
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+      throw new Error("Expected error not thrown");
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("TSSAuthenticationFailed");
+      expect(err.message).to.include("DuplicateRemainingAccount");
     }
   });
 
-  it("Unwhitelist SPL token using wrong nonce should fail", async () => {
-    const pdaAccountData = await gatewayProgram.account.pda.fetch(pdaAccount);
-    const nonce = pdaAccountData.nonce;
+  it("Deposit SOL debited from a program-owned account through its deposit escrow", async () => {
+    const [connectedPdaAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("connected", "utf-8")],
+      connectedProgram.programId
+    );
+    const [escrow] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("deposit_escrow", "utf-8"), connectedPdaAccount.toBuffer()],
+      gatewayProgram.programId
+    );
+    const amount = 10_000_000;
 
-    const buffer = Buffer.concat([
-      Buffer.from("ZETACHAIN", "utf-8"),
-      Buffer.from([0x03]),
-      chain_id_bn.toArrayLike(Buffer, "be", 8),
-      nonce.toArrayLike(Buffer, "be", 8),
-      mint.publicKey.toBuffer(),
-    ]);
-    const message_hash = keccak256(buffer);
-    const signature = keyPair.sign(message_hash, "hex");
-    const { r, s, recoveryParam } = signature;
-    const signatureBuffer = Buffer.concat([
-      r.toArrayLike(Buffer, "be", 32),
-      s.toArrayLike(Buffer, "be", 32),
-    ]);
+    const pdaBalanceBefore = await conn.getBalance(pdaAccount);
+    const connectedPdaBalanceBefore = await conn.getBalance(
+      connectedPdaAccount
+    );
+    await connectedProgram.methods
+      .triggerPrefundedDeposit(
+        new anchor.BN(amount),
+        Array.from(address),
+        revertOptions
+      )
+      .accounts({
+        gatewayEscrow: escrow,
+        gatewayPda: pdaAccount,
+        gatewayRateLimit: anchor.web3.PublicKey.findProgramAddressSync(
+          [Buffer.from("rate_limit", "utf-8"), spl.NATIVE_MINT.toBuffer()],
+          gatewayProgram.programId
+        )[0],
+        gatewayProgram: gatewayProgram.programId,
+      })
+      .rpc();
+
+    const pdaBalanceAfter = await conn.getBalance(pdaAccount);
+    const connectedPdaBalanceAfter = await conn.getBalance(connectedPdaAccount);
+    expect(pdaBalanceAfter - pdaBalanceBefore).to.eq(amount + 2_000_000);
+    expect(connectedPdaBalanceBefore - connectedPdaBalanceAfter).to.eq(amount);
+    expect(await conn.getBalance(escrow)).to.eq(0);
+  });
+
+  it("Deposit from an empty deposit escrow should fail", async () => {
+    const source = anchor.web3.Keypair.generate();
 
     try {
       await gatewayProgram.methods
-        .unwhitelistSplMint(
-          Array.from(signatureBuffer),
-          Number(recoveryParam),
-          Array.from(message_hash),
-          nonce.subn(1)
-        )
+        .depositPrefunded(Array.from(address), revertOptions)
         .accounts({
-          whitelistCandidate: mint.publicKey,
+          deposit: { signer: wallet.publicKey },
+          source: source.publicKey,
         })
+        .signers([source])
         .rpc();
-      throw new Error("Expected error not thrown"); // This line will make the test fail if no error is thrown
+      throw new Error("Expected error not thrown");
     } catch (err) {
       expect(err).to.be.instanceof(anchor.AnchorError);
-      expect(err.message).to.include("NonceMismatch.");
+      expect(err.message).to.include("EmptyDepositEscrow.");
     }
   });
 